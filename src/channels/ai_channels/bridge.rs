@@ -0,0 +1,229 @@
+use crate::channels::ai_channels::resistance::{
+    ExcitationSource, ExcitedInputKind, LeadWireInputKind,
+};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+use std::sync::Arc;
+
+/// Kinds of channel which measure a Wheatstone bridge with voltage excitation.
+pub trait BridgeInputKind: LeadWireInputKind {}
+
+impl<K: BridgeInputKind> TaskChannel<K> {
+    property!(get_set bridge_configuration / set_bridge_configuration: BridgeConfiguration = DAQmxGetAIBridgeCfg, DAQmxSetAIBridgeCfg);
+    property!(get_set excitation_voltage / set_excitation_voltage: f64 = DAQmxGetAIExcitVal, DAQmxSetAIExcitVal);
+    property!(get_set nominal_bridge_resistance / set_nominal_bridge_resistance: f64 = DAQmxGetAIBridgeNomResistance, DAQmxSetAIBridgeNomResistance);
+    property!(get_set initial_bridge_voltage / set_initial_bridge_voltage: f64 = DAQmxGetAIBridgeInitialVoltage, DAQmxSetAIBridgeInitialVoltage);
+}
+
+/// A bridge channel measuring the ratio of the bridge output to the excitation voltage.
+pub struct Bridge;
+
+impl ChannelKind for Bridge {}
+impl AnalogInputKind for Bridge {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Bridge);
+}
+impl ExcitedInputKind for Bridge {}
+impl LeadWireInputKind for Bridge {}
+impl BridgeInputKind for Bridge {}
+impl ScaledInputKind for Bridge {
+    type Scale = BridgeScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIBridgeUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIBridgeUnits;
+}
+
+/// The arrangement of the bridge elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BridgeConfiguration {
+    FullBridge,
+    HalfBridge,
+    QuarterBridge,
+    NoBridge,
+}
+
+impl PropertyValue for BridgeConfiguration {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_FullBridge => Ok(BridgeConfiguration::FullBridge),
+            DAQmx_Val_HalfBridge => Ok(BridgeConfiguration::HalfBridge),
+            DAQmx_Val_QuarterBridge => Ok(BridgeConfiguration::QuarterBridge),
+            DAQmx_Val_NoBridge => Ok(BridgeConfiguration::NoBridge),
+            _ => Err(DaqmxError::UnexpectedValue("BridgeConfiguration", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            BridgeConfiguration::FullBridge => DAQmx_Val_FullBridge,
+            BridgeConfiguration::HalfBridge => DAQmx_Val_HalfBridge,
+            BridgeConfiguration::QuarterBridge => DAQmx_Val_QuarterBridge,
+            BridgeConfiguration::NoBridge => DAQmx_Val_NoBridge,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BridgeScale {
+    VoltsPerVolt,
+    MilliVoltsPerVolt,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+    /// Units are set from the TEDS configuration. This case should be read only.
+    FromTEDS,
+}
+
+impl BridgeScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for BridgeScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for BridgeScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_VoltsPerVolt => Ok(Self::VoltsPerVolt),
+            DAQmx_Val_mVoltsPerVolt => Ok(Self::MilliVoltsPerVolt),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            DAQmx_Val_FromTEDS => Ok(Self::FromTEDS),
+            _ => Err(DaqmxError::UnexpectedValue("Bridge Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            BridgeScale::VoltsPerVolt => DAQmx_Val_VoltsPerVolt,
+            BridgeScale::MilliVoltsPerVolt => DAQmx_Val_mVoltsPerVolt,
+            BridgeScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+            BridgeScale::FromTEDS => DAQmx_Val_FromTEDS,
+        }
+    }
+}
+
+pub struct BridgeChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: BridgeScale,
+    bridge_configuration: BridgeConfiguration,
+    excitation_source: ExcitationSource,
+    excitation_voltage: f64,
+    nominal_bridge_resistance: f64,
+}
+
+impl ChannelBuilder for BridgeChannelBuilder {
+    type Kind = Bridge;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: -0.002,
+            max: 0.002,
+            scale: BridgeScale::VoltsPerVolt,
+            bridge_configuration: BridgeConfiguration::FullBridge,
+            excitation_source: ExcitationSource::Internal,
+            excitation_voltage: 2.5,
+            nominal_bridge_resistance: 350.0,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIBridgeChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.bridge_configuration.into_raw(),
+            self.excitation_source.into_raw(),
+            self.excitation_voltage,
+            self.nominal_bridge_resistance,
+            custom_scale_name
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for BridgeChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl BridgeChannelBuilder {
+    pub fn scale(self, scale: BridgeScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn bridge_configuration(self, bridge_configuration: BridgeConfiguration) -> Self {
+        Self {
+            bridge_configuration,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_voltage(self, excitation_voltage: f64) -> Self {
+        Self {
+            excitation_voltage,
+            ..self
+        }
+    }
+
+    pub fn nominal_bridge_resistance(self, nominal_bridge_resistance: f64) -> Self {
+        Self {
+            nominal_bridge_resistance,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! Bridge based sensors, such as load cells, pressure transducers and torque sensors.
+//!
+//! These channels convert the bridge output to physical units using a calibration from the
+//! sensor datasheet or calibration certificate, so they don't need a separate custom scale.
+use crate::channels::ai_channels::bridge::{BridgeConfiguration, BridgeInputKind};
+use crate::channels::ai_channels::resistance::{
+    ExcitationSource, ExcitedInputKind, LeadWireInputKind,
+};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::scales::PreScaledUnits;
+use crate::sys::*;
+use std::ffi::{CString, c_char};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type TwoPointLinearCreate = unsafe extern "C" fn(
+    TaskHandle,
+    *const c_char,
+    *const c_char,
+    f64,
+    f64,
+    i32,
+    i32,
+    i32,
+    f64,
+    f64,
+    f64,
+    f64,
+    i32,
+    f64,
+    f64,
+    i32,
+    *const c_char,
+) -> i32;
+
+type TableCreate = unsafe extern "C" fn(
+    TaskHandle,
+    *const c_char,
+    *const c_char,
+    f64,
+    f64,
+    i32,
+    i32,
+    i32,
+    f64,
+    f64,
+    *const f64,
+    u32,
+    i32,
+    *const f64,
+    u32,
+    i32,
+    *const c_char,
+) -> i32;
+
+type PolynomialCreate = unsafe extern "C" fn(
+    TaskHandle,
+    *const c_char,
+    *const c_char,
+    f64,
+    f64,
+    i32,
+    i32,
+    i32,
+    f64,
+    f64,
+    *const f64,
+    u32,
+    *const f64,
+    u32,
+    i32,
+    i32,
+    *const c_char,
+) -> i32;
+
+/// Kinds of channel which measure a bridge sensor with a calibration to physical units.
+///
+/// This selects the DAQmx functions used for each kind, so the channels and builders can share
+/// their implementation.
+pub trait BridgeSensorKind: BridgeInputKind + ScaledInputKind<Scale = BridgeSensorScale> {
+    /// The units used by the builder unless another scale is set.
+    const DEFAULT_UNITS: PreScaledUnits;
+    const CREATE_TWO_POINT_LINEAR: TwoPointLinearCreate;
+    const CREATE_TABLE: TableCreate;
+    const CREATE_POLYNOMIAL: PolynomialCreate;
+}
+
+/// A pressure transducer based on a bridge.
+pub struct PressureBridge;
+
+impl ChannelKind for PressureBridge {}
+impl AnalogInputKind for PressureBridge {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::PressureBridge);
+}
+impl ExcitedInputKind for PressureBridge {}
+impl LeadWireInputKind for PressureBridge {}
+impl BridgeInputKind for PressureBridge {}
+impl ScaledInputKind for PressureBridge {
+    type Scale = BridgeSensorScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIPressureUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIPressureUnits;
+}
+impl BridgeSensorKind for PressureBridge {
+    const DEFAULT_UNITS: PreScaledUnits = PreScaledUnits::PSI;
+    const CREATE_TWO_POINT_LINEAR: TwoPointLinearCreate =
+        DAQmxCreateAIPressureBridgeTwoPointLinChan;
+    const CREATE_TABLE: TableCreate = DAQmxCreateAIPressureBridgeTableChan;
+    const CREATE_POLYNOMIAL: PolynomialCreate = DAQmxCreateAIPressureBridgePolynomialChan;
+}
+
+/// A load cell or other force sensor based on a bridge.
+pub struct ForceBridge;
+
+impl ChannelKind for ForceBridge {}
+impl AnalogInputKind for ForceBridge {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::ForceBridge);
+}
+impl ExcitedInputKind for ForceBridge {}
+impl LeadWireInputKind for ForceBridge {}
+impl BridgeInputKind for ForceBridge {}
+impl ScaledInputKind for ForceBridge {
+    type Scale = BridgeSensorScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIForceUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIForceUnits;
+}
+impl BridgeSensorKind for ForceBridge {
+    const DEFAULT_UNITS: PreScaledUnits = PreScaledUnits::Newtons;
+    const CREATE_TWO_POINT_LINEAR: TwoPointLinearCreate = DAQmxCreateAIForceBridgeTwoPointLinChan;
+    const CREATE_TABLE: TableCreate = DAQmxCreateAIForceBridgeTableChan;
+    const CREATE_POLYNOMIAL: PolynomialCreate = DAQmxCreateAIForceBridgePolynomialChan;
+}
+
+/// A torque sensor based on a bridge.
+pub struct TorqueBridge;
+
+impl ChannelKind for TorqueBridge {}
+impl AnalogInputKind for TorqueBridge {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::TorqueBridge);
+}
+impl ExcitedInputKind for TorqueBridge {}
+impl LeadWireInputKind for TorqueBridge {}
+impl BridgeInputKind for TorqueBridge {}
+impl ScaledInputKind for TorqueBridge {
+    type Scale = BridgeSensorScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAITorqueUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAITorqueUnits;
+}
+impl BridgeSensorKind for TorqueBridge {
+    const DEFAULT_UNITS: PreScaledUnits = PreScaledUnits::NewtonMeters;
+    const CREATE_TWO_POINT_LINEAR: TwoPointLinearCreate = DAQmxCreateAITorqueBridgeTwoPointLinChan;
+    const CREATE_TABLE: TableCreate = DAQmxCreateAITorqueBridgeTableChan;
+    const CREATE_POLYNOMIAL: PolynomialCreate = DAQmxCreateAITorqueBridgePolynomialChan;
+}
+
+impl<K: BridgeSensorKind> TaskChannel<K> {
+    property!(get_set electrical_units / set_electrical_units: PreScaledUnits = DAQmxGetAIBridgeElectricalUnits, DAQmxSetAIBridgeElectricalUnits);
+    property!(get_set physical_units / set_physical_units: PreScaledUnits = DAQmxGetAIBridgePhysicalUnits, DAQmxSetAIBridgePhysicalUnits);
+
+    /// Read the calibration used to convert the bridge output to physical units.
+    pub fn calibration(&self) -> Result<BridgeCalibration, DaqmxError> {
+        let scale_type: i32 = self.property_get(DAQmxGetAIBridgeScaleType)?;
+        #[allow(non_upper_case_globals)]
+        match scale_type {
+            DAQmx_Val_TwoPointLinear => Ok(BridgeCalibration::TwoPointLinear {
+                first_electrical_value: self
+                    .property_get(DAQmxGetAIBridgeTwoPointLinFirstElectricalVal)?,
+                first_physical_value: self
+                    .property_get(DAQmxGetAIBridgeTwoPointLinFirstPhysicalVal)?,
+                second_electrical_value: self
+                    .property_get(DAQmxGetAIBridgeTwoPointLinSecondElectricalVal)?,
+                second_physical_value: self
+                    .property_get(DAQmxGetAIBridgeTwoPointLinSecondPhysicalVal)?,
+            }),
+            DAQmx_Val_Table => Ok(BridgeCalibration::Table {
+                electrical_values: self.property_get_array(DAQmxGetAIBridgeTableElectricalVals)?,
+                physical_values: self.property_get_array(DAQmxGetAIBridgeTablePhysicalVals)?,
+            }),
+            DAQmx_Val_Polynomial => Ok(BridgeCalibration::Polynomial {
+                forward_coefficients: self.property_get_array(DAQmxGetAIBridgePolyForwardCoeff)?,
+                reverse_coefficients: self.property_get_array(DAQmxGetAIBridgePolyReverseCoeff)?,
+            }),
+            _ => Err(DaqmxError::UnexpectedValue("Bridge Scale Type", scale_type)),
+        }
+    }
+}
+
+/// The mapping from the bridge output in the electrical units to the physical units.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BridgeCalibration {
+    /// A linear mapping through two points, such as zero and the rated output of a load cell.
+    TwoPointLinear {
+        first_electrical_value: f64,
+        first_physical_value: f64,
+        second_electrical_value: f64,
+        second_physical_value: f64,
+    },
+    /// A table of points which are interpolated linearly, such as from a calibration certificate.
+    Table {
+        electrical_values: Vec<f64>,
+        physical_values: Vec<f64>,
+    },
+    /// Polynomials in order of increasing power. The forward coefficients convert electrical
+    /// values to physical values and the reverse coefficients convert them back.
+    Polynomial {
+        forward_coefficients: Vec<f64>,
+        reverse_coefficients: Vec<f64>,
+    },
+}
+
+/// The units of a bridge sensor channel.
+///
+/// If these differ from the physical units of the calibration, DAQmx converts between them, e.g.
+/// a load cell calibrated in pounds can read in newtons.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BridgeSensorScale {
+    Units(PreScaledUnits),
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl BridgeSensorScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for BridgeSensorScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for BridgeSensorScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        if raw == DAQmx_Val_FromCustomScale {
+            Ok(Self::CustomScale(None))
+        } else {
+            PreScaledUnits::from_raw(raw).map(Self::Units)
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            BridgeSensorScale::Units(units) => units.into_raw(),
+            BridgeSensorScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+/// Builds a bridge sensor channel of kind `K`.
+///
+/// By default the calibration maps 0 to 2 mV/V onto 0 to 100 of the default units of the kind,
+/// which should be replaced with the sensor's own calibration.
+pub struct BridgeSensorChannelBuilder<K: BridgeSensorKind> {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: BridgeSensorScale,
+    bridge_configuration: BridgeConfiguration,
+    excitation_source: ExcitationSource,
+    excitation_voltage: f64,
+    nominal_bridge_resistance: f64,
+    calibration: BridgeCalibration,
+    electrical_units: PreScaledUnits,
+    physical_units: PreScaledUnits,
+    _kind: PhantomData<K>,
+}
+
+pub type PressureBridgeChannelBuilder = BridgeSensorChannelBuilder<PressureBridge>;
+pub type ForceBridgeChannelBuilder = BridgeSensorChannelBuilder<ForceBridge>;
+pub type TorqueBridgeChannelBuilder = BridgeSensorChannelBuilder<TorqueBridge>;
+
+impl<K: BridgeSensorKind> ChannelBuilder for BridgeSensorChannelBuilder<K> {
+    type Kind = K;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: -100.0,
+            max: 100.0,
+            scale: BridgeSensorScale::Units(K::DEFAULT_UNITS),
+            bridge_configuration: BridgeConfiguration::FullBridge,
+            excitation_source: ExcitationSource::Internal,
+            excitation_voltage: 2.5,
+            nominal_bridge_resistance: 350.0,
+            calibration: BridgeCalibration::TwoPointLinear {
+                first_electrical_value: 0.0,
+                first_physical_value: 0.0,
+                second_electrical_value: 2.0,
+                second_physical_value: 100.0,
+            },
+            electrical_units: PreScaledUnits::MilliVoltsPerVolt,
+            physical_units: K::DEFAULT_UNITS,
+            _kind: PhantomData,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        let units = self.scale.clone().into_raw();
+        let electrical_units = self.electrical_units.clone().into_raw();
+        let physical_units = self.physical_units.clone().into_raw();
+        match &self.calibration {
+            BridgeCalibration::TwoPointLinear {
+                first_electrical_value,
+                first_physical_value,
+                second_electrical_value,
+                second_physical_value,
+            } => daqmx_call!((K::CREATE_TWO_POINT_LINEAR)(
+                task,
+                self.physical_channel.as_ptr(),
+                self.name.as_ptr(),
+                self.min,
+                self.max,
+                units,
+                self.bridge_configuration.into_raw(),
+                self.excitation_source.into_raw(),
+                self.excitation_voltage,
+                self.nominal_bridge_resistance,
+                *first_electrical_value,
+                *second_electrical_value,
+                electrical_units,
+                *first_physical_value,
+                *second_physical_value,
+                physical_units,
+                custom_scale_name
+            ))?,
+            BridgeCalibration::Table {
+                electrical_values,
+                physical_values,
+            } => daqmx_call!((K::CREATE_TABLE)(
+                task,
+                self.physical_channel.as_ptr(),
+                self.name.as_ptr(),
+                self.min,
+                self.max,
+                units,
+                self.bridge_configuration.into_raw(),
+                self.excitation_source.into_raw(),
+                self.excitation_voltage,
+                self.nominal_bridge_resistance,
+                electrical_values.as_ptr(),
+                electrical_values.len() as u32,
+                electrical_units,
+                physical_values.as_ptr(),
+                physical_values.len() as u32,
+                physical_units,
+                custom_scale_name
+            ))?,
+            BridgeCalibration::Polynomial {
+                forward_coefficients,
+                reverse_coefficients,
+            } => daqmx_call!((K::CREATE_POLYNOMIAL)(
+                task,
+                self.physical_channel.as_ptr(),
+                self.name.as_ptr(),
+                self.min,
+                self.max,
+                units,
+                self.bridge_configuration.into_raw(),
+                self.excitation_source.into_raw(),
+                self.excitation_voltage,
+                self.nominal_bridge_resistance,
+                forward_coefficients.as_ptr(),
+                forward_coefficients.len() as u32,
+                reverse_coefficients.as_ptr(),
+                reverse_coefficients.len() as u32,
+                electrical_units,
+                physical_units,
+                custom_scale_name
+            ))?,
+        }
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl<K: BridgeSensorKind> AnalogChannelBuilder for BridgeSensorChannelBuilder<K> {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl<K: BridgeSensorKind> BridgeSensorChannelBuilder<K> {
+    pub fn scale(self, scale: BridgeSensorScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn bridge_configuration(self, bridge_configuration: BridgeConfiguration) -> Self {
+        Self {
+            bridge_configuration,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_voltage(self, excitation_voltage: f64) -> Self {
+        Self {
+            excitation_voltage,
+            ..self
+        }
+    }
+
+    pub fn nominal_bridge_resistance(self, nominal_bridge_resistance: f64) -> Self {
+        Self {
+            nominal_bridge_resistance,
+            ..self
+        }
+    }
+
+    pub fn calibration(self, calibration: BridgeCalibration) -> Self {
+        Self {
+            calibration,
+            ..self
+        }
+    }
+
+    /// The units of the electrical values of the calibration, either
+    /// [`PreScaledUnits::VoltsPerVolt`] or [`PreScaledUnits::MilliVoltsPerVolt`].
+    pub fn electrical_units(self, electrical_units: PreScaledUnits) -> Self {
+        Self {
+            electrical_units,
+            ..self
+        }
+    }
+
+    /// The units of the physical values of the calibration.
+    pub fn physical_units(self, physical_units: PreScaledUnits) -> Self {
+        Self {
+            physical_units,
+            ..self
+        }
+    }
+}
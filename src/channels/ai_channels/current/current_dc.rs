@@ -1,39 +1,44 @@
-use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
 use crate::channels::{
     AnalogInputKind, ChannelBuilder, ChannelKind,
     TaskChannel,
 };
 use crate::daqmx_call;
 use crate::error::DaqmxError;
-use ni_daqmx_sys::*;
+use crate::sys::*;
 use std::ffi::CString;
-use std::str::FromStr;
 use std::sync::Arc;
-use crate::channels::ai_channels::{AnalogChannelBuilder, AnalogTerminalConfig};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
 
 pub struct CurrentChannel;
 
 impl ChannelKind for CurrentChannel {}
-impl AnalogInputKind for CurrentChannel {}
-
-impl TaskChannel<CurrentChannel> {
-    pub fn scale(&self) -> Result<CurrentScale, DaqmxError> {
-        let mut initial: CurrentScale = self.property_get(DAQmxGetAICurrentUnits)?;
-
-        if let CurrentScale::CustomScale(None) = initial {
-            let name = self.custom_scale_name()?;
-            let name_cstr = CString::from_str(&name)?;
-            initial = CurrentScale::CustomScale(Some(Arc::new(name_cstr)));
-        }
-        Ok(initial)
-    }
+impl AnalogInputKind for CurrentChannel {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Current);
+}
 
-    pub fn set_scale(&self, scale: CurrentScale) -> Result<(), DaqmxError> {
-        if let CurrentScale::CustomScale(Some(name)) = &scale {
-            self.set_custom_scale_name(name)?;
+impl ScaledInputKind for CurrentChannel {
+    type Scale = CurrentScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAICurrentUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAICurrentUnits;
+}
+impl CurrentInputKind for CurrentChannel {}
+
+/// Kinds of channel which measure a current through a shunt resistor.
+pub trait CurrentInputKind: ScaledInputKind<Scale = CurrentScale> {}
+
+impl<K: CurrentInputKind> TaskChannel<K> {
+    /// Gets the location of the shunt resistor, including its value if it is external.
+    pub fn shunt_resistor_location(&self) -> Result<ShuntResistorLocation, DaqmxError> {
+        let location: ShuntResistorLocation = self.property_get(DAQmxGetAICurrentShuntLoc)?;
+        if let ShuntResistorLocation::External(_) = location {
+            let value = self.property_get(DAQmxGetAICurrentShuntResistance)?;
+            Ok(ShuntResistorLocation::External(value))
+        } else {
+            Ok(location)
         }
-        self.property_set(DAQmxSetAICurrentUnits, scale.clone().into_raw())?;
-        Ok(())
     }
 }
 
@@ -58,6 +63,19 @@ impl CurrentScale {
     }
 }
 
+impl CustomScaleUnits for CurrentScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
 impl PropertyValue for CurrentScale {
     type Raw = i32;
 
@@ -80,6 +98,8 @@ impl PropertyValue for CurrentScale {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShuntResistorLocation {
     Default,
     Internal,
@@ -144,10 +164,7 @@ impl ChannelBuilder for CurrentChannelBuilder {
             ShuntResistorLocation::External(value) => value,
             _ => 249.0,
         };
-        let custom_scale_name = match &self.scale {
-            CurrentScale::CustomScale(Some(name)) => name.as_ptr(),
-            _ => std::ptr::null(),
-        };
+        let custom_scale_name = self.scale.custom_scale_ptr();
         let expected_name = self.name.or(&self.physical_channel).to_owned();
         daqmx_call!(DAQmxCreateAICurrentChan(
             task,
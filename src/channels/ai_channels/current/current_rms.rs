@@ -0,0 +1,110 @@
+use crate::channels::ai_channels::current::current_dc::{
+    CurrentInputKind, CurrentScale, ShuntResistorLocation,
+};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel};
+use crate::daqmx_call;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// The RMS value of a current, calculated over the samples of each read.
+pub struct CurrentRms;
+
+impl ChannelKind for CurrentRms {}
+impl AnalogInputKind for CurrentRms {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::CurrentRms);
+}
+
+impl ScaledInputKind for CurrentRms {
+    type Scale = CurrentScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAICurrentUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAICurrentUnits;
+}
+impl CurrentInputKind for CurrentRms {}
+
+pub struct CurrentRmsChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: CurrentScale,
+    shunt_resistor_location: ShuntResistorLocation,
+    terminal_config: AnalogTerminalConfig,
+}
+
+impl ChannelBuilder for CurrentRmsChannelBuilder {
+    type Kind = CurrentRms;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel.into())?,
+            name: ChannelName::default(),
+            min: -0.01,
+            max: 0.01,
+            scale: CurrentScale::Amps,
+            shunt_resistor_location: ShuntResistorLocation::Default,
+            terminal_config: AnalogTerminalConfig::Default,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let shunt_value = match self.shunt_resistor_location {
+            ShuntResistorLocation::External(value) => value,
+            _ => 249.0,
+        };
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAICurrentRMSChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.terminal_config.into_raw(),
+            self.min,
+            self.max,
+            self.scale.into_raw(),
+            self.shunt_resistor_location.into_raw(),
+            shunt_value,
+            custom_scale_name
+        ))?;
+
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for CurrentRmsChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl CurrentRmsChannelBuilder {
+    pub fn terminal_config(self, terminal_config: AnalogTerminalConfig) -> Self {
+        Self {
+            terminal_config,
+            ..self
+        }
+    }
+
+    pub fn scale(self, scale: CurrentScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn shunt_resistor_location(self, shunt_resistor_location: ShuntResistorLocation) -> Self {
+        Self {
+            shunt_resistor_location,
+            ..self
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod current_dc;
+pub mod current_rms;
@@ -0,0 +1,165 @@
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+use std::sync::Arc;
+
+/// The frequency of a voltage signal, measured by an analog input counting crossings of a
+/// threshold.
+pub struct FrequencyVoltage;
+
+impl ChannelKind for FrequencyVoltage {}
+impl AnalogInputKind for FrequencyVoltage {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::FrequencyVoltage);
+}
+impl ScaledInputKind for FrequencyVoltage {
+    type Scale = FrequencyScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIFreqUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIFreqUnits;
+}
+
+impl TaskChannel<FrequencyVoltage> {
+    property!(
+        /// The voltage level in volts at which a cycle is detected.
+        get_set_reset threshold_level / set_threshold_level / reset_threshold_level: f64 = DAQmxGetAIFreqThreshVoltage, DAQmxSetAIFreqThreshVoltage, DAQmxResetAIFreqThreshVoltage
+    );
+    property!(
+        /// The window in volts below the threshold the signal must pass through before another cycle
+        /// is detected, to reject noise.
+        get_set_reset hysteresis / set_hysteresis / reset_hysteresis: f64 = DAQmxGetAIFreqHyst, DAQmxSetAIFreqHyst, DAQmxResetAIFreqHyst
+    );
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FrequencyScale {
+    Hertz,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl FrequencyScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for FrequencyScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for FrequencyScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Hz => Ok(Self::Hertz),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            _ => Err(DaqmxError::UnexpectedValue("Frequency Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            FrequencyScale::Hertz => DAQmx_Val_Hz,
+            FrequencyScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+pub struct FrequencyVoltageChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: FrequencyScale,
+    threshold_level: f64,
+    hysteresis: f64,
+}
+
+impl ChannelBuilder for FrequencyVoltageChannelBuilder {
+    type Kind = FrequencyVoltage;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: 1.0,
+            max: 100.0,
+            scale: FrequencyScale::Hertz,
+            threshold_level: 0.0,
+            hysteresis: 0.0,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIFreqVoltageChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.threshold_level,
+            self.hysteresis,
+            custom_scale_name
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for FrequencyVoltageChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl FrequencyVoltageChannelBuilder {
+    pub fn scale(self, scale: FrequencyScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// The voltage level in volts at which a cycle is detected.
+    pub fn threshold_level(self, threshold_level: f64) -> Self {
+        Self {
+            threshold_level,
+            ..self
+        }
+    }
+
+    /// The window in volts below the threshold level that the signal must pass through before
+    /// another cycle is detected.
+    pub fn hysteresis(self, hysteresis: f64) -> Self {
+        Self { hysteresis, ..self }
+    }
+}
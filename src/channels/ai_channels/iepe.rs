@@ -0,0 +1,710 @@
+//! Channels for IEPE sensors, such as accelerometers and microphones, which are powered by a
+//! current excitation from the device.
+use crate::channels::ai_channels::resistance::{ExcitationSource, ExcitedInputKind};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+use std::sync::Arc;
+
+/// Kinds of channel which measure an IEPE sensor.
+pub trait IepeInputKind: ExcitedInputKind {}
+
+impl<K: IepeInputKind> TaskChannel<K> {
+    property!(get_set excitation_current / set_excitation_current: f64 = DAQmxGetAIExcitVal, DAQmxSetAIExcitVal);
+    property!(get_set coupling / set_coupling: Coupling = DAQmxGetAICoupling, DAQmxSetAICoupling);
+}
+
+/// The input coupling of the channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Coupling {
+    /// Removes the DC offset, such as the bias voltage of an IEPE sensor.
+    AC,
+    DC,
+    /// Connects the input to ground, e.g. to measure the noise floor.
+    Ground,
+}
+
+impl PropertyValue for Coupling {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_AC => Ok(Coupling::AC),
+            DAQmx_Val_DC => Ok(Coupling::DC),
+            DAQmx_Val_GND => Ok(Coupling::Ground),
+            _ => Err(DaqmxError::UnexpectedValue("Coupling", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            Coupling::AC => DAQmx_Val_AC,
+            Coupling::DC => DAQmx_Val_DC,
+            Coupling::Ground => DAQmx_Val_GND,
+        }
+    }
+}
+
+/// Set the coupling on a new channel, as the create functions have no coupling parameter.
+fn apply_coupling<K: IepeInputKind>(
+    channel: TaskChannel<K>,
+    coupling: Option<Coupling>,
+) -> crate::error::Result<TaskChannel<K>> {
+    if let Some(coupling) = coupling {
+        channel.set_coupling(coupling)?;
+    }
+    Ok(channel)
+}
+
+/// An IEPE accelerometer channel.
+pub struct Accelerometer;
+
+impl ChannelKind for Accelerometer {}
+impl AnalogInputKind for Accelerometer {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Accelerometer);
+}
+impl ExcitedInputKind for Accelerometer {}
+impl IepeInputKind for Accelerometer {}
+impl ScaledInputKind for Accelerometer {
+    type Scale = AccelerationScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIAccelUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIAccelUnits;
+}
+
+impl TaskChannel<Accelerometer> {
+    property!(get_set sensitivity / set_sensitivity: f64 = DAQmxGetAIAccelSensitivity, DAQmxSetAIAccelSensitivity);
+    property!(get_set sensitivity_units / set_sensitivity_units: AccelerometerSensitivityUnits = DAQmxGetAIAccelSensitivityUnits, DAQmxSetAIAccelSensitivityUnits);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AccelerationScale {
+    G,
+    MetersPerSecondSquared,
+    InchesPerSecondSquared,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl AccelerationScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for AccelerationScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for AccelerationScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_AccelUnit_g => Ok(Self::G),
+            DAQmx_Val_MetersPerSecondSquared => Ok(Self::MetersPerSecondSquared),
+            DAQmx_Val_InchesPerSecondSquared => Ok(Self::InchesPerSecondSquared),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            _ => Err(DaqmxError::UnexpectedValue("Acceleration Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            AccelerationScale::G => DAQmx_Val_AccelUnit_g,
+            AccelerationScale::MetersPerSecondSquared => DAQmx_Val_MetersPerSecondSquared,
+            AccelerationScale::InchesPerSecondSquared => DAQmx_Val_InchesPerSecondSquared,
+            AccelerationScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccelerometerSensitivityUnits {
+    MilliVoltsPerG,
+    VoltsPerG,
+}
+
+impl PropertyValue for AccelerometerSensitivityUnits {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_mVoltsPerG => Ok(Self::MilliVoltsPerG),
+            DAQmx_Val_VoltsPerG => Ok(Self::VoltsPerG),
+            _ => Err(DaqmxError::UnexpectedValue(
+                "AccelerometerSensitivityUnits",
+                raw,
+            )),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            AccelerometerSensitivityUnits::MilliVoltsPerG => DAQmx_Val_mVoltsPerG,
+            AccelerometerSensitivityUnits::VoltsPerG => DAQmx_Val_VoltsPerG,
+        }
+    }
+}
+
+pub struct AccelerometerChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: AccelerationScale,
+    terminal_config: AnalogTerminalConfig,
+    sensitivity: f64,
+    sensitivity_units: AccelerometerSensitivityUnits,
+    excitation_source: ExcitationSource,
+    excitation_current: f64,
+    coupling: Option<Coupling>,
+}
+
+impl ChannelBuilder for AccelerometerChannelBuilder {
+    type Kind = Accelerometer;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: -5.0,
+            max: 5.0,
+            scale: AccelerationScale::G,
+            terminal_config: AnalogTerminalConfig::Default,
+            sensitivity: 1000.0,
+            sensitivity_units: AccelerometerSensitivityUnits::MilliVoltsPerG,
+            excitation_source: ExcitationSource::Internal,
+            excitation_current: 0.004,
+            coupling: None,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIAccelChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.terminal_config.into_raw(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.sensitivity,
+            self.sensitivity_units.into_raw(),
+            self.excitation_source.into_raw(),
+            self.excitation_current,
+            custom_scale_name
+        ))?;
+        apply_coupling(TaskChannel::new(task, expected_name), self.coupling)
+    }
+}
+
+impl AnalogChannelBuilder for AccelerometerChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl AccelerometerChannelBuilder {
+    pub fn scale(self, scale: AccelerationScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn terminal_config(self, terminal_config: AnalogTerminalConfig) -> Self {
+        Self {
+            terminal_config,
+            ..self
+        }
+    }
+
+    /// The sensitivity from the sensor datasheet or calibration, in the sensitivity units.
+    pub fn sensitivity(self, sensitivity: f64) -> Self {
+        Self {
+            sensitivity,
+            ..self
+        }
+    }
+
+    pub fn sensitivity_units(self, sensitivity_units: AccelerometerSensitivityUnits) -> Self {
+        Self {
+            sensitivity_units,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_current(self, excitation_current: f64) -> Self {
+        Self {
+            excitation_current,
+            ..self
+        }
+    }
+
+    /// Set the coupling. If this isn't set, the device default is used.
+    ///
+    /// The create function has no coupling parameter, so this is set once the channel is created.
+    /// If DAQmx rejects it, [`ChannelBuilder::add_to_task`] returns the error but the channel
+    /// remains in the task.
+    pub fn coupling(self, coupling: Coupling) -> Self {
+        Self {
+            coupling: Some(coupling),
+            ..self
+        }
+    }
+}
+
+/// An IEPE microphone channel, measuring sound pressure.
+pub struct Microphone;
+
+impl ChannelKind for Microphone {}
+impl AnalogInputKind for Microphone {
+    const MEASUREMENT_TYPE: Option<MeasurementType> =
+        Some(MeasurementType::SoundPressureMicrophone);
+}
+impl ExcitedInputKind for Microphone {}
+impl IepeInputKind for Microphone {}
+impl ScaledInputKind for Microphone {
+    type Scale = SoundPressureScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAISoundPressureUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAISoundPressureUnits;
+}
+
+impl TaskChannel<Microphone> {
+    property!(
+        /// The sensitivity in mV/Pa.
+        get_set sensitivity / set_sensitivity: f64 = DAQmxGetAIMicrophoneSensitivity, DAQmxSetAIMicrophoneSensitivity
+    );
+    property!(
+        /// The maximum instantaneous sound pressure level in dB, which sets the input range.
+        get_set max_sound_pressure_level / set_max_sound_pressure_level: f64 = DAQmxGetAISoundPressureMaxSoundPressureLvl, DAQmxSetAISoundPressureMaxSoundPressureLvl
+    );
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SoundPressureScale {
+    Pascals,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl SoundPressureScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for SoundPressureScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for SoundPressureScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Pascals => Ok(Self::Pascals),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            _ => Err(DaqmxError::UnexpectedValue("Sound Pressure Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            SoundPressureScale::Pascals => DAQmx_Val_Pascals,
+            SoundPressureScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+pub struct MicrophoneChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    scale: SoundPressureScale,
+    terminal_config: AnalogTerminalConfig,
+    sensitivity: f64,
+    max_sound_pressure_level: f64,
+    excitation_source: ExcitationSource,
+    excitation_current: f64,
+    coupling: Option<Coupling>,
+}
+
+impl ChannelBuilder for MicrophoneChannelBuilder {
+    type Kind = Microphone;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            scale: SoundPressureScale::Pascals,
+            terminal_config: AnalogTerminalConfig::Default,
+            sensitivity: 10.0,
+            max_sound_pressure_level: 100.0,
+            excitation_source: ExcitationSource::Internal,
+            excitation_current: 0.004,
+            coupling: None,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIMicrophoneChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.terminal_config.into_raw(),
+            self.scale.clone().into_raw(),
+            self.sensitivity,
+            self.max_sound_pressure_level,
+            self.excitation_source.into_raw(),
+            self.excitation_current,
+            custom_scale_name
+        ))?;
+        apply_coupling(TaskChannel::new(task, expected_name), self.coupling)
+    }
+}
+
+// The microphone range is set by the maximum sound pressure level rather than a min and max, so
+// this builder doesn't implement `AnalogChannelBuilder`.
+impl MicrophoneChannelBuilder {
+    pub fn scale(self, scale: SoundPressureScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn terminal_config(self, terminal_config: AnalogTerminalConfig) -> Self {
+        Self {
+            terminal_config,
+            ..self
+        }
+    }
+
+    /// The sensitivity in mV/Pa.
+    pub fn sensitivity(self, sensitivity: f64) -> Self {
+        Self {
+            sensitivity,
+            ..self
+        }
+    }
+
+    /// The maximum instantaneous sound pressure level to measure, in dB.
+    pub fn max_sound_pressure_level(self, max_sound_pressure_level: f64) -> Self {
+        Self {
+            max_sound_pressure_level,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_current(self, excitation_current: f64) -> Self {
+        Self {
+            excitation_current,
+            ..self
+        }
+    }
+
+    /// Set the coupling. If this isn't set, the device default is used.
+    ///
+    /// The create function has no coupling parameter, so this is set once the channel is created.
+    /// If DAQmx rejects it, [`ChannelBuilder::add_to_task`] returns the error but the channel
+    /// remains in the task.
+    pub fn coupling(self, coupling: Coupling) -> Self {
+        Self {
+            coupling: Some(coupling),
+            ..self
+        }
+    }
+}
+
+/// An IEPE force sensor channel.
+pub struct ForceIepe;
+
+impl ChannelKind for ForceIepe {}
+impl AnalogInputKind for ForceIepe {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::ForceIepe);
+}
+impl ExcitedInputKind for ForceIepe {}
+impl IepeInputKind for ForceIepe {}
+impl ScaledInputKind for ForceIepe {
+    type Scale = ForceScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIForceUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIForceUnits;
+}
+
+impl TaskChannel<ForceIepe> {
+    property!(get_set sensitivity / set_sensitivity: f64 = DAQmxGetAIForceIEPESensorSensitivity, DAQmxSetAIForceIEPESensorSensitivity);
+    property!(get_set sensitivity_units / set_sensitivity_units: ForceIepeSensitivityUnits = DAQmxGetAIForceIEPESensorSensitivityUnits, DAQmxSetAIForceIEPESensorSensitivityUnits);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ForceScale {
+    Newtons,
+    Pounds,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl ForceScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for ForceScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for ForceScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Newtons => Ok(Self::Newtons),
+            DAQmx_Val_Pounds => Ok(Self::Pounds),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            _ => Err(DaqmxError::UnexpectedValue("Force Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            ForceScale::Newtons => DAQmx_Val_Newtons,
+            ForceScale::Pounds => DAQmx_Val_Pounds,
+            ForceScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForceIepeSensitivityUnits {
+    MilliVoltsPerNewton,
+    MilliVoltsPerPound,
+}
+
+impl PropertyValue for ForceIepeSensitivityUnits {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_mVoltsPerNewton => Ok(Self::MilliVoltsPerNewton),
+            DAQmx_Val_mVoltsPerPound => Ok(Self::MilliVoltsPerPound),
+            _ => Err(DaqmxError::UnexpectedValue(
+                "ForceIepeSensitivityUnits",
+                raw,
+            )),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            ForceIepeSensitivityUnits::MilliVoltsPerNewton => DAQmx_Val_mVoltsPerNewton,
+            ForceIepeSensitivityUnits::MilliVoltsPerPound => DAQmx_Val_mVoltsPerPound,
+        }
+    }
+}
+
+pub struct ForceIepeChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: ForceScale,
+    terminal_config: AnalogTerminalConfig,
+    sensitivity: f64,
+    sensitivity_units: ForceIepeSensitivityUnits,
+    excitation_source: ExcitationSource,
+    excitation_current: f64,
+    coupling: Option<Coupling>,
+}
+
+impl ChannelBuilder for ForceIepeChannelBuilder {
+    type Kind = ForceIepe;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: -2000.0,
+            max: 2000.0,
+            scale: ForceScale::Newtons,
+            terminal_config: AnalogTerminalConfig::Default,
+            sensitivity: 2.25,
+            sensitivity_units: ForceIepeSensitivityUnits::MilliVoltsPerNewton,
+            excitation_source: ExcitationSource::Internal,
+            excitation_current: 0.004,
+            coupling: None,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIForceIEPEChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.terminal_config.into_raw(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.sensitivity,
+            self.sensitivity_units.into_raw(),
+            self.excitation_source.into_raw(),
+            self.excitation_current,
+            custom_scale_name
+        ))?;
+        apply_coupling(TaskChannel::new(task, expected_name), self.coupling)
+    }
+}
+
+impl AnalogChannelBuilder for ForceIepeChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl ForceIepeChannelBuilder {
+    pub fn scale(self, scale: ForceScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn terminal_config(self, terminal_config: AnalogTerminalConfig) -> Self {
+        Self {
+            terminal_config,
+            ..self
+        }
+    }
+
+    /// The sensitivity from the sensor datasheet or calibration, in the sensitivity units.
+    pub fn sensitivity(self, sensitivity: f64) -> Self {
+        Self {
+            sensitivity,
+            ..self
+        }
+    }
+
+    pub fn sensitivity_units(self, sensitivity_units: ForceIepeSensitivityUnits) -> Self {
+        Self {
+            sensitivity_units,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_current(self, excitation_current: f64) -> Self {
+        Self {
+            excitation_current,
+            ..self
+        }
+    }
+
+    /// Set the coupling. If this isn't set, the device default is used.
+    ///
+    /// The create function has no coupling parameter, so this is set once the channel is created.
+    /// If DAQmx rejects it, [`ChannelBuilder::add_to_task`] returns the error but the channel
+    /// remains in the task.
+    pub fn coupling(self, coupling: Coupling) -> Self {
+        Self {
+            coupling: Some(coupling),
+            ..self
+        }
+    }
+}
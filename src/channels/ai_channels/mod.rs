@@ -1,23 +1,115 @@
+pub mod bridge;
+pub mod bridge_sensor;
 pub mod current;
+pub mod frequency;
+pub mod iepe;
 pub mod resistance;
+pub mod strain;
 pub mod temperature;
 pub mod voltage;
 
-use super::properties::PropertyValue;
+use super::properties::{PropertyValue, ScalarGetter, ScalarSetter};
 use super::{ChannelBuilder, ChannelKind, TaskChannel, property};
 use crate::error::{DaqmxError, Result};
-use ni_daqmx_sys::*;
-use std::ffi::CStr;
+use crate::sys::*;
+use std::ffi::{CStr, CString, c_char};
+use std::sync::Arc;
 
-pub trait AnalogInputKind: ChannelKind {}
+pub trait AnalogInputKind: ChannelKind {
+    /// The measurement type of channels of this kind, used to check a channel is this kind.
+    ///
+    /// [`None`] if the kind can be used for any analog input channel.
+    const MEASUREMENT_TYPE: Option<MeasurementType>;
+}
+
+/// Kinds of channel whose units are selected by a scale, which may be a custom scale.
+///
+/// This selects the DAQmx functions for the units of each kind, so the scale accessors are
+/// implemented once for all of them.
+pub trait ScaledInputKind: AnalogInputKind {
+    type Scale: CustomScaleUnits;
+    const UNITS_GETTER: ScalarGetter<i32>;
+    const UNITS_SETTER: ScalarSetter<i32>;
+}
+
+/// A scale which may select a custom scale for the units of the channel.
+pub trait CustomScaleUnits: PropertyValue<Raw = i32> {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self;
+    /// `Some` if this is a custom scale, containing the name if it has been determined.
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>>;
+
+    /// The custom scale name to pass to a create function, or null if there isn't one.
+    fn custom_scale_ptr(&self) -> *const c_char {
+        match self.custom_scale() {
+            Some(Some(name)) => name.as_ptr(),
+            _ => std::ptr::null(),
+        }
+    }
+}
+
+impl<K: ScaledInputKind> TaskChannel<K> {
+    pub fn scale(&self) -> Result<K::Scale> {
+        let scale: K::Scale = self.property_get(K::UNITS_GETTER)?;
+
+        if let Some(None) = scale.custom_scale() {
+            let name = self.custom_scale_name()?;
+            Ok(K::Scale::from_custom_scale_name(Arc::new(CString::new(
+                name,
+            )?)))
+        } else {
+            Ok(scale)
+        }
+    }
+
+    pub fn set_scale(&self, scale: K::Scale) -> Result<()> {
+        if let Some(Some(name)) = scale.custom_scale() {
+            self.set_custom_scale_name(name)?;
+        }
+        self.property_set(K::UNITS_SETTER, scale)
+    }
+}
+
+/// An analog input channel of any kind, exposing the properties common to all analog inputs.
+pub struct AnyAnalogInput;
+
+impl ChannelKind for AnyAnalogInput {}
+impl AnalogInputKind for AnyAnalogInput {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = None;
+}
+
+/// An analog input channel from a task where the kind is only known at runtime.
+///
+/// See [`Task::get_any_channel`](crate::tasks::Task::get_any_channel).
+pub enum AnyAnalogInputChannel {
+    Voltage(TaskChannel<voltage::Voltage>),
+    VoltageRms(TaskChannel<voltage::VoltageRms>),
+    Current(TaskChannel<current::current_dc::CurrentChannel>),
+    CurrentRms(TaskChannel<current::current_rms::CurrentRms>),
+    FrequencyVoltage(TaskChannel<frequency::FrequencyVoltage>),
+    Rtd(TaskChannel<temperature::Rtd>),
+    Thermocouple(TaskChannel<temperature::Thermocouple>),
+    Thermistor(TaskChannel<temperature::Thermistor>),
+    BuiltInTemperatureSensor(TaskChannel<temperature::BuiltInTemperatureSensor>),
+    StrainGage(TaskChannel<strain::StrainGage>),
+    Bridge(TaskChannel<bridge::Bridge>),
+    Resistance(TaskChannel<resistance::Resistance>),
+    Accelerometer(TaskChannel<iepe::Accelerometer>),
+    Microphone(TaskChannel<iepe::Microphone>),
+    ForceIepe(TaskChannel<iepe::ForceIepe>),
+    PressureBridge(TaskChannel<bridge_sensor::PressureBridge>),
+    ForceBridge(TaskChannel<bridge_sensor::ForceBridge>),
+    TorqueBridge(TaskChannel<bridge_sensor::TorqueBridge>),
+    /// A measurement type without a dedicated kind in this crate.
+    Other(MeasurementType, TaskChannel<AnyAnalogInput>),
+}
 
 impl<K: AnalogInputKind> TaskChannel<K> {
-    property!(get_string physical_channel = ni_daqmx_sys::DAQmxGetPhysicalChanName);
+    property!(get measurement_type: MeasurementType = DAQmxGetAIMeasType);
     property!(get_set ai_max / set_ai_max: f64 = DAQmxGetAIMax, DAQmxSetAIMax);
     property!(get_set ai_min / set_ai_min: f64 = DAQmxGetAIMin, DAQmxSetAIMin);
     property!(get_set terminal_config / set_terminal_config:
               AnalogTerminalConfig = DAQmxGetAITermCfg, DAQmxSetAITermCfg);
-    property!(get_string custom_scale_name = ni_daqmx_sys::DAQmxGetAICustomScaleName);
+    property!(get_string custom_scale_name = crate::sys::DAQmxGetAICustomScaleName);
 
     pub fn set_custom_scale_name(&self, name: &CStr) -> Result<()> {
         self.property_set_raw(DAQmxSetAICustomScaleName, name.as_ptr())
@@ -30,6 +122,7 @@ impl<K: AnalogInputKind> TaskChannel<K> {
 
 #[repr(i32)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Defines the input configuration for the analog input.
 pub enum AnalogTerminalConfig {
     /// Uses the [default for the type/hardware combination](https://www.ni.com/docs/en-US/bundle/ni-daqmx-device-considerations/page/defaulttermconfig.html).
@@ -80,3 +173,109 @@ pub trait AnalogChannelBuilder: ChannelBuilder {
     fn max(self, max: f64) -> Self;
     fn min(self, min: f64) -> Self;
 }
+
+/// The measurement types of analog input channels.
+///
+/// These are reported by devices and physical channels as the measurements they support.
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MeasurementType {
+    Voltage = DAQmx_Val_Voltage,
+    VoltageRms = DAQmx_Val_VoltageRMS,
+    Current = DAQmx_Val_Current,
+    CurrentRms = DAQmx_Val_CurrentRMS,
+    VoltageCustomWithExcitation = DAQmx_Val_Voltage_CustomWithExcitation,
+    Bridge = DAQmx_Val_Bridge,
+    FrequencyVoltage = DAQmx_Val_Freq_Voltage,
+    Resistance = DAQmx_Val_Resistance,
+    Thermocouple = DAQmx_Val_Temp_TC,
+    Thermistor = DAQmx_Val_Temp_Thrmstr,
+    Rtd = DAQmx_Val_Temp_RTD,
+    BuiltInTemperatureSensor = DAQmx_Val_Temp_BuiltInSensor,
+    StrainGage = DAQmx_Val_Strain_Gage,
+    RosetteStrainGage = DAQmx_Val_Rosette_Strain_Gage,
+    PositionLvdt = DAQmx_Val_Position_LVDT,
+    PositionRvdt = DAQmx_Val_Position_RVDT,
+    PositionEddyCurrentProximityProbe = DAQmx_Val_Position_EddyCurrentProximityProbe,
+    Accelerometer = DAQmx_Val_Accelerometer,
+    AccelerationCharge = DAQmx_Val_Acceleration_Charge,
+    Acceleration4WireDcVoltage = DAQmx_Val_Acceleration_4WireDCVoltage,
+    VelocityIepe = DAQmx_Val_Velocity_IEPESensor,
+    ForceBridge = DAQmx_Val_Force_Bridge,
+    ForceIepe = DAQmx_Val_Force_IEPESensor,
+    PressureBridge = DAQmx_Val_Pressure_Bridge,
+    SoundPressureMicrophone = DAQmx_Val_SoundPressure_Microphone,
+    TorqueBridge = DAQmx_Val_Torque_Bridge,
+    TedsSensor = DAQmx_Val_TEDS_Sensor,
+    Charge = DAQmx_Val_Charge,
+    Power = DAQmx_Val_Power,
+}
+
+impl PropertyValue for MeasurementType {
+    type Raw = i32;
+
+    fn from_raw(value: i32) -> Result<Self> {
+        #[allow(non_upper_case_globals)]
+        match value {
+            DAQmx_Val_Voltage => Ok(Self::Voltage),
+            DAQmx_Val_VoltageRMS => Ok(Self::VoltageRms),
+            DAQmx_Val_Current => Ok(Self::Current),
+            DAQmx_Val_CurrentRMS => Ok(Self::CurrentRms),
+            DAQmx_Val_Voltage_CustomWithExcitation => Ok(Self::VoltageCustomWithExcitation),
+            DAQmx_Val_Bridge => Ok(Self::Bridge),
+            DAQmx_Val_Freq_Voltage => Ok(Self::FrequencyVoltage),
+            DAQmx_Val_Resistance => Ok(Self::Resistance),
+            DAQmx_Val_Temp_TC => Ok(Self::Thermocouple),
+            DAQmx_Val_Temp_Thrmstr => Ok(Self::Thermistor),
+            DAQmx_Val_Temp_RTD => Ok(Self::Rtd),
+            DAQmx_Val_Temp_BuiltInSensor => Ok(Self::BuiltInTemperatureSensor),
+            DAQmx_Val_Strain_Gage => Ok(Self::StrainGage),
+            DAQmx_Val_Rosette_Strain_Gage => Ok(Self::RosetteStrainGage),
+            DAQmx_Val_Position_LVDT => Ok(Self::PositionLvdt),
+            DAQmx_Val_Position_RVDT => Ok(Self::PositionRvdt),
+            DAQmx_Val_Position_EddyCurrentProximityProbe => {
+                Ok(Self::PositionEddyCurrentProximityProbe)
+            }
+            DAQmx_Val_Accelerometer => Ok(Self::Accelerometer),
+            DAQmx_Val_Acceleration_Charge => Ok(Self::AccelerationCharge),
+            DAQmx_Val_Acceleration_4WireDCVoltage => Ok(Self::Acceleration4WireDcVoltage),
+            DAQmx_Val_Velocity_IEPESensor => Ok(Self::VelocityIepe),
+            DAQmx_Val_Force_Bridge => Ok(Self::ForceBridge),
+            DAQmx_Val_Force_IEPESensor => Ok(Self::ForceIepe),
+            DAQmx_Val_Pressure_Bridge => Ok(Self::PressureBridge),
+            DAQmx_Val_SoundPressure_Microphone => Ok(Self::SoundPressureMicrophone),
+            DAQmx_Val_Torque_Bridge => Ok(Self::TorqueBridge),
+            DAQmx_Val_TEDS_Sensor => Ok(Self::TedsSensor),
+            DAQmx_Val_Charge => Ok(Self::Charge),
+            DAQmx_Val_Power => Ok(Self::Power),
+            _ => Err(DaqmxError::UnexpectedValue("MeasurementType", value)),
+        }
+    }
+
+    fn into_raw(self) -> i32 {
+        self as i32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measurement_type_round_trip() {
+        let raw = MeasurementType::Thermocouple.into_raw();
+        assert_eq!(raw, DAQmx_Val_Temp_TC);
+        assert_eq!(
+            MeasurementType::from_raw(raw).unwrap(),
+            MeasurementType::Thermocouple
+        );
+    }
+
+    #[test]
+    fn test_measurement_type_unexpected() {
+        assert_eq!(
+            MeasurementType::from_raw(0),
+            Err(DaqmxError::UnexpectedValue("MeasurementType", 0))
+        );
+    }
+}
@@ -1,9 +1,35 @@
-use crate::channels::properties::PropertyValue;
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
 use crate::error::DaqmxError;
-use ni_daqmx_sys::*;
+use crate::sys::*;
+use std::ffi::CString;
+use std::sync::Arc;
 
-/// The excitation source for a resistance channel.
+/// Kinds of channel which are powered by an excitation source, either from the device or
+/// externally.
+pub trait ExcitedInputKind: AnalogInputKind {}
+
+impl<K: ExcitedInputKind> TaskChannel<K> {
+    property!(get_set excitation_source / set_excitation_source: ExcitationSource = DAQmxGetAIExcitSrc, DAQmxSetAIExcitSrc);
+}
+
+/// Kinds of channel which can compensate for the resistance of the wires leading to the sensor.
+pub trait LeadWireInputKind: ExcitedInputKind {}
+
+impl<K: LeadWireInputKind> TaskChannel<K> {
+    property!(
+        /// The resistance of each wire leading to the sensor, in ohms.
+        get_set_reset lead_wire_resistance / set_lead_wire_resistance / reset_lead_wire_resistance: f64 = DAQmxGetAILeadWireResistance, DAQmxSetAILeadWireResistance, DAQmxResetAILeadWireResistance
+    );
+}
+
+/// The excitation source for an excited channel.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExcitationSource {
     Internal,
     External,
@@ -34,6 +60,7 @@ impl PropertyValue for ExcitationSource {
 
 /// The wire configuration for a resistance measurement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WireConfiguration {
     TwoWire,
     ThreeWire,
@@ -61,3 +88,185 @@ impl PropertyValue for WireConfiguration {
         }
     }
 }
+
+/// A resistance channel, measuring with a current excitation.
+pub struct Resistance;
+
+impl ChannelKind for Resistance {}
+impl AnalogInputKind for Resistance {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Resistance);
+}
+impl ExcitedInputKind for Resistance {}
+impl LeadWireInputKind for Resistance {}
+impl ScaledInputKind for Resistance {
+    type Scale = ResistanceScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIResistanceUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIResistanceUnits;
+}
+
+impl TaskChannel<Resistance> {
+    property!(get_set excitation_current / set_excitation_current: f64 = DAQmxGetAIExcitVal, DAQmxSetAIExcitVal);
+    property!(get_set wire_configuration / set_wire_configuration: WireConfiguration = DAQmxGetAIResistanceCfg, DAQmxSetAIResistanceCfg);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ResistanceScale {
+    Ohms,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+    /// Units are set from the TEDS configuration. This case should be read only.
+    FromTEDS,
+}
+
+impl ResistanceScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for ResistanceScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for ResistanceScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Ohms => Ok(Self::Ohms),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            DAQmx_Val_FromTEDS => Ok(Self::FromTEDS),
+            _ => Err(DaqmxError::UnexpectedValue("Resistance Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            ResistanceScale::Ohms => DAQmx_Val_Ohms,
+            ResistanceScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+            ResistanceScale::FromTEDS => DAQmx_Val_FromTEDS,
+        }
+    }
+}
+
+pub struct ResistanceChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: ResistanceScale,
+    wire_configuration: WireConfiguration,
+    excitation_source: ExcitationSource,
+    excitation_current: f64,
+    lead_wire_resistance: Option<f64>,
+}
+
+impl ChannelBuilder for ResistanceChannelBuilder {
+    type Kind = Resistance;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: 100.0,
+            max: 1000.0,
+            scale: ResistanceScale::Ohms,
+            wire_configuration: WireConfiguration::FourWire,
+            excitation_source: ExcitationSource::Internal,
+            excitation_current: 0.001,
+            lead_wire_resistance: None,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIResistanceChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.wire_configuration.into_raw(),
+            self.excitation_source.into_raw(),
+            self.excitation_current,
+            custom_scale_name
+        ))?;
+        let channel: TaskChannel<Resistance> = TaskChannel::new(task, expected_name);
+        // The lead wire resistance isn't a parameter of the create function.
+        if let Some(resistance) = self.lead_wire_resistance {
+            channel.set_lead_wire_resistance(resistance)?;
+        }
+        Ok(channel)
+    }
+}
+
+impl AnalogChannelBuilder for ResistanceChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl ResistanceChannelBuilder {
+    pub fn scale(self, scale: ResistanceScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn wire_configuration(self, wire_configuration: WireConfiguration) -> Self {
+        Self {
+            wire_configuration,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_current(self, excitation_current: f64) -> Self {
+        Self {
+            excitation_current,
+            ..self
+        }
+    }
+
+    /// The resistance of each wire leading to the sensor, in ohms.
+    ///
+    /// The create function has no lead wire parameter, so this is set once the channel is created.
+    /// If DAQmx rejects the value, [`ChannelBuilder::add_to_task`] returns the error but the
+    /// channel remains in the task.
+    pub fn lead_wire_resistance(self, lead_wire_resistance: f64) -> Self {
+        Self {
+            lead_wire_resistance: Some(lead_wire_resistance),
+            ..self
+        }
+    }
+}
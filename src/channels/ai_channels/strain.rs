@@ -0,0 +1,276 @@
+use crate::channels::ai_channels::bridge::BridgeInputKind;
+use crate::channels::ai_channels::resistance::{
+    ExcitationSource, ExcitedInputKind, LeadWireInputKind,
+};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+use std::sync::Arc;
+
+/// A strain gage channel.
+///
+/// The bridge properties, such as the excitation and nominal gage resistance, are available
+/// through [`BridgeInputKind`].
+pub struct StrainGage;
+
+impl ChannelKind for StrainGage {}
+impl AnalogInputKind for StrainGage {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::StrainGage);
+}
+impl ExcitedInputKind for StrainGage {}
+impl LeadWireInputKind for StrainGage {}
+impl BridgeInputKind for StrainGage {}
+impl ScaledInputKind for StrainGage {
+    type Scale = StrainScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIStrainUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIStrainUnits;
+}
+
+impl TaskChannel<StrainGage> {
+    property!(get_set strain_gage_configuration / set_strain_gage_configuration: StrainGageConfiguration = DAQmxGetAIStrainGageCfg, DAQmxSetAIStrainGageCfg);
+    property!(get_set gage_factor / set_gage_factor: f64 = DAQmxGetAIStrainGageGageFactor, DAQmxSetAIStrainGageGageFactor);
+    property!(get_set poisson_ratio / set_poisson_ratio: f64 = DAQmxGetAIStrainGagePoissonRatio, DAQmxSetAIStrainGagePoissonRatio);
+}
+
+/// The strain gage bridge configurations, which set how many gages are active and how they are
+/// arranged to compensate for bending, axial strain and temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StrainGageConfiguration {
+    FullBridgeI,
+    FullBridgeII,
+    FullBridgeIII,
+    HalfBridgeI,
+    HalfBridgeII,
+    QuarterBridgeI,
+    QuarterBridgeII,
+}
+
+impl PropertyValue for StrainGageConfiguration {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_FullBridgeI => Ok(StrainGageConfiguration::FullBridgeI),
+            DAQmx_Val_FullBridgeII => Ok(StrainGageConfiguration::FullBridgeII),
+            DAQmx_Val_FullBridgeIII => Ok(StrainGageConfiguration::FullBridgeIII),
+            DAQmx_Val_HalfBridgeI => Ok(StrainGageConfiguration::HalfBridgeI),
+            DAQmx_Val_HalfBridgeII => Ok(StrainGageConfiguration::HalfBridgeII),
+            DAQmx_Val_QuarterBridgeI => Ok(StrainGageConfiguration::QuarterBridgeI),
+            DAQmx_Val_QuarterBridgeII => Ok(StrainGageConfiguration::QuarterBridgeII),
+            _ => Err(DaqmxError::UnexpectedValue("StrainGageConfiguration", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            StrainGageConfiguration::FullBridgeI => DAQmx_Val_FullBridgeI,
+            StrainGageConfiguration::FullBridgeII => DAQmx_Val_FullBridgeII,
+            StrainGageConfiguration::FullBridgeIII => DAQmx_Val_FullBridgeIII,
+            StrainGageConfiguration::HalfBridgeI => DAQmx_Val_HalfBridgeI,
+            StrainGageConfiguration::HalfBridgeII => DAQmx_Val_HalfBridgeII,
+            StrainGageConfiguration::QuarterBridgeI => DAQmx_Val_QuarterBridgeI,
+            StrainGageConfiguration::QuarterBridgeII => DAQmx_Val_QuarterBridgeII,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum StrainScale {
+    Strain,
+    /// A custom scale is in use. If we have not determined the name yet then this contains `None`.
+    /// If we have determined the name, it will be contained in the option.
+    CustomScale(Option<Arc<CString>>),
+}
+
+impl StrainScale {
+    pub fn new_custom(name: &str) -> Result<Self, DaqmxError> {
+        Ok(Self::CustomScale(Some(Arc::new(CString::new(name)?))))
+    }
+
+    pub fn new_custom_cstr(name: CString) -> Self {
+        Self::CustomScale(Some(Arc::new(name)))
+    }
+}
+
+impl CustomScaleUnits for StrainScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for StrainScale {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Strain => Ok(Self::Strain),
+            DAQmx_Val_FromCustomScale => Ok(Self::CustomScale(None)),
+            _ => Err(DaqmxError::UnexpectedValue("Strain Scale", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            StrainScale::Strain => DAQmx_Val_Strain,
+            StrainScale::CustomScale(_) => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+pub struct StrainGageChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: StrainScale,
+    strain_gage_configuration: StrainGageConfiguration,
+    excitation_source: ExcitationSource,
+    excitation_voltage: f64,
+    gage_factor: f64,
+    initial_bridge_voltage: f64,
+    nominal_gage_resistance: f64,
+    poisson_ratio: f64,
+    lead_wire_resistance: f64,
+}
+
+impl ChannelBuilder for StrainGageChannelBuilder {
+    type Kind = StrainGage;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: -0.001,
+            max: 0.001,
+            scale: StrainScale::Strain,
+            strain_gage_configuration: StrainGageConfiguration::FullBridgeI,
+            excitation_source: ExcitationSource::Internal,
+            excitation_voltage: 2.5,
+            gage_factor: 2.0,
+            initial_bridge_voltage: 0.0,
+            nominal_gage_resistance: 350.0,
+            poisson_ratio: 0.3,
+            lead_wire_resistance: 0.0,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = self.scale.custom_scale_ptr();
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAIStrainGageChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            self.strain_gage_configuration.into_raw(),
+            self.excitation_source.into_raw(),
+            self.excitation_voltage,
+            self.gage_factor,
+            self.initial_bridge_voltage,
+            self.nominal_gage_resistance,
+            self.poisson_ratio,
+            self.lead_wire_resistance,
+            custom_scale_name
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for StrainGageChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl StrainGageChannelBuilder {
+    pub fn scale(self, scale: StrainScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn strain_gage_configuration(
+        self,
+        strain_gage_configuration: StrainGageConfiguration,
+    ) -> Self {
+        Self {
+            strain_gage_configuration,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation_voltage(self, excitation_voltage: f64) -> Self {
+        Self {
+            excitation_voltage,
+            ..self
+        }
+    }
+
+    pub fn gage_factor(self, gage_factor: f64) -> Self {
+        Self {
+            gage_factor,
+            ..self
+        }
+    }
+
+    /// The bridge output voltage when no strain is applied, which is subtracted from readings.
+    pub fn initial_bridge_voltage(self, initial_bridge_voltage: f64) -> Self {
+        Self {
+            initial_bridge_voltage,
+            ..self
+        }
+    }
+
+    pub fn nominal_gage_resistance(self, nominal_gage_resistance: f64) -> Self {
+        Self {
+            nominal_gage_resistance,
+            ..self
+        }
+    }
+
+    pub fn poisson_ratio(self, poisson_ratio: f64) -> Self {
+        Self {
+            poisson_ratio,
+            ..self
+        }
+    }
+
+    pub fn lead_wire_resistance(self, lead_wire_resistance: f64) -> Self {
+        Self {
+            lead_wire_resistance,
+            ..self
+        }
+    }
+}
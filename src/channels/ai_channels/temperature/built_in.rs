@@ -0,0 +1,63 @@
+use crate::channels::ai_channels::MeasurementType;
+use crate::channels::ai_channels::temperature::{TemperatureInputKind, TemperatureUnits};
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel};
+use crate::daqmx_call;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// The temperature sensor built in to a device or terminal block, such as the cold junction
+/// sensor of a thermocouple module.
+pub struct BuiltInTemperatureSensor;
+
+impl ChannelKind for BuiltInTemperatureSensor {}
+impl AnalogInputKind for BuiltInTemperatureSensor {
+    const MEASUREMENT_TYPE: Option<MeasurementType> =
+        Some(MeasurementType::BuiltInTemperatureSensor);
+}
+impl TemperatureInputKind for BuiltInTemperatureSensor {}
+
+/// The range of the sensor is fixed by the device, so this builder doesn't implement
+/// [`AnalogChannelBuilder`](crate::channels::ai_channels::AnalogChannelBuilder).
+pub struct BuiltInTemperatureSensorBuilder {
+    physical_channel: CString,
+    channel_name: ChannelName,
+    units: TemperatureUnits,
+}
+
+impl ChannelBuilder for BuiltInTemperatureSensorBuilder {
+    type Kind = BuiltInTemperatureSensor;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel.into())?,
+            channel_name: ChannelName::default(),
+            units: TemperatureUnits::Celsius,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.channel_name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(
+        self,
+        task: TaskHandle,
+    ) -> crate::error::Result<TaskChannel<BuiltInTemperatureSensor>> {
+        let expected_name = self.channel_name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAITempBuiltInSensorChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.channel_name.as_ptr(),
+            self.units.into_raw()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl BuiltInTemperatureSensorBuilder {
+    pub fn units(self, units: TemperatureUnits) -> Self {
+        Self { units, ..self }
+    }
+}
@@ -1,11 +1,15 @@
+mod built_in;
 pub mod rtd;
+mod thermistor;
 mod thermocouple;
 
 use crate::channels::properties::PropertyValue;
 use crate::channels::{AnalogInputKind, TaskChannel, property};
 use crate::error::DaqmxError;
-use ni_daqmx_sys::*;
+use crate::sys::*;
+pub use built_in::*;
 pub use rtd::*;
+pub use thermistor::*;
 pub use thermocouple::*;
 pub trait TemperatureInputKind: AnalogInputKind {}
 
@@ -13,6 +17,8 @@ impl<K: TemperatureInputKind> TaskChannel<K> {
     property!(get_set_reset temperature_units / set_temperature_units / reset_temperature_units: TemperatureUnits = DAQmxGetAITempUnits, DAQmxSetAITempUnits, DAQmxResetAITempUnits);
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TemperatureUnits {
     Celsius,
     Fahrenheit,
@@ -5,19 +5,24 @@ use crate::channels::{
 };
 use crate::daqmx_call;
 use crate::error::DaqmxError;
-use ni_daqmx_sys::*;
+use crate::sys::*;
 use std::ffi::CString;
-use crate::channels::ai_channels::AnalogChannelBuilder;
+use crate::channels::ai_channels::{AnalogChannelBuilder, MeasurementType};
 use crate::channels::ai_channels::resistance::{ExcitationSource, WireConfiguration};
 
 pub struct Rtd {}
 
 impl ChannelKind for Rtd {}
-impl AnalogInputKind for Rtd {}
+impl AnalogInputKind for Rtd {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Rtd);
+}
 impl TemperatureInputKind for Rtd {}
 
 impl TaskChannel<Rtd> {
     property!(get_set_reset r0 / set_r0 / reset_r0: f64 = DAQmxGetAIRTDR0, DAQmxSetAIRTDR0, DAQmxResetAIRTDR0);
+    property!(get wire_configuration: WireConfiguration = DAQmxGetAIResistanceCfg);
+    property!(get excitation_source: ExcitationSource = DAQmxGetAIExcitSrc);
+    property!(get excitation_current: f64 = DAQmxGetAIExcitVal);
 
     pub fn get_type(&self) -> Result<RTDType, DaqmxError> {
         let daqmx_type: DaqmxRTDType = self.property_get(DAQmxGetAIRTDType)?;
@@ -100,6 +105,7 @@ impl PropertyValue for DaqmxRTDType {
 
 /// The types of RTD we can set.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RTDType {
     Pt3750,
     Pt3851,
@@ -0,0 +1,211 @@
+use crate::channels::ai_channels::resistance::{
+    ExcitationSource, ExcitedInputKind, WireConfiguration,
+};
+use crate::channels::ai_channels::temperature::{TemperatureInputKind, TemperatureUnits};
+use crate::channels::ai_channels::{AnalogChannelBuilder, MeasurementType};
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// A thermistor channel, with either current or voltage excitation.
+pub struct Thermistor;
+
+impl ChannelKind for Thermistor {}
+impl AnalogInputKind for Thermistor {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Thermistor);
+}
+impl TemperatureInputKind for Thermistor {}
+impl ExcitedInputKind for Thermistor {}
+
+impl TaskChannel<Thermistor> {
+    property!(get wire_configuration: WireConfiguration = DAQmxGetAIResistanceCfg);
+
+    pub fn coefficients(&self) -> Result<SteinhartHartCoefficients, DaqmxError> {
+        Ok(SteinhartHartCoefficients {
+            a: self.property_get(DAQmxGetAIThrmstrA)?,
+            b: self.property_get(DAQmxGetAIThrmstrB)?,
+            c: self.property_get(DAQmxGetAIThrmstrC)?,
+        })
+    }
+
+    pub fn set_coefficients(
+        &self,
+        coefficients: SteinhartHartCoefficients,
+    ) -> Result<(), DaqmxError> {
+        self.property_set(DAQmxSetAIThrmstrA, coefficients.a)?;
+        self.property_set(DAQmxSetAIThrmstrB, coefficients.b)?;
+        self.property_set(DAQmxSetAIThrmstrC, coefficients.c)
+    }
+
+    /// Read the excitation, which is a current or a voltage depending on how the channel was
+    /// created.
+    pub fn excitation(&self) -> Result<ThermistorExcitation, DaqmxError> {
+        let value: f64 = self.property_get(DAQmxGetAIExcitVal)?;
+        let voltage_or_current: i32 = self.property_get(DAQmxGetAIExcitVoltageOrCurrent)?;
+        #[allow(non_upper_case_globals)]
+        match voltage_or_current {
+            DAQmx_Val_Current => Ok(ThermistorExcitation::Current(value)),
+            DAQmx_Val_Voltage => Ok(ThermistorExcitation::Voltage {
+                voltage: value,
+                r1: self.property_get(DAQmxGetAIThrmstrR1)?,
+            }),
+            _ => Err(DaqmxError::UnexpectedValue(
+                "Excitation voltage or current",
+                voltage_or_current,
+            )),
+        }
+    }
+}
+
+/// The coefficients of the Steinhart-Hart equation relating the thermistor resistance to
+/// temperature: `1/T = a + b ln(R) + c ln(R)^3`, with `T` in kelvin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SteinhartHartCoefficients {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Default for SteinhartHartCoefficients {
+    /// The DAQmx defaults, which suit a 5 kΩ thermistor such as the 44031.
+    fn default() -> Self {
+        Self {
+            a: 0.001295361,
+            b: 0.0002343159,
+            c: 0.0000001018703,
+        }
+    }
+}
+
+/// How the thermistor is excited.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThermistorExcitation {
+    /// Measure the voltage across the thermistor with this current in amps.
+    Current(f64),
+    /// Measure the thermistor in a divider with the reference resistor `r1` in ohms, excited by
+    /// this voltage.
+    Voltage { voltage: f64, r1: f64 },
+}
+
+pub struct ThermistorBuilder {
+    physical_channel: CString,
+    channel_name: ChannelName,
+    max: f64,
+    min: f64,
+    units: TemperatureUnits,
+    wire_configuration: WireConfiguration,
+    excitation_source: ExcitationSource,
+    excitation: ThermistorExcitation,
+    coefficients: SteinhartHartCoefficients,
+}
+
+impl ChannelBuilder for ThermistorBuilder {
+    type Kind = Thermistor;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel.into())?,
+            channel_name: ChannelName::default(),
+            max: 100.0,
+            min: 0.0,
+            units: TemperatureUnits::Celsius,
+            wire_configuration: WireConfiguration::FourWire,
+            excitation_source: ExcitationSource::Internal,
+            excitation: ThermistorExcitation::Voltage {
+                voltage: 2.5,
+                r1: 5000.0,
+            },
+            coefficients: SteinhartHartCoefficients::default(),
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.channel_name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Thermistor>> {
+        let expected_name = self.channel_name.or(&self.physical_channel).to_owned();
+        let SteinhartHartCoefficients { a, b, c } = self.coefficients;
+        match self.excitation {
+            ThermistorExcitation::Current(current) => daqmx_call!(DAQmxCreateAIThrmstrChanIex(
+                task,
+                self.physical_channel.as_ptr(),
+                self.channel_name.as_ptr(),
+                self.min,
+                self.max,
+                self.units.into_raw(),
+                self.wire_configuration.into_raw(),
+                self.excitation_source.into_raw(),
+                current,
+                a,
+                b,
+                c
+            ))?,
+            ThermistorExcitation::Voltage { voltage, r1 } => {
+                daqmx_call!(DAQmxCreateAIThrmstrChanVex(
+                    task,
+                    self.physical_channel.as_ptr(),
+                    self.channel_name.as_ptr(),
+                    self.min,
+                    self.max,
+                    self.units.into_raw(),
+                    self.wire_configuration.into_raw(),
+                    self.excitation_source.into_raw(),
+                    voltage,
+                    a,
+                    b,
+                    c,
+                    r1
+                ))?
+            }
+        }
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for ThermistorBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl ThermistorBuilder {
+    pub fn units(self, units: TemperatureUnits) -> Self {
+        Self { units, ..self }
+    }
+
+    pub fn wire_configuration(self, wire_configuration: WireConfiguration) -> Self {
+        Self {
+            wire_configuration,
+            ..self
+        }
+    }
+
+    pub fn excitation_source(self, excitation_source: ExcitationSource) -> Self {
+        Self {
+            excitation_source,
+            ..self
+        }
+    }
+
+    pub fn excitation(self, excitation: ThermistorExcitation) -> Self {
+        Self { excitation, ..self }
+    }
+
+    pub fn coefficients(self, coefficients: SteinhartHartCoefficients) -> Self {
+        Self {
+            coefficients,
+            ..self
+        }
+    }
+}
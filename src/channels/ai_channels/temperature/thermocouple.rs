@@ -3,14 +3,16 @@ use crate::channels::properties::{ChannelName, PropertyValue};
 use crate::channels::{AnalogInputKind, ChannelBuilder, ChannelKind, TaskChannel, property};
 use crate::daqmx_call;
 use crate::error::DaqmxError;
-use ni_daqmx_sys::*;
+use crate::sys::*;
 use std::ffi::CString;
-use crate::channels::ai_channels::AnalogChannelBuilder;
+use crate::channels::ai_channels::{AnalogChannelBuilder, MeasurementType};
 
 pub struct Thermocouple;
 
 impl ChannelKind for Thermocouple {}
-impl AnalogInputKind for Thermocouple {}
+impl AnalogInputKind for Thermocouple {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Thermocouple);
+}
 impl TemperatureInputKind for Thermocouple {}
 
 impl TaskChannel<Thermocouple> {
@@ -37,6 +39,7 @@ impl From<&CjcSource> for DaqmxCjcSource {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DaqmxCjcSource {
     BuiltIn,
     ConstValue,
@@ -89,6 +92,8 @@ impl PropertyValue for ThermocoupleScaleType {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThermocoupleType {
     J,
     K,
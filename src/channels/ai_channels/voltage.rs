@@ -1,4 +1,4 @@
-use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::properties::{ChannelName, PropertyValue, ScalarGetter, ScalarSetter};
 use crate::channels::{
     AnalogInputKind, ChannelBuilder, ChannelKind,
     TaskChannel,
@@ -6,39 +6,41 @@ use crate::channels::{
 use crate::daqmx_call;
 use crate::error::DaqmxError;
 use crate::scales::PreScaledUnits;
-use ni_daqmx_sys::{
+use crate::sys::{
     DAQmx_Val_FromCustomScale, DAQmx_Val_FromTEDS, DAQmx_Val_Volts, DAQmxGetAIVoltageUnits,
     DAQmxSetAIVoltageUnits, TaskHandle,
 };
 use std::ffi::CString;
 use std::sync::Arc;
-use crate::channels::ai_channels::{AnalogChannelBuilder, AnalogTerminalConfig};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, CustomScaleUnits, MeasurementType, ScaledInputKind,
+};
 
 pub struct Voltage;
 impl ChannelKind for Voltage {}
 
-impl AnalogInputKind for Voltage {}
+impl AnalogInputKind for Voltage {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::Voltage);
+}
 
-impl TaskChannel<Voltage> {
-    pub fn scale(&self) -> Result<VoltageScale, DaqmxError> {
-        let scale: VoltageScale = self.property_get(DAQmxGetAIVoltageUnits)?;
+impl ScaledInputKind for Voltage {
+    type Scale = VoltageScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIVoltageUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIVoltageUnits;
+}
 
-        if let VoltageScale::CustomScale(_) = scale {
-            let name = self.custom_scale_name()?;
-            Ok(VoltageScale::CustomScale(Some(Arc::new(CString::new(
-                name,
-            )?))))
-        } else {
-            Ok(scale)
-        }
-    }
+/// The RMS value of a voltage, calculated over the samples of each read.
+pub struct VoltageRms;
+impl ChannelKind for VoltageRms {}
 
-    pub fn set_scale(&mut self, scale: VoltageScale) -> Result<(), DaqmxError> {
-        if let VoltageScale::CustomScale(Some(name)) = &scale {
-            self.set_custom_scale_name(name)?;
-        }
-        self.property_set(DAQmxSetAIVoltageUnits, scale)
-    }
+impl AnalogInputKind for VoltageRms {
+    const MEASUREMENT_TYPE: Option<MeasurementType> = Some(MeasurementType::VoltageRms);
+}
+
+impl ScaledInputKind for VoltageRms {
+    type Scale = VoltageScale;
+    const UNITS_GETTER: ScalarGetter<i32> = DAQmxGetAIVoltageUnits;
+    const UNITS_SETTER: ScalarSetter<i32> = DAQmxSetAIVoltageUnits;
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -61,6 +63,19 @@ impl VoltageScale {
     }
 }
 
+impl CustomScaleUnits for VoltageScale {
+    fn from_custom_scale_name(name: Arc<CString>) -> Self {
+        Self::CustomScale(Some(name))
+    }
+
+    fn custom_scale(&self) -> Option<Option<&Arc<CString>>> {
+        match self {
+            Self::CustomScale(name) => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+}
+
 impl PropertyValue for VoltageScale {
     type Raw = i32;
 
@@ -122,7 +137,7 @@ impl ChannelBuilder for VoltageChannelBuilder {
 
     fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
         let expected_name = self.name.or(&self.physical_channel).to_owned();
-        daqmx_call!(ni_daqmx_sys::DAQmxCreateAIVoltageChan(
+        daqmx_call!(crate::sys::DAQmxCreateAIVoltageChan(
             task,
             self.physical_channel.as_ptr(),
             self.name.as_ptr(),
@@ -158,3 +173,69 @@ impl VoltageChannelBuilder {
         }
     }
 }
+
+pub struct VoltageRmsChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    max: f64,
+    min: f64,
+    scale: VoltageScale,
+    terminal_config: AnalogTerminalConfig,
+}
+
+impl ChannelBuilder for VoltageRmsChannelBuilder {
+    type Kind = VoltageRms;
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            max: 5.0,
+            min: -5.0,
+            scale: VoltageScale::Volts,
+            terminal_config: AnalogTerminalConfig::Default,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(crate::sys::DAQmxCreateAIVoltageRMSChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.terminal_config as i32,
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            CString::from(self.scale).as_ptr(),
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for VoltageRmsChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl VoltageRmsChannelBuilder {
+    pub fn scale(self, scale: VoltageScale) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn terminal_config(self, terminal_config: AnalogTerminalConfig) -> Self {
+        Self {
+            terminal_config,
+            ..self
+        }
+    }
+}
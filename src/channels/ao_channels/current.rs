@@ -0,0 +1,97 @@
+use crate::channels::ai_channels::AnalogChannelBuilder;
+use crate::channels::ai_channels::current::current_dc::CurrentScale;
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{AnalogOutputKind, ChannelBuilder, ChannelKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct CurrentOutput;
+
+impl ChannelKind for CurrentOutput {}
+impl AnalogOutputKind for CurrentOutput {}
+
+impl TaskChannel<CurrentOutput> {
+    pub fn scale(&self) -> Result<CurrentScale, DaqmxError> {
+        let mut initial: CurrentScale = self.property_get(DAQmxGetAOCurrentUnits)?;
+
+        if let CurrentScale::CustomScale(None) = initial {
+            let name = self.ao_custom_scale_name()?;
+            let name_cstr = CString::from_str(&name)?;
+            initial = CurrentScale::CustomScale(Some(Arc::new(name_cstr)));
+        }
+        Ok(initial)
+    }
+
+    pub fn set_scale(&self, scale: CurrentScale) -> Result<(), DaqmxError> {
+        if let CurrentScale::CustomScale(Some(name)) = &scale {
+            self.set_ao_custom_scale_name(name)?;
+        }
+        self.property_set(DAQmxSetAOCurrentUnits, scale)
+    }
+}
+
+pub struct CurrentOutputChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    scale: CurrentScale,
+}
+
+impl ChannelBuilder for CurrentOutputChannelBuilder {
+    type Kind = CurrentOutput;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            min: 0.0,
+            max: 0.02,
+            scale: CurrentScale::Amps,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let custom_scale_name = match &self.scale {
+            CurrentScale::CustomScale(Some(name)) => name.as_ptr(),
+            _ => std::ptr::null(),
+        };
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(DAQmxCreateAOCurrentChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            custom_scale_name
+        ))?;
+
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for CurrentOutputChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl CurrentOutputChannelBuilder {
+    pub fn scale(self, scale: CurrentScale) -> Self {
+        Self { scale, ..self }
+    }
+}
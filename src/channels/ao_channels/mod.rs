@@ -0,0 +1,29 @@
+pub mod current;
+pub mod voltage;
+
+use super::{ChannelKind, TaskChannel, property};
+use crate::error::Result;
+use crate::sys::*;
+use std::ffi::CStr;
+
+pub trait AnalogOutputKind: ChannelKind {}
+
+/// An analog output channel of any kind, exposing the properties common to all analog outputs.
+pub struct AnyAnalogOutput;
+
+impl ChannelKind for AnyAnalogOutput {}
+impl AnalogOutputKind for AnyAnalogOutput {}
+
+impl<K: AnalogOutputKind> TaskChannel<K> {
+    property!(get_set ao_max / set_ao_max: f64 = DAQmxGetAOMax, DAQmxSetAOMax);
+    property!(get_set ao_min / set_ao_min: f64 = DAQmxGetAOMin, DAQmxSetAOMin);
+    property!(get_string ao_custom_scale_name = crate::sys::DAQmxGetAOCustomScaleName);
+
+    pub fn set_ao_custom_scale_name(&self, name: &CStr) -> Result<()> {
+        self.property_set_raw(DAQmxSetAOCustomScaleName, name.as_ptr())
+    }
+
+    pub fn reset_ao_custom_scale_name(&self) -> Result<()> {
+        self.property_reset(DAQmxResetAOCustomScaleName)
+    }
+}
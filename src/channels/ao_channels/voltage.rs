@@ -0,0 +1,93 @@
+use crate::channels::ai_channels::AnalogChannelBuilder;
+use crate::channels::ai_channels::voltage::VoltageScale;
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{AnalogOutputKind, ChannelBuilder, ChannelKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::{DAQmxGetAOVoltageUnits, DAQmxSetAOVoltageUnits, TaskHandle};
+use std::ffi::CString;
+use std::sync::Arc;
+
+pub struct VoltageOutput;
+
+impl ChannelKind for VoltageOutput {}
+impl AnalogOutputKind for VoltageOutput {}
+
+impl TaskChannel<VoltageOutput> {
+    pub fn scale(&self) -> Result<VoltageScale, DaqmxError> {
+        let scale: VoltageScale = self.property_get(DAQmxGetAOVoltageUnits)?;
+
+        if let VoltageScale::CustomScale(_) = scale {
+            let name = self.ao_custom_scale_name()?;
+            Ok(VoltageScale::CustomScale(Some(Arc::new(CString::new(
+                name,
+            )?))))
+        } else {
+            Ok(scale)
+        }
+    }
+
+    pub fn set_scale(&mut self, scale: VoltageScale) -> Result<(), DaqmxError> {
+        if let VoltageScale::CustomScale(Some(name)) = &scale {
+            self.set_ao_custom_scale_name(name)?;
+        }
+        self.property_set(DAQmxSetAOVoltageUnits, scale)
+    }
+}
+
+pub struct VoltageOutputChannelBuilder {
+    physical_channel: CString,
+    name: ChannelName,
+    max: f64,
+    min: f64,
+    scale: VoltageScale,
+}
+
+impl ChannelBuilder for VoltageOutputChannelBuilder {
+    type Kind = VoltageOutput;
+
+    fn new<S: Into<Vec<u8>>>(physical_channel: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            physical_channel: CString::new(physical_channel)?,
+            name: ChannelName::default(),
+            max: 10.0,
+            min: -10.0,
+            scale: VoltageScale::Volts,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.physical_channel).to_owned();
+        daqmx_call!(crate::sys::DAQmxCreateAOVoltageChan(
+            task,
+            self.physical_channel.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.scale.clone().into_raw(),
+            CString::from(self.scale).as_ptr(),
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl AnalogChannelBuilder for VoltageOutputChannelBuilder {
+    fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+}
+
+impl VoltageOutputChannelBuilder {
+    pub fn scale(self, scale: VoltageScale) -> Self {
+        Self { scale, ..self }
+    }
+}
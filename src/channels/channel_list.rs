@@ -0,0 +1,338 @@
+//! Parsing and formatting of DAQmx channel lists.
+//!
+//! DAQmx accepts several channels in one string, separated by commas, where consecutive channels
+//! can be written as a range, e.g. `Dev1/ai0:7, Dev2/ai3`. [`ChannelList`] expands these into
+//! the individual channel names and formats them back into the compact form.
+//!
+//! ```
+//! use daqmx::channels::ChannelList;
+//!
+//! let list: ChannelList = "Dev1/ai0:2, Dev2/ai3".parse().unwrap();
+//! assert_eq!(list.names(), ["Dev1/ai0", "Dev1/ai1", "Dev1/ai2", "Dev2/ai3"]);
+//! assert_eq!(list.to_string(), "Dev1/ai0:2, Dev2/ai3");
+//! ```
+
+use crate::error::{DaqmxError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// An expanded list of physical or virtual channel names.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChannelList {
+    names: Vec<String>,
+}
+
+impl ChannelList {
+    /// The most channels a single range may expand to. No device has anywhere near this many
+    /// channels of one type, so a longer range is a typo rather than something to allocate.
+    pub const MAX_RANGE_LEN: u32 = 65_536;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a channel list string, expanding any ranges into the individual channels.
+    pub fn parse(list: &str) -> Result<Self> {
+        let mut channels = Self::new();
+        for entry in list
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            match entry.split_once(':') {
+                Some((start, end)) => channels.push_entry_range(entry, start, end)?,
+                None => channels.push(entry),
+            }
+        }
+        Ok(channels)
+    }
+
+    /// Add a single channel to the end of the list.
+    pub fn push<S: Into<String>>(&mut self, name: S) {
+        self.names.push(name.into());
+    }
+
+    /// Add a range of numbered channels to the end of the list.
+    ///
+    /// The range counts down if `end` is less than `start`, as it does in DAQmx. Ranges of more
+    /// than [`Self::MAX_RANGE_LEN`] channels are rejected.
+    ///
+    /// ```
+    /// use daqmx::channels::ChannelList;
+    ///
+    /// let mut list = ChannelList::new();
+    /// list.push_range("Dev1/port0/line", 3, 0).unwrap();
+    /// assert_eq!(list.to_string(), "Dev1/port0/line3:0");
+    /// ```
+    pub fn push_range(&mut self, prefix: &str, start: u32, end: u32) -> Result<()> {
+        self.push_numbered(prefix, start, end, 0)
+    }
+
+    /// The channel names, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.names.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Expand a range entry such as `Dev1/ai0:7` or `Dev1/ai0:Dev1/ai7`.
+    fn push_entry_range(&mut self, entry: &str, start: &str, end: &str) -> Result<()> {
+        let invalid = || DaqmxError::InvalidChannelList(entry.to_owned());
+        let (prefix, start_digits) = split_number(start).ok_or_else(invalid)?;
+        // The end may repeat the full name. Only the number is needed.
+        let (end_prefix, end_digits) = split_number(end).ok_or_else(invalid)?;
+        if !end_prefix.is_empty() && !prefix.ends_with(end_prefix) {
+            return Err(invalid());
+        }
+
+        let start_number = start_digits.parse().map_err(|_| invalid())?;
+        let end_number = end_digits.parse().map_err(|_| invalid())?;
+        // Keep any leading zeros, e.g. `line00:15`.
+        let width = if start_digits.starts_with('0') {
+            start_digits.len()
+        } else {
+            0
+        };
+        self.push_numbered(prefix, start_number, end_number, width)
+    }
+
+    fn push_numbered(&mut self, prefix: &str, start: u32, end: u32, width: usize) -> Result<()> {
+        if start.abs_diff(end) >= Self::MAX_RANGE_LEN {
+            return Err(DaqmxError::InvalidChannelList(format!(
+                "{prefix}{start:0width$}:{end:0width$}"
+            )));
+        }
+        let numbers: Box<dyn Iterator<Item = u32>> = if start <= end {
+            Box::new(start..=end)
+        } else {
+            Box::new((end..=start).rev())
+        };
+        self.names
+            .extend(numbers.map(|number| format!("{prefix}{number:0width$}")));
+        Ok(())
+    }
+}
+
+/// Split the trailing number from a channel name, e.g. `Dev1/ai12` to `("Dev1/ai", "12")`.
+fn split_number(name: &str) -> Option<(&str, &str)> {
+    let digits_start = name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits_start == name.len() {
+        return None;
+    }
+    Some(name.split_at(digits_start))
+}
+
+impl FromStr for ChannelList {
+    type Err = DaqmxError;
+
+    fn from_str(list: &str) -> Result<Self> {
+        Self::parse(list)
+    }
+}
+
+/// Formats the list with consecutive channels collapsed into ranges.
+impl fmt::Display for ChannelList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut index = 0;
+        while index < self.names.len() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            let run = run_length(&self.names[index..]);
+            let first = &self.names[index];
+            if run > 1 {
+                let last = &self.names[index + run - 1];
+                let (_, last_digits) = split_number(last).unwrap_or_default();
+                write!(f, "{first}:{last_digits}")?;
+            } else {
+                f.write_str(first)?;
+            }
+            index += run;
+        }
+        Ok(())
+    }
+}
+
+/// The number of names at the start of `names` which form a range when collapsed.
+///
+/// These share a prefix and count up or down by one, so the range expands back to the same names.
+fn run_length(names: &[String]) -> usize {
+    let Some((prefix, digits)) = split_number(&names[0]) else {
+        return 1;
+    };
+    let Ok(first) = digits.parse::<u32>() else {
+        return 1;
+    };
+    let width = if digits.starts_with('0') {
+        digits.len()
+    } else {
+        0
+    };
+
+    let numbered = |number: i64| {
+        u32::try_from(number)
+            .ok()
+            .map(|number| format!("{prefix}{number:0width$}"))
+    };
+    let step = match names.get(1) {
+        Some(name) if numbered(first as i64 + 1).as_ref() == Some(name) => 1,
+        Some(name) if numbered(first as i64 - 1).as_ref() == Some(name) => -1,
+        _ => return 1,
+    };
+
+    let mut length = 2;
+    while let Some(name) = names.get(length) {
+        if numbered(first as i64 + step * length as i64).as_ref() != Some(name) {
+            break;
+        }
+        length += 1;
+    }
+    length
+}
+
+impl IntoIterator for ChannelList {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ChannelList {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.iter()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for ChannelList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self {
+            names: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_channels() {
+        let list = ChannelList::parse("Dev1/ai0,Dev2/ai3").unwrap();
+        assert_eq!(list.names(), ["Dev1/ai0", "Dev2/ai3"]);
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let list = ChannelList::parse("Dev1/ai0:3, Dev2/ai3").unwrap();
+        assert_eq!(
+            list.names(),
+            ["Dev1/ai0", "Dev1/ai1", "Dev1/ai2", "Dev1/ai3", "Dev2/ai3"]
+        );
+    }
+
+    #[test]
+    fn test_parse_descending_range() {
+        let list = ChannelList::parse("Dev1/port0/line3:1").unwrap();
+        assert_eq!(
+            list.names(),
+            ["Dev1/port0/line3", "Dev1/port0/line2", "Dev1/port0/line1"]
+        );
+    }
+
+    #[test]
+    fn test_parse_range_with_full_end_name() {
+        let list = ChannelList::parse("/Dev1/ai8:Dev1/ai9").unwrap();
+        assert_eq!(list.names(), ["/Dev1/ai8", "/Dev1/ai9"]);
+    }
+
+    #[test]
+    fn test_parse_leading_zeros() {
+        let list = ChannelList::parse("cDAQ1Mod1/line08:10").unwrap();
+        assert_eq!(
+            list.names(),
+            ["cDAQ1Mod1/line08", "cDAQ1Mod1/line09", "cDAQ1Mod1/line10"]
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert!(ChannelList::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_ranges() {
+        for list in ["Dev1/ai:3", "Dev1/ai0:", "Dev1/ai0:Dev1/ao3"] {
+            assert_eq!(
+                ChannelList::parse(list),
+                Err(DaqmxError::InvalidChannelList(list.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_collapses_ranges() {
+        let list: ChannelList = ["Dev1/ai0", "Dev1/ai1", "Dev1/ai2", "Dev1/ai5", "Temp"]
+            .into_iter()
+            .collect();
+        assert_eq!(list.to_string(), "Dev1/ai0:2, Dev1/ai5, Temp");
+    }
+
+    #[test]
+    fn test_format_descending() {
+        let list: ChannelList = ["Dev1/ai2", "Dev1/ai1", "Dev1/ai0", "Dev1/ai1"]
+            .into_iter()
+            .collect();
+        assert_eq!(list.to_string(), "Dev1/ai2:0, Dev1/ai1");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in [
+            "Dev1/ai0:7, Dev2/ai3",
+            "Dev1/port0/line7:0, Dev1/port1/line0",
+            "cDAQ1Mod1/line08:10",
+            "Voltage, Dev1/_cjtemp",
+        ] {
+            assert_eq!(ChannelList::parse(text).unwrap().to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_push_range() {
+        let mut list = ChannelList::new();
+        list.push("Dev1/ai9");
+        list.push_range("Dev1/ai", 0, 2).unwrap();
+        assert_eq!(
+            list.names(),
+            ["Dev1/ai9", "Dev1/ai0", "Dev1/ai1", "Dev1/ai2"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_huge_ranges() {
+        for text in ["Dev1/ai0:4294967295", "Dev1/ai4294967295:0"] {
+            assert!(matches!(
+                ChannelList::parse(text),
+                Err(DaqmxError::InvalidChannelList(_))
+            ));
+        }
+        let mut list = ChannelList::new();
+        assert!(list.push_range("Dev1/ai", 0, u32::MAX).is_err());
+        assert!(list.is_empty());
+    }
+}
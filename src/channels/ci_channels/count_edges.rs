@@ -0,0 +1,111 @@
+use crate::channels::ci_channels::CounterInputKind;
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::types::ClockEdge;
+use crate::sys::*;
+use std::ffi::CString;
+
+pub struct CountEdges;
+
+impl ChannelKind for CountEdges {}
+impl CounterInputKind for CountEdges {}
+
+impl TaskChannel<CountEdges> {
+    property!(get_set_reset active_edge / set_active_edge / reset_active_edge: ClockEdge = DAQmxGetCICountEdgesActiveEdge, DAQmxSetCICountEdgesActiveEdge, DAQmxResetCICountEdgesActiveEdge);
+    property!(get_set_reset count_direction / set_count_direction / reset_count_direction: CountDirection = DAQmxGetCICountEdgesDir, DAQmxSetCICountEdgesDir, DAQmxResetCICountEdgesDir);
+    property!(get_set_reset initial_count / set_initial_count / reset_initial_count: u32 = DAQmxGetCICountEdgesInitialCnt, DAQmxSetCICountEdgesInitialCnt, DAQmxResetCICountEdgesInitialCnt);
+    property!(get_string input_terminal = DAQmxGetCICountEdgesTerm);
+}
+
+/// The direction an edge counter counts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountDirection {
+    CountUp,
+    CountDown,
+    /// The state of a digital line controls the count direction.
+    ExternallyControlled,
+}
+
+impl PropertyValue for CountDirection {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_CountUp => Ok(CountDirection::CountUp),
+            DAQmx_Val_CountDown => Ok(CountDirection::CountDown),
+            DAQmx_Val_ExtControlled => Ok(CountDirection::ExternallyControlled),
+            _ => Err(DaqmxError::UnexpectedValue("CountDirection", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            CountDirection::CountUp => DAQmx_Val_CountUp,
+            CountDirection::CountDown => DAQmx_Val_CountDown,
+            CountDirection::ExternallyControlled => DAQmx_Val_ExtControlled,
+        }
+    }
+}
+
+pub struct CountEdgesChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    edge: ClockEdge,
+    initial_count: u32,
+    count_direction: CountDirection,
+}
+
+impl ChannelBuilder for CountEdgesChannelBuilder {
+    type Kind = CountEdges;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            edge: ClockEdge::Rising,
+            initial_count: 0,
+            count_direction: CountDirection::CountUp,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCICountEdgesChan(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            self.edge.into(),
+            self.initial_count,
+            self.count_direction.into_raw()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl CountEdgesChannelBuilder {
+    pub fn edge(self, edge: ClockEdge) -> Self {
+        Self { edge, ..self }
+    }
+
+    pub fn initial_count(self, initial_count: u32) -> Self {
+        Self {
+            initial_count,
+            ..self
+        }
+    }
+
+    pub fn count_direction(self, count_direction: CountDirection) -> Self {
+        Self {
+            count_direction,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::channels::ci_channels::{CounterInputKind, CounterMeasurementMethod};
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::types::ClockEdge;
+use crate::sys::*;
+use std::ffi::CString;
+
+pub struct Frequency;
+
+impl ChannelKind for Frequency {}
+impl CounterInputKind for Frequency {}
+
+impl TaskChannel<Frequency> {
+    property!(get_set_reset units / set_units / reset_units: FrequencyUnits = DAQmxGetCIFreqUnits, DAQmxSetCIFreqUnits, DAQmxResetCIFreqUnits);
+    property!(get_set_reset starting_edge / set_starting_edge / reset_starting_edge: ClockEdge = DAQmxGetCIFreqStartingEdge, DAQmxSetCIFreqStartingEdge, DAQmxResetCIFreqStartingEdge);
+    property!(get_set_reset measurement_method / set_measurement_method / reset_measurement_method: CounterMeasurementMethod = DAQmxGetCIFreqMeasMeth, DAQmxSetCIFreqMeasMeth, DAQmxResetCIFreqMeasMeth);
+    property!(get_set_reset measurement_time / set_measurement_time / reset_measurement_time: f64 = DAQmxGetCIFreqMeasTime, DAQmxSetCIFreqMeasTime, DAQmxResetCIFreqMeasTime);
+    property!(get_set_reset divisor / set_divisor / reset_divisor: u32 = DAQmxGetCIFreqDiv, DAQmxSetCIFreqDiv, DAQmxResetCIFreqDiv);
+    property!(get_string input_terminal = DAQmxGetCIFreqTerm);
+}
+
+/// The units for a counter frequency measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrequencyUnits {
+    Hertz,
+    /// Timebase ticks of the counter.
+    Ticks,
+    CustomScale,
+}
+
+impl PropertyValue for FrequencyUnits {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Hz => Ok(FrequencyUnits::Hertz),
+            DAQmx_Val_Ticks => Ok(FrequencyUnits::Ticks),
+            DAQmx_Val_FromCustomScale => Ok(FrequencyUnits::CustomScale),
+            _ => Err(DaqmxError::UnexpectedValue("FrequencyUnits", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            FrequencyUnits::Hertz => DAQmx_Val_Hz,
+            FrequencyUnits::Ticks => DAQmx_Val_Ticks,
+            FrequencyUnits::CustomScale => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
+
+pub struct FrequencyChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    units: FrequencyUnits,
+    edge: ClockEdge,
+    measurement_method: CounterMeasurementMethod,
+    measurement_time: f64,
+    divisor: u32,
+}
+
+impl ChannelBuilder for FrequencyChannelBuilder {
+    type Kind = Frequency;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            min: 2.0,
+            max: 100.0,
+            units: FrequencyUnits::Hertz,
+            edge: ClockEdge::Rising,
+            measurement_method: CounterMeasurementMethod::LowFrequencyOneCounter,
+            measurement_time: 0.001,
+            divisor: 4,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCIFreqChan(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.units.into_raw(),
+            self.edge.into(),
+            self.measurement_method.into_raw(),
+            self.measurement_time,
+            self.divisor,
+            std::ptr::null()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl FrequencyChannelBuilder {
+    /// The maximum frequency you expect to measure.
+    pub fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    /// The minimum frequency you expect to measure.
+    pub fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+
+    pub fn units(self, units: FrequencyUnits) -> Self {
+        Self { units, ..self }
+    }
+
+    /// The edge of the input signal to start each measurement on.
+    pub fn edge(self, edge: ClockEdge) -> Self {
+        Self { edge, ..self }
+    }
+
+    pub fn measurement_method(self, measurement_method: CounterMeasurementMethod) -> Self {
+        Self {
+            measurement_method,
+            ..self
+        }
+    }
+
+    /// The length of time to measure the frequency of the signal. Only used with [`CounterMeasurementMethod::HighFrequencyTwoCounters`].
+    pub fn measurement_time(self, measurement_time: f64) -> Self {
+        Self {
+            measurement_time,
+            ..self
+        }
+    }
+
+    /// The value by which to divide the input signal. Only used with [`CounterMeasurementMethod::LargeRangeTwoCounters`].
+    pub fn divisor(self, divisor: u32) -> Self {
+        Self { divisor, ..self }
+    }
+}
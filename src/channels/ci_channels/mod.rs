@@ -0,0 +1,129 @@
+//! Counter input channels.
+//!
+//! Counter input channels take a counter (e.g. `Dev1/ctr0`) rather than a physical input.
+//! The input terminal for the signal defaults to the counters default terminal and can be read from the channel.
+
+pub mod count_edges;
+pub mod frequency;
+pub mod period;
+pub mod pulse_width;
+
+use super::properties::PropertyValue;
+use super::{ChannelKind, TaskChannel, property};
+use crate::error::{DaqmxError, Result};
+use crate::types::ClockEdge;
+use crate::sys::*;
+use std::ffi::CStr;
+
+pub use count_edges::*;
+pub use frequency::*;
+pub use period::*;
+pub use pulse_width::*;
+
+pub trait CounterInputKind: ChannelKind {}
+
+/// A counter input channel of any kind, exposing the properties common to all counter inputs.
+pub struct AnyCounterInput;
+
+impl ChannelKind for AnyCounterInput {}
+impl CounterInputKind for AnyCounterInput {}
+
+impl<K: CounterInputKind> TaskChannel<K> {
+    property!(get_set_reset ci_max / set_ci_max / reset_ci_max: f64 = DAQmxGetCIMax, DAQmxSetCIMax, DAQmxResetCIMax);
+    property!(get_set_reset ci_min / set_ci_min / reset_ci_min: f64 = DAQmxGetCIMin, DAQmxSetCIMin, DAQmxResetCIMin);
+    property!(get ci_count: u32 = DAQmxGetCICount);
+    property!(get_string ci_custom_scale_name = DAQmxGetCICustomScaleName);
+
+    pub fn set_ci_custom_scale_name(&self, name: &CStr) -> Result<()> {
+        self.property_set_raw(DAQmxSetCICustomScaleName, name.as_ptr())
+    }
+
+    pub fn reset_ci_custom_scale_name(&self) -> Result<()> {
+        self.property_reset(DAQmxResetCICustomScaleName)
+    }
+}
+
+impl PropertyValue for ClockEdge {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> std::result::Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Rising => Ok(ClockEdge::Rising),
+            DAQmx_Val_Falling => Ok(ClockEdge::Falling),
+            _ => Err(DaqmxError::UnexpectedValue("ClockEdge", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        self.into()
+    }
+}
+
+/// The method used by the counter to measure frequency or period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterMeasurementMethod {
+    /// Use one counter that uses a constant timebase to measure the input signal.
+    LowFrequencyOneCounter,
+    /// Use two counters, one of which counts pulses of the signal to measure during the specified measurement time.
+    HighFrequencyTwoCounters,
+    /// Use one counter to divide the frequency of the input signal to create a lower frequency signal that the second counter can more easily measure.
+    LargeRangeTwoCounters,
+    /// Uses one counter with a dynamic averaging of the measurement over the measurement time.
+    DynamicAveraging,
+}
+
+impl PropertyValue for CounterMeasurementMethod {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> std::result::Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_LowFreq1Ctr => Ok(CounterMeasurementMethod::LowFrequencyOneCounter),
+            DAQmx_Val_HighFreq2Ctr => Ok(CounterMeasurementMethod::HighFrequencyTwoCounters),
+            DAQmx_Val_LargeRng2Ctr => Ok(CounterMeasurementMethod::LargeRangeTwoCounters),
+            DAQmx_Val_DynAvg => Ok(CounterMeasurementMethod::DynamicAveraging),
+            _ => Err(DaqmxError::UnexpectedValue("CounterMeasurementMethod", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            CounterMeasurementMethod::LowFrequencyOneCounter => DAQmx_Val_LowFreq1Ctr,
+            CounterMeasurementMethod::HighFrequencyTwoCounters => DAQmx_Val_HighFreq2Ctr,
+            CounterMeasurementMethod::LargeRangeTwoCounters => DAQmx_Val_LargeRng2Ctr,
+            CounterMeasurementMethod::DynamicAveraging => DAQmx_Val_DynAvg,
+        }
+    }
+}
+
+/// The units for counter time measurements such as period and pulse width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnits {
+    Seconds,
+    /// Timebase ticks of the counter.
+    Ticks,
+    CustomScale,
+}
+
+impl PropertyValue for TimeUnits {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> std::result::Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Seconds => Ok(TimeUnits::Seconds),
+            DAQmx_Val_Ticks => Ok(TimeUnits::Ticks),
+            DAQmx_Val_FromCustomScale => Ok(TimeUnits::CustomScale),
+            _ => Err(DaqmxError::UnexpectedValue("TimeUnits", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            TimeUnits::Seconds => DAQmx_Val_Seconds,
+            TimeUnits::Ticks => DAQmx_Val_Ticks,
+            TimeUnits::CustomScale => DAQmx_Val_FromCustomScale,
+        }
+    }
+}
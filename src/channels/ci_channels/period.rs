@@ -0,0 +1,115 @@
+use crate::channels::ci_channels::{CounterInputKind, CounterMeasurementMethod, TimeUnits};
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::types::ClockEdge;
+use crate::sys::*;
+use std::ffi::CString;
+
+pub struct Period;
+
+impl ChannelKind for Period {}
+impl CounterInputKind for Period {}
+
+impl TaskChannel<Period> {
+    property!(get_set_reset units / set_units / reset_units: TimeUnits = DAQmxGetCIPeriodUnits, DAQmxSetCIPeriodUnits, DAQmxResetCIPeriodUnits);
+    property!(get_set_reset starting_edge / set_starting_edge / reset_starting_edge: ClockEdge = DAQmxGetCIPeriodStartingEdge, DAQmxSetCIPeriodStartingEdge, DAQmxResetCIPeriodStartingEdge);
+    property!(get_set_reset measurement_method / set_measurement_method / reset_measurement_method: CounterMeasurementMethod = DAQmxGetCIPeriodMeasMeth, DAQmxSetCIPeriodMeasMeth, DAQmxResetCIPeriodMeasMeth);
+    property!(get_set_reset measurement_time / set_measurement_time / reset_measurement_time: f64 = DAQmxGetCIPeriodMeasTime, DAQmxSetCIPeriodMeasTime, DAQmxResetCIPeriodMeasTime);
+    property!(get_set_reset divisor / set_divisor / reset_divisor: u32 = DAQmxGetCIPeriodDiv, DAQmxSetCIPeriodDiv, DAQmxResetCIPeriodDiv);
+    property!(get_string input_terminal = DAQmxGetCIPeriodTerm);
+}
+
+pub struct PeriodChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    units: TimeUnits,
+    edge: ClockEdge,
+    measurement_method: CounterMeasurementMethod,
+    measurement_time: f64,
+    divisor: u32,
+}
+
+impl ChannelBuilder for PeriodChannelBuilder {
+    type Kind = Period;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            min: 0.000001,
+            max: 0.1,
+            units: TimeUnits::Seconds,
+            edge: ClockEdge::Rising,
+            measurement_method: CounterMeasurementMethod::LowFrequencyOneCounter,
+            measurement_time: 0.001,
+            divisor: 4,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCIPeriodChan(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.units.into_raw(),
+            self.edge.into(),
+            self.measurement_method.into_raw(),
+            self.measurement_time,
+            self.divisor,
+            std::ptr::null()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl PeriodChannelBuilder {
+    /// The maximum period you expect to measure.
+    pub fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    /// The minimum period you expect to measure.
+    pub fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+
+    pub fn units(self, units: TimeUnits) -> Self {
+        Self { units, ..self }
+    }
+
+    /// The edge of the input signal to start each measurement on.
+    pub fn edge(self, edge: ClockEdge) -> Self {
+        Self { edge, ..self }
+    }
+
+    pub fn measurement_method(self, measurement_method: CounterMeasurementMethod) -> Self {
+        Self {
+            measurement_method,
+            ..self
+        }
+    }
+
+    /// The length of time to measure the signal. Only used with [`CounterMeasurementMethod::HighFrequencyTwoCounters`].
+    pub fn measurement_time(self, measurement_time: f64) -> Self {
+        Self {
+            measurement_time,
+            ..self
+        }
+    }
+
+    /// The value by which to divide the input signal. Only used with [`CounterMeasurementMethod::LargeRangeTwoCounters`].
+    pub fn divisor(self, divisor: u32) -> Self {
+        Self { divisor, ..self }
+    }
+}
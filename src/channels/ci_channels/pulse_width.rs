@@ -0,0 +1,86 @@
+use crate::channels::ci_channels::{CounterInputKind, TimeUnits};
+use crate::channels::properties::{ChannelName, PropertyValue};
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::types::ClockEdge;
+use crate::sys::*;
+use std::ffi::CString;
+
+pub struct PulseWidth;
+
+impl ChannelKind for PulseWidth {}
+impl CounterInputKind for PulseWidth {}
+
+impl TaskChannel<PulseWidth> {
+    property!(get_set_reset units / set_units / reset_units: TimeUnits = DAQmxGetCIPulseWidthUnits, DAQmxSetCIPulseWidthUnits, DAQmxResetCIPulseWidthUnits);
+    property!(get_set_reset starting_edge / set_starting_edge / reset_starting_edge: ClockEdge = DAQmxGetCIPulseWidthStartingEdge, DAQmxSetCIPulseWidthStartingEdge, DAQmxResetCIPulseWidthStartingEdge);
+    property!(get_string input_terminal = DAQmxGetCIPulseWidthTerm);
+}
+
+pub struct PulseWidthChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    min: f64,
+    max: f64,
+    units: TimeUnits,
+    starting_edge: ClockEdge,
+}
+
+impl ChannelBuilder for PulseWidthChannelBuilder {
+    type Kind = PulseWidth;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            min: 0.000001,
+            max: 0.1,
+            units: TimeUnits::Seconds,
+            starting_edge: ClockEdge::Rising,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCIPulseWidthChan(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            self.min,
+            self.max,
+            self.units.into_raw(),
+            self.starting_edge.into(),
+            std::ptr::null()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl PulseWidthChannelBuilder {
+    /// The maximum pulse width you expect to measure.
+    pub fn max(self, max: f64) -> Self {
+        Self { max, ..self }
+    }
+
+    /// The minimum pulse width you expect to measure.
+    pub fn min(self, min: f64) -> Self {
+        Self { min, ..self }
+    }
+
+    pub fn units(self, units: TimeUnits) -> Self {
+        Self { units, ..self }
+    }
+
+    /// The edge of the pulse to start measuring on. [`ClockEdge::Rising`] measures the high time.
+    pub fn starting_edge(self, starting_edge: ClockEdge) -> Self {
+        Self {
+            starting_edge,
+            ..self
+        }
+    }
+}
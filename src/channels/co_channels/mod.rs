@@ -0,0 +1,51 @@
+//! Counter output channels for generating pulses.
+//!
+//! Configure the task with [`crate::tasks::Task::configure_implicit_timing`] to generate a finite or continuous pulse train.
+//! Without timing the task generates a single pulse.
+
+pub mod pulse_frequency;
+pub mod pulse_ticks;
+pub mod pulse_time;
+
+use super::properties::PropertyValue;
+use super::{ChannelKind, TaskChannel, property};
+use crate::error::DaqmxError;
+use crate::types::Level;
+use crate::sys::*;
+
+pub use pulse_frequency::*;
+pub use pulse_ticks::*;
+pub use pulse_time::*;
+
+pub trait CounterOutputKind: ChannelKind {}
+
+/// A counter output channel of any kind, exposing the properties common to all counter outputs.
+pub struct AnyCounterOutput;
+
+impl ChannelKind for AnyCounterOutput {}
+impl CounterOutputKind for AnyCounterOutput {}
+
+impl<K: CounterOutputKind> TaskChannel<K> {
+    property!(get_set_reset idle_state / set_idle_state / reset_idle_state: Level = DAQmxGetCOPulseIdleState, DAQmxSetCOPulseIdleState, DAQmxResetCOPulseIdleState);
+    property!(get_string pulse_terminal = DAQmxGetCOPulseTerm);
+    property!(get co_count: u32 = DAQmxGetCOCount);
+    property!(get output_state: Level = DAQmxGetCOOutputState);
+    property!(get pulse_done: bool = DAQmxGetCOPulseDone);
+}
+
+impl PropertyValue for Level {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_High => Ok(Level::High),
+            DAQmx_Val_Low => Ok(Level::Low),
+            _ => Err(DaqmxError::UnexpectedValue("Level", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        self.into()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::channels::co_channels::CounterOutputKind;
+use crate::channels::properties::ChannelName;
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::types::Level;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// A pulse output defined by frequency and duty cycle.
+pub struct PulseFrequency;
+
+impl ChannelKind for PulseFrequency {}
+impl CounterOutputKind for PulseFrequency {}
+
+impl TaskChannel<PulseFrequency> {
+    property!(get_set_reset frequency / set_frequency / reset_frequency: f64 = DAQmxGetCOPulseFreq, DAQmxSetCOPulseFreq, DAQmxResetCOPulseFreq);
+    property!(get_set_reset duty_cycle / set_duty_cycle / reset_duty_cycle: f64 = DAQmxGetCOPulseDutyCyc, DAQmxSetCOPulseDutyCyc, DAQmxResetCOPulseDutyCyc);
+    property!(get_set_reset initial_delay / set_initial_delay / reset_initial_delay: f64 = DAQmxGetCOPulseFreqInitialDelay, DAQmxSetCOPulseFreqInitialDelay, DAQmxResetCOPulseFreqInitialDelay);
+}
+
+pub struct PulseFrequencyChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    idle_state: Level,
+    initial_delay: f64,
+    frequency: f64,
+    duty_cycle: f64,
+}
+
+impl ChannelBuilder for PulseFrequencyChannelBuilder {
+    type Kind = PulseFrequency;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            idle_state: Level::Low,
+            initial_delay: 0.0,
+            frequency: 1.0,
+            duty_cycle: 0.5,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCOPulseChanFreq(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            DAQmx_Val_Hz,
+            self.idle_state.into(),
+            self.initial_delay,
+            self.frequency,
+            self.duty_cycle
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl PulseFrequencyChannelBuilder {
+    /// The resting state of the output terminal.
+    pub fn idle_state(self, idle_state: Level) -> Self {
+        Self { idle_state, ..self }
+    }
+
+    /// The time in seconds to wait before generating the first pulse.
+    pub fn initial_delay(self, initial_delay: f64) -> Self {
+        Self {
+            initial_delay,
+            ..self
+        }
+    }
+
+    /// The frequency of the pulses in Hz.
+    pub fn frequency(self, frequency: f64) -> Self {
+        Self { frequency, ..self }
+    }
+
+    /// The width of the pulse divided by the pulse period, between 0 and 1.
+    pub fn duty_cycle(self, duty_cycle: f64) -> Self {
+        Self { duty_cycle, ..self }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::channels::co_channels::CounterOutputKind;
+use crate::channels::properties::ChannelName;
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::types::Level;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// A pulse output defined by the number of high and low ticks of a source timebase.
+pub struct PulseTicks;
+
+impl ChannelKind for PulseTicks {}
+impl CounterOutputKind for PulseTicks {}
+
+impl TaskChannel<PulseTicks> {
+    property!(get_set_reset high_ticks / set_high_ticks / reset_high_ticks: u32 = DAQmxGetCOPulseHighTicks, DAQmxSetCOPulseHighTicks, DAQmxResetCOPulseHighTicks);
+    property!(get_set_reset low_ticks / set_low_ticks / reset_low_ticks: u32 = DAQmxGetCOPulseLowTicks, DAQmxSetCOPulseLowTicks, DAQmxResetCOPulseLowTicks);
+    property!(get_set_reset initial_delay / set_initial_delay / reset_initial_delay: u32 = DAQmxGetCOPulseTicksInitialDelay, DAQmxSetCOPulseTicksInitialDelay, DAQmxResetCOPulseTicksInitialDelay);
+    property!(get_string timebase_source = DAQmxGetCOCtrTimebaseSrc);
+}
+
+pub struct PulseTicksChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    source_terminal: CString,
+    idle_state: Level,
+    initial_delay: i32,
+    low_ticks: i32,
+    high_ticks: i32,
+}
+
+impl ChannelBuilder for PulseTicksChannelBuilder {
+    type Kind = PulseTicks;
+
+    /// Creates the builder with the 20MHz timebase of the counters device as the source.
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        let counter = CString::new(counter)?;
+        let device = counter
+            .to_string_lossy()
+            .trim_start_matches('/')
+            .split('/')
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+        Ok(Self {
+            counter,
+            name: ChannelName::default(),
+            source_terminal: CString::new(format!("/{device}/20MHzTimebase"))?,
+            idle_state: Level::Low,
+            initial_delay: 0,
+            low_ticks: 100,
+            high_ticks: 100,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCOPulseChanTicks(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            self.source_terminal.as_ptr(),
+            self.idle_state.into(),
+            self.initial_delay,
+            self.low_ticks,
+            self.high_ticks
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+/// The create function takes tick counts as `int32`, so larger counts are rejected.
+fn ticks_arg(property: &'static str, ticks: u32) -> crate::error::Result<i32> {
+    ticks
+        .try_into()
+        .map_err(|_| DaqmxError::ValueOutOfRange(property, ticks.into()))
+}
+
+impl PulseTicksChannelBuilder {
+    /// The terminal of the timebase to count ticks of.
+    pub fn source_terminal<S: Into<Vec<u8>>>(self, source_terminal: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            source_terminal: CString::new(source_terminal)?,
+            ..self
+        })
+    }
+
+    /// The resting state of the output terminal.
+    pub fn idle_state(self, idle_state: Level) -> Self {
+        Self { idle_state, ..self }
+    }
+
+    /// The number of ticks to wait before generating the first pulse, up to `i32::MAX`.
+    pub fn initial_delay(self, initial_delay: u32) -> crate::error::Result<Self> {
+        Ok(Self {
+            initial_delay: ticks_arg("initial delay", initial_delay)?,
+            ..self
+        })
+    }
+
+    /// The number of ticks the pulse is low, up to `i32::MAX`.
+    pub fn low_ticks(self, low_ticks: u32) -> crate::error::Result<Self> {
+        Ok(Self {
+            low_ticks: ticks_arg("low ticks", low_ticks)?,
+            ..self
+        })
+    }
+
+    /// The number of ticks the pulse is high, up to `i32::MAX`.
+    pub fn high_ticks(self, high_ticks: u32) -> crate::error::Result<Self> {
+        Ok(Self {
+            high_ticks: ticks_arg("high ticks", high_ticks)?,
+            ..self
+        })
+    }
+}
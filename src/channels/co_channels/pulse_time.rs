@@ -0,0 +1,88 @@
+use crate::channels::co_channels::CounterOutputKind;
+use crate::channels::properties::ChannelName;
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::types::Level;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// A pulse output defined by the high and low time in seconds.
+pub struct PulseTime;
+
+impl ChannelKind for PulseTime {}
+impl CounterOutputKind for PulseTime {}
+
+impl TaskChannel<PulseTime> {
+    property!(get_set_reset high_time / set_high_time / reset_high_time: f64 = DAQmxGetCOPulseHighTime, DAQmxSetCOPulseHighTime, DAQmxResetCOPulseHighTime);
+    property!(get_set_reset low_time / set_low_time / reset_low_time: f64 = DAQmxGetCOPulseLowTime, DAQmxSetCOPulseLowTime, DAQmxResetCOPulseLowTime);
+    property!(get_set_reset initial_delay / set_initial_delay / reset_initial_delay: f64 = DAQmxGetCOPulseTimeInitialDelay, DAQmxSetCOPulseTimeInitialDelay, DAQmxResetCOPulseTimeInitialDelay);
+}
+
+pub struct PulseTimeChannelBuilder {
+    counter: CString,
+    name: ChannelName,
+    idle_state: Level,
+    initial_delay: f64,
+    low_time: f64,
+    high_time: f64,
+}
+
+impl ChannelBuilder for PulseTimeChannelBuilder {
+    type Kind = PulseTime;
+
+    fn new<S: Into<Vec<u8>>>(counter: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            counter: CString::new(counter)?,
+            name: ChannelName::default(),
+            idle_state: Level::Low,
+            initial_delay: 0.0,
+            low_time: 0.01,
+            high_time: 0.01,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.counter).to_owned();
+        daqmx_call!(DAQmxCreateCOPulseChanTime(
+            task,
+            self.counter.as_ptr(),
+            self.name.as_ptr(),
+            DAQmx_Val_Seconds,
+            self.idle_state.into(),
+            self.initial_delay,
+            self.low_time,
+            self.high_time
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl PulseTimeChannelBuilder {
+    /// The resting state of the output terminal.
+    pub fn idle_state(self, idle_state: Level) -> Self {
+        Self { idle_state, ..self }
+    }
+
+    /// The time in seconds to wait before generating the first pulse.
+    pub fn initial_delay(self, initial_delay: f64) -> Self {
+        Self {
+            initial_delay,
+            ..self
+        }
+    }
+
+    /// The time in seconds the pulse is low.
+    pub fn low_time(self, low_time: f64) -> Self {
+        Self { low_time, ..self }
+    }
+
+    /// The time in seconds the pulse is high.
+    pub fn high_time(self, high_time: f64) -> Self {
+        Self { high_time, ..self }
+    }
+}
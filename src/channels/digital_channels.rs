@@ -0,0 +1,156 @@
+//! Digital input and output channels.
+//!
+//! Digital channels are created from one or more lines, e.g. `Dev1/port0/line0:7` or a whole port `Dev1/port0`.
+//! The [`LineGrouping`] then determines whether you get a channel per line or a single channel for all lines.
+
+use super::properties::{ChannelName, PropertyValue};
+use super::{ChannelBuilder, ChannelKind, TaskChannel, property};
+use crate::daqmx_call;
+use crate::error::DaqmxError;
+use crate::sys::*;
+use std::ffi::CString;
+
+pub trait DigitalInputKind: ChannelKind {}
+pub trait DigitalOutputKind: ChannelKind {}
+
+/// Determines how the lines passed to a digital channel builder are grouped into virtual channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineGrouping {
+    /// Create one virtual channel for each line.
+    ///
+    /// Use this for per-line boolean reads and writes.
+    #[default]
+    ChannelPerLine,
+    /// Create a single virtual channel containing all of the lines.
+    ///
+    /// Use this for reading and writing whole ports as integers.
+    ChannelForAllLines,
+}
+
+impl PropertyValue for LineGrouping {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self, DaqmxError> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_ChanPerLine => Ok(LineGrouping::ChannelPerLine),
+            DAQmx_Val_ChanForAllLines => Ok(LineGrouping::ChannelForAllLines),
+            _ => Err(DaqmxError::UnexpectedValue("LineGrouping", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        match self {
+            LineGrouping::ChannelPerLine => DAQmx_Val_ChanPerLine,
+            LineGrouping::ChannelForAllLines => DAQmx_Val_ChanForAllLines,
+        }
+    }
+}
+
+pub struct DigitalInputChannel;
+
+impl ChannelKind for DigitalInputChannel {}
+impl DigitalInputKind for DigitalInputChannel {}
+
+impl<K: DigitalInputKind> TaskChannel<K> {
+    property!(get_set_reset di_invert_lines / set_di_invert_lines / reset_di_invert_lines: bool = DAQmxGetDIInvertLines, DAQmxSetDIInvertLines, DAQmxResetDIInvertLines);
+    property!(get di_number_of_lines: u32 = DAQmxGetDINumLines);
+}
+
+pub struct DigitalOutputChannel;
+
+impl ChannelKind for DigitalOutputChannel {}
+impl DigitalOutputKind for DigitalOutputChannel {}
+
+impl<K: DigitalOutputKind> TaskChannel<K> {
+    property!(get_set_reset do_invert_lines / set_do_invert_lines / reset_do_invert_lines: bool = DAQmxGetDOInvertLines, DAQmxSetDOInvertLines, DAQmxResetDOInvertLines);
+    property!(get do_number_of_lines: u32 = DAQmxGetDONumLines);
+}
+
+pub struct DigitalInputChannelBuilder {
+    lines: CString,
+    name: ChannelName,
+    line_grouping: LineGrouping,
+}
+
+impl ChannelBuilder for DigitalInputChannelBuilder {
+    type Kind = DigitalInputChannel;
+
+    /// Create the builder from the lines to include, e.g. `Dev1/port0/line0:3` or `Dev1/port0`.
+    fn new<S: Into<Vec<u8>>>(lines: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            lines: CString::new(lines)?,
+            name: ChannelName::default(),
+            line_grouping: LineGrouping::ChannelPerLine,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.lines).to_owned();
+        daqmx_call!(DAQmxCreateDIChan(
+            task,
+            self.lines.as_ptr(),
+            self.name.as_ptr(),
+            self.line_grouping.into_raw()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl DigitalInputChannelBuilder {
+    pub fn line_grouping(self, line_grouping: LineGrouping) -> Self {
+        Self {
+            line_grouping,
+            ..self
+        }
+    }
+}
+
+pub struct DigitalOutputChannelBuilder {
+    lines: CString,
+    name: ChannelName,
+    line_grouping: LineGrouping,
+}
+
+impl ChannelBuilder for DigitalOutputChannelBuilder {
+    type Kind = DigitalOutputChannel;
+
+    /// Create the builder from the lines to include, e.g. `Dev1/port0/line0:3` or `Dev1/port0`.
+    fn new<S: Into<Vec<u8>>>(lines: S) -> crate::error::Result<Self> {
+        Ok(Self {
+            lines: CString::new(lines)?,
+            name: ChannelName::default(),
+            line_grouping: LineGrouping::ChannelPerLine,
+        })
+    }
+
+    fn name<S: Into<Vec<u8>>>(mut self, name: S) -> crate::error::Result<Self> {
+        self.name.set(name)?;
+        Ok(self)
+    }
+
+    fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>> {
+        let expected_name = self.name.or(&self.lines).to_owned();
+        daqmx_call!(DAQmxCreateDOChan(
+            task,
+            self.lines.as_ptr(),
+            self.name.as_ptr(),
+            self.line_grouping.into_raw()
+        ))?;
+        Ok(TaskChannel::new(task, expected_name))
+    }
+}
+
+impl DigitalOutputChannelBuilder {
+    pub fn line_grouping(self, line_grouping: LineGrouping) -> Self {
+        Self {
+            line_grouping,
+            ..self
+        }
+    }
+}
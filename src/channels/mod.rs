@@ -1,8 +1,18 @@
 pub mod ai_channels;
-mod properties;
+pub mod ao_channels;
+pub mod channel_list;
+pub mod ci_channels;
+pub mod co_channels;
+pub mod digital_channels;
+pub(crate) mod properties;
 
 pub use ai_channels::AnalogInputKind;
-use ni_daqmx_sys::TaskHandle;
+pub use ao_channels::AnalogOutputKind;
+pub use channel_list::ChannelList;
+pub use ci_channels::CounterInputKind;
+pub use co_channels::CounterOutputKind;
+pub use digital_channels::{DigitalInputKind, DigitalOutputKind};
+use crate::sys::TaskHandle;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 
@@ -30,17 +40,20 @@ impl<K: ChannelKind> TaskChannel<K> {
         self.task
     }
 
-    fn name(&self) -> &CStr {
+    /// The name of the virtual channel.
+    pub fn name(&self) -> &CStr {
         &self.name
     }
 }
 macro_rules! property {
-    (get $name:ident: $ty:ty = $getter:path) => {
+    ($(#[$meta:meta])* get $name:ident: $ty:ty = $getter:path) => {
+        $(#[$meta])*
         pub fn $name(&self) -> crate::error::Result<$ty> {
             self.property_get($getter)
         }
     };
-    (get_set $name:ident / $set:ident : $ty:ty = $getter:path, $setter:path) => {
+    ($(#[$meta:meta])* get_set $name:ident / $set:ident : $ty:ty = $getter:path, $setter:path) => {
+        $(#[$meta])*
         pub fn $name(&self) -> crate::error::Result<$ty> {
             self.property_get($getter)
         }
@@ -48,7 +61,8 @@ macro_rules! property {
             self.property_set($setter, value)
         }
     };
-    (get_set_reset $name:ident / $set:ident / $reset:ident : $ty:ty = $getter:path, $setter:path, $resetter:path) => {
+    ($(#[$meta:meta])* get_set_reset $name:ident / $set:ident / $reset:ident : $ty:ty = $getter:path, $setter:path, $resetter:path) => {
+        $(#[$meta])*
         pub fn $name(&self) -> crate::error::Result<$ty> {
             self.property_get($getter)
         }
@@ -59,7 +73,8 @@ macro_rules! property {
             self.property_reset($resetter)
         }
     };
-    (get_string $name:ident = $getter:path) => {
+    ($(#[$meta:meta])* get_string $name:ident = $getter:path) => {
+        $(#[$meta])*
         pub fn $name(&self) -> crate::error::Result<String> {
             self.property_get_string($getter)
         }
@@ -83,4 +98,8 @@ pub trait ChannelBuilder: Sized {
     fn add_to_task(self, task: TaskHandle) -> crate::error::Result<TaskChannel<Self::Kind>>;
 }
 
+impl<K: ChannelKind> TaskChannel<K> {
+    property!(get_string physical_channel = crate::sys::DAQmxGetPhysicalChanName);
+}
+
 pub(crate) use property;
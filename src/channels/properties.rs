@@ -4,7 +4,7 @@
 use crate::channels::{ChannelKind, TaskChannel};
 use crate::daqmx_call;
 use crate::error::{DaqmxError, handle_error, string_property_size_error};
-use ni_daqmx_sys::TaskHandle;
+use crate::sys::TaskHandle;
 use std::ffi::{CStr, CString, c_char};
 
 /// Handles conversion between the DAQmx API and the Rust type.
@@ -27,6 +27,16 @@ macro_rules! identity_property {
 
 identity_property!(f64, i32, u32, u64);
 
+impl PropertyValue for bool {
+    type Raw = crate::sys::bool32;
+    fn from_raw(raw: crate::sys::bool32) -> Result<Self, DaqmxError> {
+        Ok(raw != 0)
+    }
+    fn into_raw(self) -> crate::sys::bool32 {
+        self as crate::sys::bool32
+    }
+}
+
 // int32 DAQmxGetXXX(TaskHandle, const char* chan, T* value)
 pub type ScalarGetter<T> = unsafe extern "C" fn(TaskHandle, *const c_char, *mut T) -> i32;
 // int32 DAQmxSetXXX(TaskHandle, const char* chan, T value)
@@ -34,12 +44,15 @@ pub type ScalarSetter<T> = unsafe extern "C" fn(TaskHandle, *const c_char, T) ->
 
 pub type Resetter = unsafe extern "C" fn(TaskHandle, *const c_char) -> i32;
 
+// int32 DAQmxGetXXX(TaskHandle, const char* chan, float64* data, uInt32 arraySizeInElements)
+pub type ArrayGetter = unsafe extern "C" fn(TaskHandle, *const c_char, *mut f64, u32) -> i32;
+
 impl<K: ChannelKind> TaskChannel<K> {
     ///Read a channel property as a string, given a raw DAQmx Function.
     pub fn property_get_string(
         &self,
         daqmx_fn: unsafe extern "C" fn(
-            ni_daqmx_sys::TaskHandle,
+            crate::sys::TaskHandle,
             *const std::os::raw::c_char,
             *mut std::os::raw::c_char,
             u32,
@@ -77,6 +90,28 @@ impl<K: ChannelKind> TaskChannel<K> {
         return Ok(String::from_utf8(buffer)?);
     }
 
+    /// Read a channel property as an array, given a raw DAQmx Function.
+    pub fn property_get_array(&self, daqmx_fn: ArrayGetter) -> crate::error::Result<Vec<f64>> {
+        let return_value =
+            unsafe { daqmx_fn(self.task(), self.name().as_ptr(), std::ptr::null_mut(), 0) };
+        if return_value < 0 {
+            handle_error(return_value)?;
+        }
+
+        let mut values = vec![0.0; return_value as usize];
+        if values.is_empty() {
+            return Ok(values);
+        }
+        daqmx_call!(daqmx_fn(
+            self.task(),
+            self.name().as_ptr(),
+            values.as_mut_ptr(),
+            values.len() as u32
+        ))?;
+
+        Ok(values)
+    }
+
     fn property_get_raw<T: Default>(&self, daqmx_fn: ScalarGetter<T>) -> crate::error::Result<T> {
         let mut value: T = T::default();
 
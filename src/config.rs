@@ -0,0 +1,1817 @@
+//! Declarative descriptions of analog input tasks, which can be loaded from and saved to files.
+//!
+//! A [`TaskConfig`] lists the channels of a task with the same parameters as their builders, the
+//! sample clock timing and any custom scales the channels use. Any format supported by serde can
+//! be used, for example TOML:
+//!
+//! ```toml
+//! name = "Temperatures"
+//!
+//! [timing]
+//! rate = 100.0
+//! sample_mode = "ContinuousSamples"
+//! samples_per_channel = 1000
+//!
+//! [[channels]]
+//! kind = "Thermocouple"
+//! physical_channel = "Dev1/ai0"
+//! thermocouple_type = "K"
+//!
+//! [[channels]]
+//! kind = "Voltage"
+//! physical_channel = "Dev1/ai1"
+//! name = "Supply"
+//! min = 0.0
+//! max = 10.0
+//! ```
+//!
+//! Parameters which are left out use the defaults of the channel builder.
+//!
+//! This module requires the `serde` feature.
+
+use serde::{Deserialize, Serialize};
+
+use crate::channels::ai_channels::bridge::{
+    Bridge, BridgeChannelBuilder, BridgeConfiguration, BridgeScale,
+};
+use crate::channels::ai_channels::bridge_sensor::{
+    BridgeCalibration, BridgeSensorChannelBuilder, BridgeSensorKind, BridgeSensorScale,
+    ForceBridge, PressureBridge, TorqueBridge,
+};
+use crate::channels::ai_channels::current::current_dc::{
+    CurrentChannel, CurrentChannelBuilder, CurrentScale, ShuntResistorLocation,
+};
+use crate::channels::ai_channels::current::current_rms::{CurrentRms, CurrentRmsChannelBuilder};
+use crate::channels::ai_channels::frequency::{
+    FrequencyScale, FrequencyVoltage, FrequencyVoltageChannelBuilder,
+};
+use crate::channels::ai_channels::iepe::{
+    AccelerationScale, Accelerometer, AccelerometerChannelBuilder, AccelerometerSensitivityUnits,
+    Coupling, ForceIepe, ForceIepeChannelBuilder, ForceIepeSensitivityUnits, ForceScale,
+    Microphone, MicrophoneChannelBuilder, SoundPressureScale,
+};
+use crate::channels::ai_channels::resistance::{
+    ExcitationSource, Resistance, ResistanceChannelBuilder, ResistanceScale, WireConfiguration,
+};
+use crate::channels::ai_channels::strain::{
+    StrainGage, StrainGageChannelBuilder, StrainGageConfiguration, StrainScale,
+};
+use crate::channels::ai_channels::temperature::{
+    BuiltInTemperatureSensor, BuiltInTemperatureSensorBuilder, CjcSource, DaqmxCjcSource,
+    RTDBuilder, RTDType, Rtd, SteinhartHartCoefficients, TemperatureUnits, Thermistor,
+    ThermistorBuilder, ThermistorExcitation, Thermocouple, ThermocoupleBuilder, ThermocoupleType,
+};
+use crate::channels::ai_channels::voltage::{
+    Voltage, VoltageChannelBuilder, VoltageRms, VoltageRmsChannelBuilder, VoltageScale,
+};
+use crate::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, AnyAnalogInputChannel,
+};
+use crate::channels::{ChannelBuilder, ChannelKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::{DaqmxError, Result};
+use crate::scales::{
+    CustomScale, LinearScale, LinearScaling, MapScale, MapScaling, PolynomialScale,
+    PolynomialScaling, PreScaledUnits, ScaleType, Scaling, TableScale, TableScaling,
+};
+use crate::sys::DAQmx_Val_SampClk;
+use crate::tasks::{AnalogInput, Task};
+use crate::types::{ClockEdge, SampleMode};
+use std::ffi::{CStr, CString};
+
+/// The description of an analog input task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaskConfig {
+    /// The name of the task. DAQmx generates a name if this is empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// Sample clock timing. The task is software timed if this is [`None`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing: Option<SampleClockTimingConfig>,
+    /// Custom scales used by the channels, which are created before the channels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scales: Vec<CustomScaleConfig>,
+    pub channels: Vec<AnalogInputChannelConfig>,
+}
+
+impl TaskConfig {
+    /// Create the scales and a task with the channels and timing described.
+    pub fn create_task(&self) -> Result<Task<AnalogInput>> {
+        for scale in &self.scales {
+            scale.create()?;
+        }
+        let mut task = Task::new(&self.name)?;
+        for channel in &self.channels {
+            channel.add_to_task(&mut task)?;
+        }
+        if let Some(timing) = &self.timing {
+            timing.apply(&mut task)?;
+        }
+        Ok(task)
+    }
+
+    /// Describe an existing task by reading back the properties of its channels and timing.
+    ///
+    /// The custom scales used by the channels are read back as well, so the configuration can
+    /// recreate the task on another system.
+    pub fn from_task(task: &Task<AnalogInput>) -> Result<Self> {
+        let channels: Vec<AnalogInputChannelConfig> = task
+            .channel_names()?
+            .iter()
+            .map(|name| AnalogInputChannelConfig::from_channel(task.get_any_channel(name)?))
+            .collect::<Result<_>>()?;
+
+        let mut scale_names: Vec<&str> = Vec::new();
+        for name in channels
+            .iter()
+            .filter_map(AnalogInputChannelConfig::custom_scale)
+        {
+            if !scale_names.contains(&name) {
+                scale_names.push(name);
+            }
+        }
+        let scales = scale_names
+            .into_iter()
+            .map(|name| CustomScaleConfig::from_scale(CustomScale::open(name)?))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            name: task.name()?,
+            timing: SampleClockTimingConfig::from_task(task)?,
+            scales,
+            channels,
+        })
+    }
+}
+
+/// The parameters of [`Task::configure_sample_clock_timing`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SampleClockTimingConfig {
+    /// The terminal of the sample clock. The onboard clock is used if this is [`None`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub rate: f64,
+    #[serde(default)]
+    pub active_edge: ClockEdge,
+    pub sample_mode: SampleMode,
+    pub samples_per_channel: u64,
+}
+
+impl SampleClockTimingConfig {
+    pub fn apply(&self, task: &mut Task<AnalogInput>) -> Result<()> {
+        task.configure_sample_clock_timing(
+            self.source.as_deref(),
+            self.rate,
+            self.active_edge,
+            self.sample_mode,
+            self.samples_per_channel,
+        )
+    }
+
+    /// Read the timing of the task, or [`None`] if it doesn't use a sample clock.
+    pub fn from_task(task: &Task<AnalogInput>) -> Result<Option<Self>> {
+        let mut timing_type = 0;
+        daqmx_call!(crate::sys::DAQmxGetSampTimingType(
+            task.raw_handle(),
+            &mut timing_type
+        ))?;
+        if timing_type != DAQmx_Val_SampClk {
+            return Ok(None);
+        }
+
+        let source = task.sample_clock_source()?;
+        let source = if source.is_empty() || source.eq_ignore_ascii_case("OnboardClock") {
+            None
+        } else {
+            Some(source)
+        };
+        Ok(Some(Self {
+            source,
+            rate: task.sample_clock_rate()?,
+            active_edge: task.sample_clock_active_edge()?,
+            sample_mode: task.sample_mode()?,
+            samples_per_channel: task.samples_per_channel()?,
+        }))
+    }
+}
+
+/// A custom scale from [`crate::scales`], identified by the `type` field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CustomScaleConfig {
+    Linear(LinearScaleConfig),
+    Map(MapScaleConfig),
+    Polynomial(PolynomialScaleConfig),
+    Table(TableScaleConfig),
+}
+
+impl CustomScaleConfig {
+    /// Create the scale in DAQmx so channels can use it by name.
+    pub fn create(&self) -> Result<()> {
+        match self {
+            CustomScaleConfig::Linear(config) => config.create().map(drop),
+            CustomScaleConfig::Map(config) => config.create().map(drop),
+            CustomScaleConfig::Polynomial(config) => config.create().map(drop),
+            CustomScaleConfig::Table(config) => config.create().map(drop),
+        }
+    }
+
+    /// Describe an existing scale by reading back its parameters.
+    pub fn from_scale(scale: CustomScale) -> Result<Self> {
+        let name = channel_name(scale.name());
+        let pre_scaled_units = scale.pre_scaled_units()?;
+        let scaled_units = scale.scaled_units()?;
+        let config = match scale.scale_type()? {
+            ScaleType::Linear => {
+                let scale = scale.into_linear()?;
+                Self::Linear(LinearScaleConfig {
+                    name,
+                    slope: scale.slope()?,
+                    y_intercept: scale.y_intercept()?,
+                    pre_scaled_units,
+                    scaled_units,
+                })
+            }
+            ScaleType::Map => {
+                let scale = scale.into_map()?;
+                Self::Map(MapScaleConfig {
+                    name,
+                    pre_scaled_min: scale.pre_scaled_min()?,
+                    pre_scaled_max: scale.pre_scaled_max()?,
+                    scaled_min: scale.scaled_min()?,
+                    scaled_max: scale.scaled_max()?,
+                    pre_scaled_units,
+                    scaled_units,
+                })
+            }
+            ScaleType::Polynomial => {
+                let scale = scale.into_polynomial()?;
+                Self::Polynomial(PolynomialScaleConfig {
+                    name,
+                    forward_coefficients: scale.forward_coefficients()?,
+                    reverse_coefficients: scale.reverse_coefficients()?,
+                    pre_scaled_units,
+                    scaled_units,
+                })
+            }
+            ScaleType::Table => {
+                let scale = scale.into_table()?;
+                Self::Table(TableScaleConfig {
+                    name,
+                    pre_scaled_values: scale.pre_scaled_values()?,
+                    scaled_values: scale.scaled_values()?,
+                    pre_scaled_units,
+                    scaled_units,
+                })
+            }
+        };
+        Ok(config)
+    }
+
+    /// The scaling described, which evaluates the scale without the driver.
+    pub fn scaling(&self) -> Box<dyn Scaling> {
+        match self {
+            CustomScaleConfig::Linear(config) => Box::new(LinearScaling {
+                slope: config.slope,
+                y_intercept: config.y_intercept,
+            }),
+            CustomScaleConfig::Map(config) => Box::new(MapScaling {
+                pre_scaled_min: config.pre_scaled_min,
+                pre_scaled_max: config.pre_scaled_max,
+                scaled_min: config.scaled_min,
+                scaled_max: config.scaled_max,
+            }),
+            CustomScaleConfig::Polynomial(config) => Box::new(PolynomialScaling {
+                forward_coefficients: config.forward_coefficients.clone(),
+                reverse_coefficients: config.reverse_coefficients.clone(),
+            }),
+            CustomScaleConfig::Table(config) => Box::new(TableScaling {
+                pre_scaled_values: config.pre_scaled_values.clone(),
+                scaled_values: config.scaled_values.clone(),
+            }),
+        }
+    }
+}
+
+/// The parameters of [`LinearScale::new`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinearScaleConfig {
+    pub name: String,
+    pub slope: f64,
+    #[serde(default)]
+    pub y_intercept: f64,
+    pub pre_scaled_units: PreScaledUnits,
+    pub scaled_units: String,
+}
+
+impl LinearScaleConfig {
+    pub fn create(&self) -> Result<LinearScale> {
+        LinearScale::new(
+            &self.name,
+            self.slope,
+            self.y_intercept,
+            self.pre_scaled_units.clone(),
+            &self.scaled_units,
+        )
+    }
+}
+
+/// The parameters of [`MapScale::new`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MapScaleConfig {
+    pub name: String,
+    pub pre_scaled_min: f64,
+    pub pre_scaled_max: f64,
+    pub scaled_min: f64,
+    pub scaled_max: f64,
+    pub pre_scaled_units: PreScaledUnits,
+    pub scaled_units: String,
+}
+
+impl MapScaleConfig {
+    pub fn create(&self) -> Result<MapScale> {
+        MapScale::new(
+            &self.name,
+            self.pre_scaled_min,
+            self.pre_scaled_max,
+            self.scaled_min,
+            self.scaled_max,
+            self.pre_scaled_units.clone(),
+            &self.scaled_units,
+        )
+    }
+}
+
+/// The parameters of [`PolynomialScale::new`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolynomialScaleConfig {
+    pub name: String,
+    pub forward_coefficients: Vec<f64>,
+    pub reverse_coefficients: Vec<f64>,
+    pub pre_scaled_units: PreScaledUnits,
+    pub scaled_units: String,
+}
+
+impl PolynomialScaleConfig {
+    pub fn create(&self) -> Result<PolynomialScale> {
+        PolynomialScale::new(
+            &self.name,
+            &self.forward_coefficients,
+            &self.reverse_coefficients,
+            self.pre_scaled_units.clone(),
+            &self.scaled_units,
+        )
+    }
+}
+
+/// The parameters of [`TableScale::new`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TableScaleConfig {
+    pub name: String,
+    pub pre_scaled_values: Vec<f64>,
+    pub scaled_values: Vec<f64>,
+    pub pre_scaled_units: PreScaledUnits,
+    pub scaled_units: String,
+}
+
+impl TableScaleConfig {
+    pub fn create(&self) -> Result<TableScale> {
+        TableScale::new(
+            &self.name,
+            &self.pre_scaled_values,
+            &self.scaled_values,
+            self.pre_scaled_units.clone(),
+            &self.scaled_units,
+        )
+    }
+}
+
+/// A channel of an analog input task, identified by the `kind` field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum AnalogInputChannelConfig {
+    Voltage(VoltageChannelConfig),
+    VoltageRms(VoltageRmsChannelConfig),
+    Current(CurrentChannelConfig),
+    CurrentRms(CurrentRmsChannelConfig),
+    FrequencyVoltage(FrequencyVoltageChannelConfig),
+    Rtd(RtdChannelConfig),
+    Thermocouple(ThermocoupleChannelConfig),
+    Thermistor(ThermistorChannelConfig),
+    BuiltInTemperatureSensor(BuiltInTemperatureSensorChannelConfig),
+    StrainGage(StrainGageChannelConfig),
+    Bridge(BridgeChannelConfig),
+    Resistance(ResistanceChannelConfig),
+    Accelerometer(AccelerometerChannelConfig),
+    Microphone(MicrophoneChannelConfig),
+    ForceIepe(ForceIepeChannelConfig),
+    PressureBridge(BridgeSensorChannelConfig),
+    ForceBridge(BridgeSensorChannelConfig),
+    TorqueBridge(BridgeSensorChannelConfig),
+}
+
+impl AnalogInputChannelConfig {
+    pub fn add_to_task(&self, task: &mut Task<AnalogInput>) -> Result<()> {
+        match self {
+            AnalogInputChannelConfig::Voltage(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::VoltageRms(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Current(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::CurrentRms(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::FrequencyVoltage(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Rtd(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Thermocouple(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Thermistor(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::BuiltInTemperatureSensor(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::StrainGage(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Bridge(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Resistance(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Accelerometer(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::Microphone(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::ForceIepe(config) => {
+                task.create_channel(config.builder()?)?;
+            }
+            AnalogInputChannelConfig::PressureBridge(config) => {
+                task.create_channel(config.builder::<PressureBridge>()?)?;
+            }
+            AnalogInputChannelConfig::ForceBridge(config) => {
+                task.create_channel(config.builder::<ForceBridge>()?)?;
+            }
+            AnalogInputChannelConfig::TorqueBridge(config) => {
+                task.create_channel(config.builder::<TorqueBridge>()?)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The name of the custom scale used by the channel, if any.
+    pub fn custom_scale(&self) -> Option<&str> {
+        match self {
+            AnalogInputChannelConfig::Voltage(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::VoltageRms(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Current(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::CurrentRms(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::FrequencyVoltage(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::StrainGage(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Bridge(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Resistance(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Accelerometer(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Microphone(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::ForceIepe(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::PressureBridge(config)
+            | AnalogInputChannelConfig::ForceBridge(config)
+            | AnalogInputChannelConfig::TorqueBridge(config) => config.custom_scale.as_deref(),
+            AnalogInputChannelConfig::Rtd(_)
+            | AnalogInputChannelConfig::Thermocouple(_)
+            | AnalogInputChannelConfig::Thermistor(_)
+            | AnalogInputChannelConfig::BuiltInTemperatureSensor(_) => None,
+        }
+    }
+
+    /// Describe a channel in a task by reading back its properties.
+    pub fn from_channel(channel: AnyAnalogInputChannel) -> Result<Self> {
+        match channel {
+            AnyAnalogInputChannel::Voltage(channel) => {
+                VoltageChannelConfig::from_channel(&channel).map(Self::Voltage)
+            }
+            AnyAnalogInputChannel::VoltageRms(channel) => {
+                VoltageRmsChannelConfig::from_channel(&channel).map(Self::VoltageRms)
+            }
+            AnyAnalogInputChannel::Current(channel) => {
+                CurrentChannelConfig::from_channel(&channel).map(Self::Current)
+            }
+            AnyAnalogInputChannel::CurrentRms(channel) => {
+                CurrentRmsChannelConfig::from_channel(&channel).map(Self::CurrentRms)
+            }
+            AnyAnalogInputChannel::FrequencyVoltage(channel) => {
+                FrequencyVoltageChannelConfig::from_channel(&channel).map(Self::FrequencyVoltage)
+            }
+            AnyAnalogInputChannel::Rtd(channel) => {
+                RtdChannelConfig::from_channel(&channel).map(Self::Rtd)
+            }
+            AnyAnalogInputChannel::Thermocouple(channel) => {
+                ThermocoupleChannelConfig::from_channel(&channel).map(Self::Thermocouple)
+            }
+            AnyAnalogInputChannel::Thermistor(channel) => {
+                ThermistorChannelConfig::from_channel(&channel).map(Self::Thermistor)
+            }
+            AnyAnalogInputChannel::BuiltInTemperatureSensor(channel) => {
+                BuiltInTemperatureSensorChannelConfig::from_channel(&channel)
+                    .map(Self::BuiltInTemperatureSensor)
+            }
+            AnyAnalogInputChannel::StrainGage(channel) => {
+                StrainGageChannelConfig::from_channel(&channel).map(Self::StrainGage)
+            }
+            AnyAnalogInputChannel::Bridge(channel) => {
+                BridgeChannelConfig::from_channel(&channel).map(Self::Bridge)
+            }
+            AnyAnalogInputChannel::Resistance(channel) => {
+                ResistanceChannelConfig::from_channel(&channel).map(Self::Resistance)
+            }
+            AnyAnalogInputChannel::Accelerometer(channel) => {
+                AccelerometerChannelConfig::from_channel(&channel).map(Self::Accelerometer)
+            }
+            AnyAnalogInputChannel::Microphone(channel) => {
+                MicrophoneChannelConfig::from_channel(&channel).map(Self::Microphone)
+            }
+            AnyAnalogInputChannel::ForceIepe(channel) => {
+                ForceIepeChannelConfig::from_channel(&channel).map(Self::ForceIepe)
+            }
+            AnyAnalogInputChannel::PressureBridge(channel) => {
+                BridgeSensorChannelConfig::from_channel(&channel).map(Self::PressureBridge)
+            }
+            AnyAnalogInputChannel::ForceBridge(channel) => {
+                BridgeSensorChannelConfig::from_channel(&channel).map(Self::ForceBridge)
+            }
+            AnyAnalogInputChannel::TorqueBridge(channel) => {
+                BridgeSensorChannelConfig::from_channel(&channel).map(Self::TorqueBridge)
+            }
+            AnyAnalogInputChannel::Other(measurement_type, channel) => {
+                Err(DaqmxError::UnsupportedConfiguration(
+                    channel_name(channel.name()),
+                    format!("{measurement_type:?} channels have no configuration"),
+                ))
+            }
+        }
+    }
+}
+
+/// The parameters of [`VoltageChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VoltageChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    /// The name of a custom scale. The channel measures volts if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl VoltageChannelConfig {
+    pub fn builder(&self) -> Result<VoltageChannelBuilder> {
+        let mut builder = VoltageChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(VoltageScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Voltage>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            VoltageScale::Volts => None,
+            VoltageScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+            VoltageScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            terminal_config: Some(channel.terminal_config()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`CurrentChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CurrentChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shunt_resistor_location: Option<ShuntResistorLocation>,
+    /// The name of a custom scale. The channel measures amps if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl CurrentChannelConfig {
+    pub fn builder(&self) -> Result<CurrentChannelBuilder> {
+        let mut builder = CurrentChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(location) = self.shunt_resistor_location {
+            builder = builder.shunt_resistor_location(location);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(CurrentScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<CurrentChannel>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            CurrentScale::Amps => None,
+            CurrentScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+            CurrentScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            terminal_config: Some(channel.terminal_config()?),
+            shunt_resistor_location: Some(channel.shunt_resistor_location()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`VoltageRmsChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VoltageRmsChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    /// The name of a custom scale. The channel measures volts if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl VoltageRmsChannelConfig {
+    pub fn builder(&self) -> Result<VoltageRmsChannelBuilder> {
+        let mut builder = VoltageRmsChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(VoltageScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<VoltageRms>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            VoltageScale::Volts => None,
+            VoltageScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+            VoltageScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            terminal_config: Some(channel.terminal_config()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`CurrentRmsChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CurrentRmsChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shunt_resistor_location: Option<ShuntResistorLocation>,
+    /// The name of a custom scale. The channel measures amps if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl CurrentRmsChannelConfig {
+    pub fn builder(&self) -> Result<CurrentRmsChannelBuilder> {
+        let mut builder = CurrentRmsChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(location) = self.shunt_resistor_location {
+            builder = builder.shunt_resistor_location(location);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(CurrentScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<CurrentRms>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            CurrentScale::Amps => None,
+            CurrentScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+            CurrentScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            terminal_config: Some(channel.terminal_config()?),
+            shunt_resistor_location: Some(channel.shunt_resistor_location()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`FrequencyVoltageChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrequencyVoltageChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// The threshold level in volts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_level: Option<f64>,
+    /// The hysteresis in volts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hysteresis: Option<f64>,
+    /// The name of a custom scale. The channel measures hertz if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl FrequencyVoltageChannelConfig {
+    pub fn builder(&self) -> Result<FrequencyVoltageChannelBuilder> {
+        let mut builder = FrequencyVoltageChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(threshold_level) = self.threshold_level {
+            builder = builder.threshold_level(threshold_level);
+        }
+        if let Some(hysteresis) = self.hysteresis {
+            builder = builder.hysteresis(hysteresis);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(FrequencyScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<FrequencyVoltage>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            FrequencyScale::Hertz => None,
+            FrequencyScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            threshold_level: Some(channel.threshold_level()?),
+            hysteresis: Some(channel.hysteresis()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`RTDBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RtdChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<TemperatureUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtd_type: Option<RTDType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wire_configuration: Option<WireConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r0: Option<f64>,
+}
+
+impl RtdChannelConfig {
+    pub fn builder(&self) -> Result<RTDBuilder> {
+        let mut builder = RTDBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = self.units {
+            builder = builder.units(units);
+        }
+        if let Some(rtd_type) = self.rtd_type {
+            builder = builder.rtd_type(rtd_type);
+        }
+        if let Some(wire_configuration) = self.wire_configuration {
+            builder = builder.wire_configuration(wire_configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(current) = self.excitation_current {
+            builder = builder.excitation_current(current);
+        }
+        if let Some(r0) = self.r0 {
+            builder = builder.r0(r0);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Rtd>) -> Result<Self> {
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units: Some(channel.temperature_units()?),
+            rtd_type: Some(channel.get_type()?),
+            wire_configuration: Some(channel.wire_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_current: Some(channel.excitation_current()?),
+            r0: Some(channel.r0()?),
+        })
+    }
+}
+
+/// The parameters of [`ThermocoupleBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThermocoupleChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thermocouple_type: Option<ThermocoupleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<TemperatureUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cjc_source: Option<CjcSourceConfig>,
+}
+
+/// The cold junction compensation source of a thermocouple, as in [`CjcSource`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CjcSourceConfig {
+    BuiltIn,
+    ConstValue(f64),
+    Channel(String),
+}
+
+impl ThermocoupleChannelConfig {
+    pub fn builder(&self) -> Result<ThermocoupleBuilder> {
+        let mut builder = ThermocoupleBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(thermocouple_type) = self.thermocouple_type {
+            builder = builder.thermocouple_type(thermocouple_type);
+        }
+        if let Some(units) = self.units {
+            builder = builder.units(units);
+        }
+        if let Some(source) = &self.cjc_source {
+            let source = match source {
+                CjcSourceConfig::BuiltIn => CjcSource::BuiltIn,
+                CjcSourceConfig::ConstValue(value) => CjcSource::ConstValue(*value),
+                CjcSourceConfig::Channel(channel) => {
+                    CjcSource::Channel(CString::new(channel.as_str())?)
+                }
+            };
+            builder = builder.cjc_source(source);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Thermocouple>) -> Result<Self> {
+        let cjc_source = match channel.cjc_source()? {
+            DaqmxCjcSource::BuiltIn => CjcSourceConfig::BuiltIn,
+            DaqmxCjcSource::ConstValue => CjcSourceConfig::ConstValue(channel.cjc_value()?),
+            DaqmxCjcSource::Channel => CjcSourceConfig::Channel(channel.cjc_channel()?),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            thermocouple_type: Some(channel.thermocouple_type()?),
+            units: Some(channel.temperature_units()?),
+            cjc_source: Some(cjc_source),
+        })
+    }
+}
+
+/// The parameters of [`ThermistorBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThermistorChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<TemperatureUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wire_configuration: Option<WireConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation: Option<ThermistorExcitation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coefficients: Option<SteinhartHartCoefficients>,
+}
+
+impl ThermistorChannelConfig {
+    pub fn builder(&self) -> Result<ThermistorBuilder> {
+        let mut builder = ThermistorBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = self.units {
+            builder = builder.units(units);
+        }
+        if let Some(wire_configuration) = self.wire_configuration {
+            builder = builder.wire_configuration(wire_configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(excitation) = self.excitation {
+            builder = builder.excitation(excitation);
+        }
+        if let Some(coefficients) = self.coefficients {
+            builder = builder.coefficients(coefficients);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Thermistor>) -> Result<Self> {
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units: Some(channel.temperature_units()?),
+            wire_configuration: Some(channel.wire_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation: Some(channel.excitation()?),
+            coefficients: Some(channel.coefficients()?),
+        })
+    }
+}
+
+/// The parameters of [`BuiltInTemperatureSensorBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BuiltInTemperatureSensorChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<TemperatureUnits>,
+}
+
+impl BuiltInTemperatureSensorChannelConfig {
+    pub fn builder(&self) -> Result<BuiltInTemperatureSensorBuilder> {
+        let mut builder = BuiltInTemperatureSensorBuilder::new(self.physical_channel.as_str())?;
+        if let Some(name) = &self.name {
+            builder = builder.name(name.as_str())?;
+        }
+        if let Some(units) = self.units {
+            builder = builder.units(units);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<BuiltInTemperatureSensor>) -> Result<Self> {
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            units: Some(channel.temperature_units()?),
+        })
+    }
+}
+
+/// The parameters of [`StrainGageChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StrainGageChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strain_gage_configuration: Option<StrainGageConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gage_factor: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_bridge_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nominal_gage_resistance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poisson_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_wire_resistance: Option<f64>,
+    /// The name of a custom scale. The channel measures strain if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl StrainGageChannelConfig {
+    pub fn builder(&self) -> Result<StrainGageChannelBuilder> {
+        let mut builder = StrainGageChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(configuration) = self.strain_gage_configuration {
+            builder = builder.strain_gage_configuration(configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(voltage) = self.excitation_voltage {
+            builder = builder.excitation_voltage(voltage);
+        }
+        if let Some(gage_factor) = self.gage_factor {
+            builder = builder.gage_factor(gage_factor);
+        }
+        if let Some(voltage) = self.initial_bridge_voltage {
+            builder = builder.initial_bridge_voltage(voltage);
+        }
+        if let Some(resistance) = self.nominal_gage_resistance {
+            builder = builder.nominal_gage_resistance(resistance);
+        }
+        if let Some(poisson_ratio) = self.poisson_ratio {
+            builder = builder.poisson_ratio(poisson_ratio);
+        }
+        if let Some(resistance) = self.lead_wire_resistance {
+            builder = builder.lead_wire_resistance(resistance);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(StrainScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<StrainGage>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            StrainScale::Strain => None,
+            StrainScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            strain_gage_configuration: Some(channel.strain_gage_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_voltage: Some(channel.excitation_voltage()?),
+            gage_factor: Some(channel.gage_factor()?),
+            initial_bridge_voltage: Some(channel.initial_bridge_voltage()?),
+            nominal_gage_resistance: Some(channel.nominal_bridge_resistance()?),
+            poisson_ratio: Some(channel.poisson_ratio()?),
+            lead_wire_resistance: Some(channel.lead_wire_resistance()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`BridgeChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BridgeChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// The units of the bridge ratio. Ignored if a custom scale is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<BridgeUnitsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_configuration: Option<BridgeConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nominal_bridge_resistance: Option<f64>,
+    /// The name of a custom scale applied to the bridge ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+/// The units of a bridge channel without a custom scale, as in [`BridgeScale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BridgeUnitsConfig {
+    VoltsPerVolt,
+    MilliVoltsPerVolt,
+}
+
+impl BridgeChannelConfig {
+    pub fn builder(&self) -> Result<BridgeChannelBuilder> {
+        let mut builder = BridgeChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = self.units {
+            builder = builder.scale(match units {
+                BridgeUnitsConfig::VoltsPerVolt => BridgeScale::VoltsPerVolt,
+                BridgeUnitsConfig::MilliVoltsPerVolt => BridgeScale::MilliVoltsPerVolt,
+            });
+        }
+        if let Some(configuration) = self.bridge_configuration {
+            builder = builder.bridge_configuration(configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(voltage) = self.excitation_voltage {
+            builder = builder.excitation_voltage(voltage);
+        }
+        if let Some(resistance) = self.nominal_bridge_resistance {
+            builder = builder.nominal_bridge_resistance(resistance);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(BridgeScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Bridge>) -> Result<Self> {
+        let (units, custom_scale) = match channel.scale()? {
+            BridgeScale::VoltsPerVolt => (Some(BridgeUnitsConfig::VoltsPerVolt), None),
+            BridgeScale::MilliVoltsPerVolt => (Some(BridgeUnitsConfig::MilliVoltsPerVolt), None),
+            BridgeScale::CustomScale(name) => (None, Some(scale_name(name.as_deref()))),
+            BridgeScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units,
+            bridge_configuration: Some(channel.bridge_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_voltage: Some(channel.excitation_voltage()?),
+            nominal_bridge_resistance: Some(channel.nominal_bridge_resistance()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`ResistanceChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResistanceChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wire_configuration: Option<WireConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_wire_resistance: Option<f64>,
+    /// The name of a custom scale. The channel measures ohms if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl ResistanceChannelConfig {
+    pub fn builder(&self) -> Result<ResistanceChannelBuilder> {
+        let mut builder = ResistanceChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(wire_configuration) = self.wire_configuration {
+            builder = builder.wire_configuration(wire_configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(current) = self.excitation_current {
+            builder = builder.excitation_current(current);
+        }
+        if let Some(resistance) = self.lead_wire_resistance {
+            builder = builder.lead_wire_resistance(resistance);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(ResistanceScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Resistance>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            ResistanceScale::Ohms => None,
+            ResistanceScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+            ResistanceScale::FromTEDS => return Err(teds_not_supported(channel)),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            wire_configuration: Some(channel.wire_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_current: Some(channel.excitation_current()?),
+            lead_wire_resistance: Some(channel.lead_wire_resistance()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of a [`BridgeSensorChannelBuilder`], used by the pressure, force and torque
+/// bridge channels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BridgeSensorChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// The units of the channel. Ignored if a custom scale is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<PreScaledUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_configuration: Option<BridgeConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nominal_bridge_resistance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibration: Option<BridgeCalibration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub electrical_units: Option<PreScaledUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_units: Option<PreScaledUnits>,
+    /// The name of a custom scale applied to the physical value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl BridgeSensorChannelConfig {
+    pub fn builder<K: BridgeSensorKind>(&self) -> Result<BridgeSensorChannelBuilder<K>> {
+        let mut builder = BridgeSensorChannelBuilder::<K>::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = &self.units {
+            builder = builder.scale(BridgeSensorScale::Units(units.clone()));
+        }
+        if let Some(configuration) = self.bridge_configuration {
+            builder = builder.bridge_configuration(configuration);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(voltage) = self.excitation_voltage {
+            builder = builder.excitation_voltage(voltage);
+        }
+        if let Some(resistance) = self.nominal_bridge_resistance {
+            builder = builder.nominal_bridge_resistance(resistance);
+        }
+        if let Some(calibration) = &self.calibration {
+            builder = builder.calibration(calibration.clone());
+        }
+        if let Some(units) = &self.electrical_units {
+            builder = builder.electrical_units(units.clone());
+        }
+        if let Some(units) = &self.physical_units {
+            builder = builder.physical_units(units.clone());
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(BridgeSensorScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel<K: BridgeSensorKind>(channel: &TaskChannel<K>) -> Result<Self> {
+        let (units, custom_scale) = match channel.scale()? {
+            BridgeSensorScale::Units(PreScaledUnits::FromTEDS) => {
+                return Err(teds_not_supported(channel));
+            }
+            BridgeSensorScale::Units(units) => (Some(units), None),
+            BridgeSensorScale::CustomScale(name) => (None, Some(scale_name(name.as_deref()))),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units,
+            bridge_configuration: Some(channel.bridge_configuration()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_voltage: Some(channel.excitation_voltage()?),
+            nominal_bridge_resistance: Some(channel.nominal_bridge_resistance()?),
+            calibration: Some(channel.calibration()?),
+            electrical_units: Some(channel.electrical_units()?),
+            physical_units: Some(channel.physical_units()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`AccelerometerChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccelerometerChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// The units of acceleration. Ignored if a custom scale is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<AccelerationUnitsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity_units: Option<AccelerometerSensitivityUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupling: Option<Coupling>,
+    /// The name of a custom scale applied to the acceleration in g.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+/// The units of an accelerometer channel without a custom scale, as in [`AccelerationScale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccelerationUnitsConfig {
+    G,
+    MetersPerSecondSquared,
+    InchesPerSecondSquared,
+}
+
+impl AccelerometerChannelConfig {
+    pub fn builder(&self) -> Result<AccelerometerChannelBuilder> {
+        let mut builder = AccelerometerChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = self.units {
+            builder = builder.scale(match units {
+                AccelerationUnitsConfig::G => AccelerationScale::G,
+                AccelerationUnitsConfig::MetersPerSecondSquared => {
+                    AccelerationScale::MetersPerSecondSquared
+                }
+                AccelerationUnitsConfig::InchesPerSecondSquared => {
+                    AccelerationScale::InchesPerSecondSquared
+                }
+            });
+        }
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(sensitivity) = self.sensitivity {
+            builder = builder.sensitivity(sensitivity);
+        }
+        if let Some(units) = self.sensitivity_units {
+            builder = builder.sensitivity_units(units);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(current) = self.excitation_current {
+            builder = builder.excitation_current(current);
+        }
+        if let Some(coupling) = self.coupling {
+            builder = builder.coupling(coupling);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(AccelerationScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Accelerometer>) -> Result<Self> {
+        let (units, custom_scale) = match channel.scale()? {
+            AccelerationScale::G => (Some(AccelerationUnitsConfig::G), None),
+            AccelerationScale::MetersPerSecondSquared => {
+                (Some(AccelerationUnitsConfig::MetersPerSecondSquared), None)
+            }
+            AccelerationScale::InchesPerSecondSquared => {
+                (Some(AccelerationUnitsConfig::InchesPerSecondSquared), None)
+            }
+            AccelerationScale::CustomScale(name) => (None, Some(scale_name(name.as_deref()))),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units,
+            terminal_config: Some(channel.terminal_config()?),
+            sensitivity: Some(channel.sensitivity()?),
+            sensitivity_units: Some(channel.sensitivity_units()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_current: Some(channel.excitation_current()?),
+            coupling: Some(channel.coupling()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`MicrophoneChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MicrophoneChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_sound_pressure_level: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupling: Option<Coupling>,
+    /// The name of a custom scale. The channel measures pascals if this is [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+impl MicrophoneChannelConfig {
+    pub fn builder(&self) -> Result<MicrophoneChannelBuilder> {
+        let mut builder = MicrophoneChannelBuilder::new(self.physical_channel.as_str())?;
+        if let Some(name) = &self.name {
+            builder = builder.name(name.as_str())?;
+        }
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(sensitivity) = self.sensitivity {
+            builder = builder.sensitivity(sensitivity);
+        }
+        if let Some(level) = self.max_sound_pressure_level {
+            builder = builder.max_sound_pressure_level(level);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(current) = self.excitation_current {
+            builder = builder.excitation_current(current);
+        }
+        if let Some(coupling) = self.coupling {
+            builder = builder.coupling(coupling);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(SoundPressureScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<Microphone>) -> Result<Self> {
+        let custom_scale = match channel.scale()? {
+            SoundPressureScale::Pascals => None,
+            SoundPressureScale::CustomScale(name) => Some(scale_name(name.as_deref())),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            terminal_config: Some(channel.terminal_config()?),
+            sensitivity: Some(channel.sensitivity()?),
+            max_sound_pressure_level: Some(channel.max_sound_pressure_level()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_current: Some(channel.excitation_current()?),
+            coupling: Some(channel.coupling()?),
+            custom_scale,
+        })
+    }
+}
+
+/// The parameters of [`ForceIepeChannelBuilder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForceIepeChannelConfig {
+    pub physical_channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// The units of force. Ignored if a custom scale is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<ForceUnitsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_config: Option<AnalogTerminalConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity_units: Option<ForceIepeSensitivityUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_source: Option<ExcitationSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excitation_current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupling: Option<Coupling>,
+    /// The name of a custom scale applied to the force in newtons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scale: Option<String>,
+}
+
+/// The units of a force channel without a custom scale, as in [`ForceScale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForceUnitsConfig {
+    Newtons,
+    Pounds,
+}
+
+impl ForceIepeChannelConfig {
+    pub fn builder(&self) -> Result<ForceIepeChannelBuilder> {
+        let mut builder = ForceIepeChannelBuilder::new(self.physical_channel.as_str())?;
+        builder = apply_common(builder, self.name.as_deref(), self.min, self.max)?;
+        if let Some(units) = self.units {
+            builder = builder.scale(match units {
+                ForceUnitsConfig::Newtons => ForceScale::Newtons,
+                ForceUnitsConfig::Pounds => ForceScale::Pounds,
+            });
+        }
+        if let Some(terminal_config) = self.terminal_config {
+            builder = builder.terminal_config(terminal_config);
+        }
+        if let Some(sensitivity) = self.sensitivity {
+            builder = builder.sensitivity(sensitivity);
+        }
+        if let Some(units) = self.sensitivity_units {
+            builder = builder.sensitivity_units(units);
+        }
+        if let Some(source) = self.excitation_source {
+            builder = builder.excitation_source(source);
+        }
+        if let Some(current) = self.excitation_current {
+            builder = builder.excitation_current(current);
+        }
+        if let Some(coupling) = self.coupling {
+            builder = builder.coupling(coupling);
+        }
+        if let Some(scale) = &self.custom_scale {
+            builder = builder.scale(ForceScale::new_custom(scale)?);
+        }
+        Ok(builder)
+    }
+
+    pub fn from_channel(channel: &TaskChannel<ForceIepe>) -> Result<Self> {
+        let (units, custom_scale) = match channel.scale()? {
+            ForceScale::Newtons => (Some(ForceUnitsConfig::Newtons), None),
+            ForceScale::Pounds => (Some(ForceUnitsConfig::Pounds), None),
+            ForceScale::CustomScale(name) => (None, Some(scale_name(name.as_deref()))),
+        };
+        Ok(Self {
+            physical_channel: channel.physical_channel()?,
+            name: assigned_name(channel)?,
+            min: Some(channel.ai_min()?),
+            max: Some(channel.ai_max()?),
+            units,
+            terminal_config: Some(channel.terminal_config()?),
+            sensitivity: Some(channel.sensitivity()?),
+            sensitivity_units: Some(channel.sensitivity_units()?),
+            excitation_source: Some(channel.excitation_source()?),
+            excitation_current: Some(channel.excitation_current()?),
+            coupling: Some(channel.coupling()?),
+            custom_scale,
+        })
+    }
+}
+
+/// Apply the parameters shared by all of the analog input builders.
+fn apply_common<B: AnalogChannelBuilder>(
+    mut builder: B,
+    name: Option<&str>,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> Result<B> {
+    if let Some(name) = name {
+        builder = builder.name(name)?;
+    }
+    if let Some(min) = min {
+        builder = builder.min(min);
+    }
+    if let Some(max) = max {
+        builder = builder.max(max);
+    }
+    Ok(builder)
+}
+
+/// The name of the channel if it differs from the physical channel it was created on.
+fn assigned_name<K: ChannelKind>(channel: &TaskChannel<K>) -> Result<Option<String>> {
+    let name = channel_name(channel.name());
+    if name == channel.physical_channel()? {
+        Ok(None)
+    } else {
+        Ok(Some(name))
+    }
+}
+
+fn scale_name(name: Option<&CString>) -> String {
+    name.map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn teds_not_supported<K: ChannelKind>(channel: &TaskChannel<K>) -> DaqmxError {
+    DaqmxError::UnsupportedConfiguration(
+        channel_name(channel.name()),
+        "units from TEDS have no configuration".to_owned(),
+    )
+}
+
+fn channel_name(name: &CStr) -> String {
+    name.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TOML: &str = r#"
+        name = "Temperatures"
+
+        [timing]
+        rate = 100.0
+        sample_mode = "ContinuousSamples"
+        samples_per_channel = 1000
+
+        [[scales]]
+        type = "Linear"
+        name = "Pressure"
+        slope = 2.5
+        pre_scaled_units = "Volts"
+        scaled_units = "bar"
+
+        [[scales]]
+        type = "Table"
+        name = "Flow"
+        pre_scaled_values = [0.0, 1.0, 5.0]
+        scaled_values = [0.0, 0.5, 10.0]
+        pre_scaled_units = "Volts"
+        scaled_units = "l/min"
+
+        [[channels]]
+        kind = "Thermocouple"
+        physical_channel = "Dev1/ai0"
+        thermocouple_type = "K"
+        cjc_source = { ConstValue = 20.0 }
+
+        [[channels]]
+        kind = "Voltage"
+        physical_channel = "Dev1/ai1"
+        name = "Inlet"
+        custom_scale = "Pressure"
+
+        [[channels]]
+        kind = "Rtd"
+        physical_channel = "Dev1/ai2"
+        rtd_type = { Custom = { a = 0.0039, b = -5.8e-7, c = 0.0 } }
+    "#;
+
+    #[test]
+    fn test_load_toml() {
+        let config: TaskConfig = toml::from_str(TOML).unwrap();
+        assert_eq!(config.name, "Temperatures");
+        assert_eq!(
+            config.timing,
+            Some(SampleClockTimingConfig {
+                source: None,
+                rate: 100.0,
+                active_edge: ClockEdge::Rising,
+                sample_mode: SampleMode::ContinuousSamples,
+                samples_per_channel: 1000,
+            })
+        );
+        assert_eq!(config.scales.len(), 2);
+        assert!(matches!(config.scales[1], CustomScaleConfig::Table(_)));
+        assert_eq!(
+            config.channels[1],
+            AnalogInputChannelConfig::Voltage(VoltageChannelConfig {
+                physical_channel: "Dev1/ai1".to_owned(),
+                name: Some("Inlet".to_owned()),
+                min: None,
+                max: None,
+                terminal_config: None,
+                custom_scale: Some("Pressure".to_owned()),
+            })
+        );
+        let AnalogInputChannelConfig::Rtd(rtd) = &config.channels[2] else {
+            panic!("expected an RTD channel");
+        };
+        assert!(matches!(rtd.rtd_type, Some(RTDType::Custom { .. })));
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let config: TaskConfig = toml::from_str(TOML).unwrap();
+        let text = toml::to_string(&config).unwrap();
+        assert_eq!(toml::from_str::<TaskConfig>(&text).unwrap(), config);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let config: TaskConfig = toml::from_str(TOML).unwrap();
+        let text = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<TaskConfig>(&text).unwrap(), config);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let text =
+            r#"{"channels": [{"kind": "Voltage", "physical_channel": "Dev1/ai0", "maxx": 1}]}"#;
+        assert!(serde_json::from_str::<TaskConfig>(text).is_err());
+        let text = text.replace("maxx", "max");
+        assert!(serde_json::from_str::<TaskConfig>(&text).is_ok());
+    }
+
+    #[test]
+    fn test_scale_scaling() {
+        let config: TaskConfig = toml::from_str(TOML).unwrap();
+        assert_eq!(config.scales[0].scaling().apply(2.0), 5.0);
+        assert_eq!(config.scales[1].scaling().apply(3.0), 5.25);
+        assert_eq!(config.scales[1].scaling().reverse(5.25), 3.0);
+    }
+}
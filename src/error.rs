@@ -2,6 +2,8 @@ use log::warn;
 /// Error handling types and functions.
 use thiserror::Error;
 
+use crate::channels::ai_channels::MeasurementType;
+use crate::scales::ScaleType;
 use crate::types::buffer_to_string;
 
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -21,6 +23,24 @@ pub enum DaqmxError {
     StringPropertyLengthChanged,
     #[error("Value for given type ({0}) isn't a value that is expected: {1}")]
     UnexpectedValue(&'static str, i32),
+    #[error(
+        "Write buffer length ({0}) is not a multiple of the number of channels in the task ({1})"
+    )]
+    WriteBufferSize(usize, u32),
+    #[error("Channel list entry is not a valid channel or range: {0}")]
+    InvalidChannelList(String),
+    #[error("Channel {0} is not in the task")]
+    ChannelNotInTask(String),
+    #[error("Channel {0} measures {2:?} but the requested channel kind measures {1:?}")]
+    ChannelKindMismatch(String, MeasurementType, MeasurementType),
+    #[error("Saved task {0} contains channels of a different type to the requested task")]
+    TaskTypeMismatch(String),
+    #[error("Scale {0} is a {2:?} scale but a {1:?} scale was requested")]
+    ScaleTypeMismatch(String, ScaleType, ScaleType),
+    #[error("Channel {0} can't be described by a task configuration: {1}")]
+    UnsupportedConfiguration(String, String),
+    #[error("Value for {0} ({1}) is outside the range DAQmx accepts")]
+    ValueOutOfRange(&'static str, u64),
 }
 
 pub type Result<T> = std::result::Result<T, DaqmxError>;
@@ -35,7 +55,7 @@ pub fn handle_error(return_code: i32) -> Result<()> {
             //use extended info for errors.
             unsafe {
                 let mut buffer = vec![0i8; 2048];
-                ni_daqmx_sys::DAQmxGetExtendedErrorInfo(buffer.as_mut_ptr(), 2048);
+                crate::sys::DAQmxGetExtendedErrorInfo(buffer.as_mut_ptr(), 2048);
                 let message = buffer_to_string(buffer);
                 return Result::Err(DaqmxError::DaqmxError(return_code, message));
             }
@@ -44,7 +64,7 @@ pub fn handle_error(return_code: i32) -> Result<()> {
             //use error string for warning. Just report to log.
             unsafe {
                 let mut buffer = vec![0i8; 2048];
-                ni_daqmx_sys::DAQmxGetErrorString(return_code, buffer.as_mut_ptr(), 2048);
+                crate::sys::DAQmxGetErrorString(return_code, buffer.as_mut_ptr(), 2048);
                 let message = buffer_to_string(buffer);
                 warn!("DAQmx Warning: {:}", message);
             }
@@ -59,9 +79,9 @@ pub fn handle_error(return_code: i32) -> Result<()> {
 /// * Returns `true` if there is a size error.
 /// * Returns `false` if there is no error.
 pub fn string_property_size_error(return_code: i32) -> Result<bool> {
-    const TRUNCATED_WARNING: i32 = ni_daqmx_sys::DAQmxWarningCAPIStringTruncatedToFitBuffer as i32;
+    const TRUNCATED_WARNING: i32 = crate::sys::DAQmxWarningCAPIStringTruncatedToFitBuffer as i32;
     match return_code {
-        ni_daqmx_sys::DAQmxErrorBufferTooSmallForString | TRUNCATED_WARNING => {
+        crate::sys::DAQmxErrorBufferTooSmallForString | TRUNCATED_WARNING => {
             // Wrong size, go again.
             Ok(true)
         }
@@ -80,12 +100,12 @@ mod test {
     fn test_string_property_size_error() {
         assert_eq!(string_property_size_error(0), Ok(false));
         assert_eq!(
-            string_property_size_error(ni_daqmx_sys::DAQmxErrorBufferTooSmallForString),
+            string_property_size_error(crate::sys::DAQmxErrorBufferTooSmallForString),
             Ok(true)
         );
         assert_eq!(
             string_property_size_error(
-                ni_daqmx_sys::DAQmxWarningCAPIStringTruncatedToFitBuffer as i32
+                crate::sys::DAQmxWarningCAPIStringTruncatedToFitBuffer as i32
             ),
             Ok(true)
         );
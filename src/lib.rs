@@ -1,6 +1,11 @@
 pub mod channels;
+#[cfg(feature = "serde")]
+pub mod config;
 pub mod error;
+pub mod persistence;
 pub mod scales;
+mod sys;
+pub mod system;
 pub mod tasks;
 pub mod types;
 
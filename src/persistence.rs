@@ -0,0 +1,110 @@
+//! Persistence of tasks, global channels and scales in the MAX configuration.
+//!
+//! Items saved here can be edited in MAX and loaded by name, e.g. with
+//! [`Task::load`](crate::tasks::Task::load), so configuration doesn't need to be duplicated in code.
+
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::system::get_system_list;
+use std::ffi::CString;
+
+/// Options for saving a task, global channel or scale to MAX.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SaveOptions {
+    /// Save with this name rather than the current name.
+    pub save_as: Option<String>,
+    /// The author recorded with the saved item.
+    pub author: String,
+    /// Replace an item already saved with the same name. If false, saving over an existing item is an error.
+    pub overwrite: bool,
+    /// Allow the item to be edited interactively in the DAQ Assistant.
+    pub allow_interactive_editing: bool,
+    /// Allow the item to be deleted interactively in MAX.
+    pub allow_interactive_deletion: bool,
+}
+
+/// Save options converted for the DAQmx API.
+pub(crate) struct RawSaveOptions {
+    pub save_as: CString,
+    pub author: CString,
+    pub options: u32,
+}
+
+impl TryFrom<&SaveOptions> for RawSaveOptions {
+    type Error = crate::error::DaqmxError;
+
+    fn try_from(options: &SaveOptions) -> Result<Self> {
+        let mut flags = 0;
+        if options.overwrite {
+            flags |= crate::sys::DAQmx_Val_Save_Overwrite;
+        }
+        if options.allow_interactive_editing {
+            flags |= crate::sys::DAQmx_Val_Save_AllowInteractiveEditing;
+        }
+        if options.allow_interactive_deletion {
+            flags |= crate::sys::DAQmx_Val_Save_AllowInteractiveDeletion;
+        }
+        Ok(Self {
+            // An empty name saves with the current name.
+            save_as: CString::new(options.save_as.as_deref().unwrap_or_default())?,
+            author: CString::new(options.author.as_str())?,
+            options: flags as u32,
+        })
+    }
+}
+
+/// Gets the names of the tasks saved in MAX.
+pub fn saved_tasks() -> Result<Vec<String>> {
+    get_system_list(crate::sys::DAQmxGetSysTasks)
+}
+
+/// Gets the names of the global channels saved in MAX.
+pub fn saved_global_channels() -> Result<Vec<String>> {
+    get_system_list(crate::sys::DAQmxGetSysGlobalChans)
+}
+
+/// Gets the names of the custom scales saved in MAX.
+pub fn saved_scales() -> Result<Vec<String>> {
+    get_system_list(crate::sys::DAQmxGetSysScales)
+}
+
+/// Deletes a task from MAX.
+pub fn delete_saved_task(name: &str) -> Result<()> {
+    let name = CString::new(name)?;
+    daqmx_call!(crate::sys::DAQmxDeleteSavedTask(name.as_ptr()))
+}
+
+/// Deletes a global channel from MAX.
+pub fn delete_saved_global_channel(name: &str) -> Result<()> {
+    let name = CString::new(name)?;
+    daqmx_call!(crate::sys::DAQmxDeleteSavedGlobalChan(name.as_ptr()))
+}
+
+/// Deletes a custom scale from MAX.
+pub fn delete_saved_scale(name: &str) -> Result<()> {
+    let name = CString::new(name)?;
+    daqmx_call!(crate::sys::DAQmxDeleteSavedScale(name.as_ptr()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_raw_save_options() {
+        let options = SaveOptions {
+            save_as: Some("saved".to_owned()),
+            overwrite: true,
+            allow_interactive_deletion: true,
+            ..Default::default()
+        };
+        let raw = RawSaveOptions::try_from(&options).unwrap();
+        assert_eq!(raw.save_as.to_str().unwrap(), "saved");
+        assert_eq!(raw.author.to_str().unwrap(), "");
+        assert_eq!(
+            raw.options,
+            (crate::sys::DAQmx_Val_Save_Overwrite
+                | crate::sys::DAQmx_Val_Save_AllowInteractiveDeletion) as u32
+        );
+    }
+}
@@ -1,18 +1,72 @@
 //! Implements custom scaling functions
 //!
+//! Scales are created in DAQmx with the constructors of the specific scale types, then used by
+//! name from channels. Existing scales, such as those in MAX or used by a channel, can be opened
+//! by name to read back their parameters.
+//!
+//! ```no_run
+//! use daqmx::scales::{CustomScale, ScaleType};
+//!
+//! let scale = CustomScale::open("Pressure").unwrap();
+//! if scale.scale_type().unwrap() == ScaleType::Linear {
+//!     let linear = scale.into_linear().unwrap();
+//!     println!("{} {}/V", linear.slope().unwrap(), linear.scaled_units().unwrap());
+//! }
+//! ```
+//!
+//! The parameters of a scale can also be read into a [`Scaling`] type, which applies the scale in
+//! Rust without the driver, for example to post-process recorded raw values offline.
+
+mod scaling;
+
+pub use scaling::{LinearScaling, MapScaling, PolynomialScaling, Scaling, TableScaling};
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::ops::Deref;
 
-use ni_daqmx_sys::*;
+use crate::sys::*;
 
 use crate::{
+    channels::properties::PropertyValue,
     daqmx_call,
     error::{DaqmxError, Result},
+    persistence::{RawSaveOptions, SaveOptions},
+    system::{get_array, get_scalar, get_string},
 };
 
+/// Generates getters and setters for properties of the scale named by `self.name`.
+macro_rules! property {
+    (get $name:ident: $ty:ty = $getter:path) => {
+        pub fn $name(&self) -> Result<$ty> {
+            get_scalar($getter, self.name())
+        }
+    };
+    (get_set $name:ident / $set:ident: $ty:ty = $getter:path, $setter:path) => {
+        property!(get $name: $ty = $getter);
+        pub fn $set(&self, value: $ty) -> Result<()> {
+            daqmx_call!($setter(self.name().as_ptr(), value.into_raw()))
+        }
+    };
+    (get_set_string $name:ident / $set:ident = $getter:path, $setter:path) => {
+        pub fn $name(&self) -> Result<String> {
+            get_string($getter, self.name())
+        }
+        pub fn $set(&self, value: &str) -> Result<()> {
+            let value = CString::new(value)?;
+            daqmx_call!($setter(self.name().as_ptr(), value.as_ptr()))
+        }
+    };
+    (get_array $name:ident = $getter:path) => {
+        pub fn $name(&self) -> Result<Vec<f64>> {
+            get_array($getter, self.name())
+        }
+    };
+}
+
 /// The custom scale type encapsulates common custom scale functions used by all scale types.
 ///
-/// It is intended to be used as an inner type for more specific types.
+/// It is intended to be used as an inner type for more specific types, which dereference to it
+/// for the common properties.
 pub struct CustomScale {
     name: CString,
 }
@@ -21,6 +75,102 @@ impl CustomScale {
     fn new(name: CString) -> Self {
         Self { name }
     }
+
+    /// Open an existing scale by name, e.g. one saved in MAX or used by a channel.
+    ///
+    /// This fails if the scale doesn't exist.
+    pub fn open<S: Into<Vec<u8>>>(name: S) -> Result<Self> {
+        let scale = Self::new(CString::new(name)?);
+        scale.scale_type()?;
+        Ok(scale)
+    }
+
+    pub fn name(&self) -> &CStr {
+        &self.name
+    }
+
+    property!(get scale_type: ScaleType = DAQmxGetScaleType);
+    property!(get_set_string description / set_description = DAQmxGetScaleDescr, DAQmxSetScaleDescr);
+    property!(get_set pre_scaled_units / set_pre_scaled_units: PreScaledUnits =
+        DAQmxGetScalePreScaledUnits, DAQmxSetScalePreScaledUnits);
+    property!(get_set_string scaled_units / set_scaled_units =
+        DAQmxGetScaleScaledUnits, DAQmxSetScaleScaledUnits);
+
+    /// Convert to a [`LinearScale`], checking the scale is linear.
+    pub fn into_linear(self) -> Result<LinearScale> {
+        self.check_type(ScaleType::Linear)?;
+        Ok(LinearScale { inner: self })
+    }
+
+    /// Convert to a [`MapScale`], checking the scale maps ranges.
+    pub fn into_map(self) -> Result<MapScale> {
+        self.check_type(ScaleType::Map)?;
+        Ok(MapScale { inner: self })
+    }
+
+    /// Convert to a [`PolynomialScale`], checking the scale is polynomial.
+    pub fn into_polynomial(self) -> Result<PolynomialScale> {
+        self.check_type(ScaleType::Polynomial)?;
+        Ok(PolynomialScale { inner: self })
+    }
+
+    /// Convert to a [`TableScale`], checking the scale is a table.
+    pub fn into_table(self) -> Result<TableScale> {
+        self.check_type(ScaleType::Table)?;
+        Ok(TableScale { inner: self })
+    }
+
+    fn check_type(&self, expected: ScaleType) -> Result<()> {
+        let actual = self.scale_type()?;
+        if actual != expected {
+            return Err(DaqmxError::ScaleTypeMismatch(
+                self.name.to_string_lossy().into_owned(),
+                expected,
+                actual,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Save the scale to MAX, using the scale name unless `save_as` is set.
+    pub fn save(&self, options: &SaveOptions) -> Result<()> {
+        let options = RawSaveOptions::try_from(options)?;
+        daqmx_call!(DAQmxSaveScale(
+            self.name.as_ptr(),
+            options.save_as.as_ptr(),
+            options.author.as_ptr(),
+            options.options
+        ))
+    }
+}
+
+/// The types of custom scale.
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScaleType {
+    Linear = DAQmx_Val_Linear,
+    Map = DAQmx_Val_MapRanges,
+    Polynomial = DAQmx_Val_Polynomial,
+    Table = DAQmx_Val_Table,
+}
+
+impl PropertyValue for ScaleType {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            DAQmx_Val_Linear => Ok(Self::Linear),
+            DAQmx_Val_MapRanges => Ok(Self::Map),
+            DAQmx_Val_Polynomial => Ok(Self::Polynomial),
+            DAQmx_Val_Table => Ok(Self::Table),
+            _ => Err(DaqmxError::UnexpectedValue("ScaleType", raw)),
+        }
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        self as i32
+    }
 }
 
 /// A linear custom scale.
@@ -50,11 +200,259 @@ impl LinearScale {
             inner: CustomScale::new(name),
         })
     }
+
+    /// Open an existing linear scale by name.
+    pub fn open<S: Into<Vec<u8>>>(name: S) -> Result<Self> {
+        CustomScale::open(name)?.into_linear()
+    }
+
+    property!(get_set slope / set_slope: f64 = DAQmxGetScaleLinSlope, DAQmxSetScaleLinSlope);
+    property!(get_set y_intercept / set_y_intercept: f64 =
+        DAQmxGetScaleLinYIntercept, DAQmxSetScaleLinYIntercept);
+
+    /// Read the scale parameters to evaluate the scale without the driver.
+    pub fn scaling(&self) -> Result<LinearScaling> {
+        Ok(LinearScaling {
+            slope: self.slope()?,
+            y_intercept: self.y_intercept()?,
+        })
+    }
+}
+
+/// A map custom scale.
+///
+/// This scales the inputs linearly so the pre-scaled range maps onto the scaled range.
+pub struct MapScale {
+    inner: CustomScale,
+}
+
+impl MapScale {
+    pub fn new(
+        name: &str,
+        pre_scaled_min: f64,
+        pre_scaled_max: f64,
+        scaled_min: f64,
+        scaled_max: f64,
+        pre_scaled_units: PreScaledUnits,
+        scaled_units: &str,
+    ) -> Result<Self> {
+        let name = CString::new(name)?;
+        daqmx_call!(DAQmxCreateMapScale(
+            name.as_ptr(),
+            pre_scaled_min,
+            pre_scaled_max,
+            scaled_min,
+            scaled_max,
+            pre_scaled_units as i32,
+            CString::new(scaled_units)?.as_ptr()
+        ))?;
+        Ok(Self {
+            inner: CustomScale::new(name),
+        })
+    }
+
+    /// Open an existing map scale by name.
+    pub fn open<S: Into<Vec<u8>>>(name: S) -> Result<Self> {
+        CustomScale::open(name)?.into_map()
+    }
+
+    property!(get_set pre_scaled_min / set_pre_scaled_min: f64 =
+        DAQmxGetScaleMapPreScaledMin, DAQmxSetScaleMapPreScaledMin);
+    property!(get_set pre_scaled_max / set_pre_scaled_max: f64 =
+        DAQmxGetScaleMapPreScaledMax, DAQmxSetScaleMapPreScaledMax);
+    property!(get_set scaled_min / set_scaled_min: f64 =
+        DAQmxGetScaleMapScaledMin, DAQmxSetScaleMapScaledMin);
+    property!(get_set scaled_max / set_scaled_max: f64 =
+        DAQmxGetScaleMapScaledMax, DAQmxSetScaleMapScaledMax);
+
+    /// Read the scale parameters to evaluate the scale without the driver.
+    pub fn scaling(&self) -> Result<MapScaling> {
+        Ok(MapScaling {
+            pre_scaled_min: self.pre_scaled_min()?,
+            pre_scaled_max: self.pre_scaled_max()?,
+            scaled_min: self.scaled_min()?,
+            scaled_max: self.scaled_max()?,
+        })
+    }
 }
 
+/// A polynomial custom scale.
+///
+/// The coefficients are in order of increasing power, so `[c0, c1, c2]` is `c0 + c1*x + c2*x^2`.
+/// The forward coefficients scale the pre-scaled values and the reverse coefficients convert
+/// scaled values back, which DAQmx uses for output channels and to set the input range.
+pub struct PolynomialScale {
+    inner: CustomScale,
+}
+
+impl PolynomialScale {
+    pub fn new(
+        name: &str,
+        forward_coefficients: &[f64],
+        reverse_coefficients: &[f64],
+        pre_scaled_units: PreScaledUnits,
+        scaled_units: &str,
+    ) -> Result<Self> {
+        let name = CString::new(name)?;
+        daqmx_call!(DAQmxCreatePolynomialScale(
+            name.as_ptr(),
+            forward_coefficients.as_ptr(),
+            forward_coefficients.len() as u32,
+            reverse_coefficients.as_ptr(),
+            reverse_coefficients.len() as u32,
+            pre_scaled_units as i32,
+            CString::new(scaled_units)?.as_ptr()
+        ))?;
+        Ok(Self {
+            inner: CustomScale::new(name),
+        })
+    }
+
+    /// Create the scale from only the forward coefficients, calculating the reverse coefficients
+    /// over the pre-scaled range `min_x` to `max_x`.
+    ///
+    /// The reverse polynomial has the same order as the forward polynomial. Use
+    /// [`PolynomialScale::calculate_reverse_coefficients`] for more control over the fit.
+    pub fn from_forward_coefficients(
+        name: &str,
+        forward_coefficients: &[f64],
+        min_x: f64,
+        max_x: f64,
+        pre_scaled_units: PreScaledUnits,
+        scaled_units: &str,
+    ) -> Result<Self> {
+        let reverse_coefficients =
+            Self::calculate_reverse_coefficients(forward_coefficients, min_x, max_x, 1000, None)?;
+        Self::new(
+            name,
+            forward_coefficients,
+            &reverse_coefficients,
+            pre_scaled_units,
+            scaled_units,
+        )
+    }
+
+    /// Calculate the reverse coefficients for the forward coefficients by fitting `points`
+    /// evaluated between `min_x` and `max_x`.
+    ///
+    /// If `reverse_order` is [`None`], the reverse polynomial has the same order as the forward
+    /// polynomial.
+    pub fn calculate_reverse_coefficients(
+        forward_coefficients: &[f64],
+        min_x: f64,
+        max_x: f64,
+        points: u32,
+        reverse_order: Option<u32>,
+    ) -> Result<Vec<f64>> {
+        // DAQmx takes these as int32, so reject values it would see as negative.
+        let points_arg = i32::try_from(points)
+            .map_err(|_| DaqmxError::ValueOutOfRange("points", points.into()))?;
+        let order_arg = reverse_order
+            .map(|order| {
+                i32::try_from(order)
+                    .map_err(|_| DaqmxError::ValueOutOfRange("reverse order", order.into()))
+            })
+            .transpose()?;
+        let order = match reverse_order {
+            Some(order) => order as usize,
+            None => forward_coefficients.len().saturating_sub(1),
+        };
+        let mut reverse_coefficients = vec![0.0; order + 1];
+        daqmx_call!(DAQmxCalculateReversePolyCoeff(
+            forward_coefficients.as_ptr(),
+            forward_coefficients.len() as u32,
+            min_x,
+            max_x,
+            points_arg,
+            order_arg.unwrap_or(-1),
+            reverse_coefficients.as_mut_ptr()
+        ))?;
+        Ok(reverse_coefficients)
+    }
+
+    /// Open an existing polynomial scale by name.
+    pub fn open<S: Into<Vec<u8>>>(name: S) -> Result<Self> {
+        CustomScale::open(name)?.into_polynomial()
+    }
+
+    property!(get_array forward_coefficients = DAQmxGetScalePolyForwardCoeff);
+    property!(get_array reverse_coefficients = DAQmxGetScalePolyReverseCoeff);
+
+    /// Read the scale parameters to evaluate the scale without the driver.
+    pub fn scaling(&self) -> Result<PolynomialScaling> {
+        Ok(PolynomialScaling {
+            forward_coefficients: self.forward_coefficients()?,
+            reverse_coefficients: self.reverse_coefficients()?,
+        })
+    }
+}
+
+/// A table custom scale.
+///
+/// This interpolates linearly between the points of a calibration table. The pre-scaled and
+/// scaled values must be the same length.
+pub struct TableScale {
+    inner: CustomScale,
+}
+
+impl TableScale {
+    pub fn new(
+        name: &str,
+        pre_scaled_values: &[f64],
+        scaled_values: &[f64],
+        pre_scaled_units: PreScaledUnits,
+        scaled_units: &str,
+    ) -> Result<Self> {
+        let name = CString::new(name)?;
+        daqmx_call!(DAQmxCreateTableScale(
+            name.as_ptr(),
+            pre_scaled_values.as_ptr(),
+            pre_scaled_values.len() as u32,
+            scaled_values.as_ptr(),
+            scaled_values.len() as u32,
+            pre_scaled_units as i32,
+            CString::new(scaled_units)?.as_ptr()
+        ))?;
+        Ok(Self {
+            inner: CustomScale::new(name),
+        })
+    }
+
+    /// Open an existing table scale by name.
+    pub fn open<S: Into<Vec<u8>>>(name: S) -> Result<Self> {
+        CustomScale::open(name)?.into_table()
+    }
+
+    property!(get_array pre_scaled_values = DAQmxGetScaleTablePreScaledVals);
+    property!(get_array scaled_values = DAQmxGetScaleTableScaledVals);
+
+    /// Read the scale parameters to evaluate the scale without the driver.
+    pub fn scaling(&self) -> Result<TableScaling> {
+        Ok(TableScaling {
+            pre_scaled_values: self.pre_scaled_values()?,
+            scaled_values: self.scaled_values()?,
+        })
+    }
+}
+
+macro_rules! deref_custom_scale {
+    ($($scale:ty),*) => {$(
+        impl Deref for $scale {
+            type Target = CustomScale;
+
+            fn deref(&self) -> &CustomScale {
+                &self.inner
+            }
+        }
+    )*};
+}
+
+deref_custom_scale!(LinearScale, MapScale, PolynomialScale, TableScale);
+
 /// Represents the different scaled units provided by DAQmx Channel types.
 #[repr(i32)]
 #[derive(PartialEq, Debug, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PreScaledUnits {
     Volts = DAQmx_Val_Volts,
     Amps = DAQmx_Val_Amps,
@@ -125,6 +523,18 @@ impl TryFrom<i32> for PreScaledUnits {
     }
 }
 
+impl PropertyValue for PreScaledUnits {
+    type Raw = i32;
+
+    fn from_raw(raw: Self::Raw) -> Result<Self> {
+        Self::try_from(raw)
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        self as i32
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,244 @@
+//! Evaluates custom scales in Rust without the driver.
+//!
+//! Each scale type has a matching scaling type holding its parameters, which can be read from an
+//! existing scale with its `scaling` method or constructed directly. This allows raw recorded
+//! values to be scaled on machines without NI-DAQmx installed, using the same maths as DAQmx.
+
+/// Converts between pre-scaled and scaled values.
+pub trait Scaling {
+    /// Convert a pre-scaled value to the scaled value.
+    fn apply(&self, value: f64) -> f64;
+
+    /// Convert a scaled value back to the pre-scaled value.
+    fn reverse(&self, value: f64) -> f64;
+
+    /// Convert all pre-scaled values in the buffer to scaled values.
+    fn apply_in_place(&self, values: &mut [f64]) {
+        for value in values {
+            *value = self.apply(*value);
+        }
+    }
+
+    /// Convert all scaled values in the buffer back to pre-scaled values.
+    fn reverse_in_place(&self, values: &mut [f64]) {
+        for value in values {
+            *value = self.reverse(*value);
+        }
+    }
+}
+
+/// The parameters of a [`LinearScale`](super::LinearScale): `y = slope * x + y_intercept`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearScaling {
+    pub slope: f64,
+    pub y_intercept: f64,
+}
+
+impl Scaling for LinearScaling {
+    fn apply(&self, value: f64) -> f64 {
+        self.slope * value + self.y_intercept
+    }
+
+    fn reverse(&self, value: f64) -> f64 {
+        (value - self.y_intercept) / self.slope
+    }
+}
+
+/// The parameters of a [`MapScale`](super::MapScale).
+///
+/// Values outside the pre-scaled range are mapped with the same slope, not coerced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MapScaling {
+    pub pre_scaled_min: f64,
+    pub pre_scaled_max: f64,
+    pub scaled_min: f64,
+    pub scaled_max: f64,
+}
+
+impl MapScaling {
+    fn slope(&self) -> f64 {
+        (self.scaled_max - self.scaled_min) / (self.pre_scaled_max - self.pre_scaled_min)
+    }
+}
+
+impl Scaling for MapScaling {
+    fn apply(&self, value: f64) -> f64 {
+        (value - self.pre_scaled_min) * self.slope() + self.scaled_min
+    }
+
+    fn reverse(&self, value: f64) -> f64 {
+        (value - self.scaled_min) / self.slope() + self.pre_scaled_min
+    }
+}
+
+/// The parameters of a [`PolynomialScale`](super::PolynomialScale).
+///
+/// As in DAQmx, the forward coefficients are used to apply the scale and the reverse
+/// coefficients to reverse it, so reversing is only as accurate as the reverse fit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolynomialScaling {
+    pub forward_coefficients: Vec<f64>,
+    pub reverse_coefficients: Vec<f64>,
+}
+
+impl Scaling for PolynomialScaling {
+    fn apply(&self, value: f64) -> f64 {
+        evaluate_polynomial(&self.forward_coefficients, value)
+    }
+
+    fn reverse(&self, value: f64) -> f64 {
+        evaluate_polynomial(&self.reverse_coefficients, value)
+    }
+}
+
+/// Evaluate a polynomial with coefficients in order of increasing power.
+fn evaluate_polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |result, coefficient| result * x + coefficient)
+}
+
+/// The parameters of a [`TableScale`](super::TableScale).
+///
+/// Values are interpolated linearly between the table points, and extrapolated from the first or
+/// last pair of points outside the table. The values should be monotonic for the scale to be
+/// reversible.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableScaling {
+    pub pre_scaled_values: Vec<f64>,
+    pub scaled_values: Vec<f64>,
+}
+
+impl Scaling for TableScaling {
+    fn apply(&self, value: f64) -> f64 {
+        interpolate(&self.pre_scaled_values, &self.scaled_values, value)
+    }
+
+    fn reverse(&self, value: f64) -> f64 {
+        interpolate(&self.scaled_values, &self.pre_scaled_values, value)
+    }
+}
+
+/// Linearly interpolate `x` in the table from `xs` to `ys`, extrapolating from the nearest end.
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let len = xs.len().min(ys.len());
+    if len < 2 {
+        return ys.first().copied().unwrap_or(f64::NAN);
+    }
+    let segment = (0..len - 1)
+        .find(|&i| x >= xs[i].min(xs[i + 1]) && x <= xs[i].max(xs[i + 1]))
+        .unwrap_or(if (x - xs[0]).abs() < (x - xs[len - 1]).abs() {
+            0
+        } else {
+            len - 2
+        });
+    let (x0, x1) = (xs[segment], xs[segment + 1]);
+    let (y0, y1) = (ys[segment], ys[segment + 1]);
+    if x1 == x0 {
+        return y0;
+    }
+    y0 + (x - x0) * (y1 - y0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_linear_scaling() {
+        let scaling = LinearScaling {
+            slope: 2.5,
+            y_intercept: -1.0,
+        };
+        assert_close(scaling.apply(2.0), 4.0);
+        assert_close(scaling.reverse(4.0), 2.0);
+    }
+
+    #[test]
+    fn test_map_scaling() {
+        let scaling = MapScaling {
+            pre_scaled_min: 1.0,
+            pre_scaled_max: 5.0,
+            scaled_min: 0.0,
+            scaled_max: 100.0,
+        };
+        assert_close(scaling.apply(1.0), 0.0);
+        assert_close(scaling.apply(3.0), 50.0);
+        assert_close(scaling.apply(6.0), 125.0);
+        assert_close(scaling.reverse(50.0), 3.0);
+    }
+
+    #[test]
+    fn test_polynomial_scaling() {
+        let scaling = PolynomialScaling {
+            forward_coefficients: vec![1.0, 2.0, 3.0],
+            reverse_coefficients: vec![-0.5, 0.5],
+        };
+        assert_close(scaling.apply(2.0), 17.0);
+        assert_close(scaling.reverse(3.0), 1.0);
+    }
+
+    #[test]
+    fn test_empty_polynomial_is_zero() {
+        let scaling = PolynomialScaling {
+            forward_coefficients: vec![],
+            reverse_coefficients: vec![],
+        };
+        assert_eq!(scaling.apply(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_table_scaling_interpolates() {
+        let scaling = TableScaling {
+            pre_scaled_values: vec![0.0, 1.0, 5.0],
+            scaled_values: vec![0.0, 0.5, 10.0],
+        };
+        assert_close(scaling.apply(0.5), 0.25);
+        assert_close(scaling.apply(1.0), 0.5);
+        assert_close(scaling.apply(3.0), 5.25);
+        assert_close(scaling.reverse(5.25), 3.0);
+    }
+
+    #[test]
+    fn test_table_scaling_extrapolates() {
+        let scaling = TableScaling {
+            pre_scaled_values: vec![0.0, 1.0, 5.0],
+            scaled_values: vec![0.0, 0.5, 10.0],
+        };
+        assert_close(scaling.apply(-1.0), -0.5);
+        assert_close(scaling.apply(7.0), 14.75);
+        assert_close(scaling.reverse(14.75), 7.0);
+    }
+
+    #[test]
+    fn test_table_scaling_decreasing_values() {
+        let scaling = TableScaling {
+            pre_scaled_values: vec![0.0, 1.0, 2.0],
+            scaled_values: vec![10.0, 6.0, 0.0],
+        };
+        assert_close(scaling.apply(1.5), 3.0);
+        assert_close(scaling.reverse(3.0), 1.5);
+        assert_close(scaling.reverse(12.0), -0.5);
+    }
+
+    #[test]
+    fn test_apply_in_place() {
+        let scaling = LinearScaling {
+            slope: 2.0,
+            y_intercept: 1.0,
+        };
+        let mut values = [0.0, 1.0, 2.0];
+        scaling.apply_in_place(&mut values);
+        assert_eq!(values, [1.0, 3.0, 5.0]);
+        scaling.reverse_in_place(&mut values);
+        assert_eq!(values, [0.0, 1.0, 2.0]);
+    }
+}
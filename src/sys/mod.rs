@@ -0,0 +1,22 @@
+//! The DAQmx C API used by the crate.
+//!
+//! This is the `ni-daqmx-sys` crate with the `driver` feature, or an in-memory simulation of it with
+//! the `simulation` feature so tasks can be exercised without NI-DAQmx installed. Exactly one must be
+//! enabled. Features are unified across the dependency graph, so rather than letting one silently
+//! win, enabling both is an error.
+
+#[cfg(not(any(feature = "driver", feature = "simulation")))]
+compile_error!("either the `driver` or the `simulation` feature must be enabled");
+
+#[cfg(all(feature = "driver", feature = "simulation"))]
+compile_error!(
+    "the `driver` and `simulation` features can't both be enabled, build the simulation with `--no-default-features`"
+);
+
+#[cfg(all(feature = "driver", not(feature = "simulation")))]
+pub(crate) use ni_daqmx_sys::*;
+
+#[cfg(feature = "simulation")]
+mod simulated;
+#[cfg(feature = "simulation")]
+pub(crate) use simulated::*;
@@ -0,0 +1,240 @@
+//! The types and constants of the NI-DAQmx C API used by the crate.
+//!
+//! These match the declarations in `ni-daqmx-sys` with the widths of the C types, so the
+//! simulation builds without the sys crate on any platform.
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use std::ffi::c_void;
+
+pub type uInt8 = u8;
+pub type uInt16 = u16;
+pub type int32 = i32;
+pub type uInt32 = u32;
+pub type float64 = f64;
+pub type uInt64 = u64;
+pub type bool32 = uInt32;
+pub type TaskHandle = *mut c_void;
+
+pub type DAQmxEveryNSamplesEventCallbackPtr = Option<
+    unsafe extern "C" fn(
+        taskHandle: TaskHandle,
+        everyNsamplesEventType: int32,
+        nSamples: uInt32,
+        callbackData: *mut c_void,
+    ) -> int32,
+>;
+pub type DAQmxDoneEventCallbackPtr = Option<
+    unsafe extern "C" fn(taskHandle: TaskHandle, status: int32, callbackData: *mut c_void) -> int32,
+>;
+pub type DAQmxSignalEventCallbackPtr = Option<
+    unsafe extern "C" fn(
+        taskHandle: TaskHandle,
+        signalID: int32,
+        callbackData: *mut c_void,
+    ) -> int32,
+>;
+
+pub const DAQmxErrorAttrNotSupported: i32 = -200197;
+pub const DAQmxErrorBufferTooSmallForString: i32 = -200228;
+pub const DAQmxErrorCanNotPerformOpWhileTaskRunning: i32 = -200479;
+pub const DAQmxErrorCannotReadWhenAutoStartFalseAndTaskNotRunningOrCommitted: i32 = -200473;
+pub const DAQmxErrorCannotRegisterDAQmxSoftwareEventWhileTaskIsRunning: i32 = -200960;
+pub const DAQmxErrorCantSaveChanWithoutReplace: i32 = -200483;
+pub const DAQmxErrorCantSaveTaskWithoutReplace: i32 = -200484;
+pub const DAQmxErrorChanAlreadyInTask: i32 = -200489;
+pub const DAQmxErrorChanNotInTask: i32 = -200486;
+pub const DAQmxErrorCustomScaleDoesNotExist: i32 = -200378;
+pub const DAQmxErrorDoneEventAlreadyRegistered: i32 = -200950;
+pub const DAQmxErrorDuplicateTask: i32 = -200089;
+pub const DAQmxErrorEveryNSampsEventAlreadyRegistered: i32 = -200881;
+pub const DAQmxErrorForwardPolynomialCoefNotSpecd: i32 = -200351;
+pub const DAQmxErrorInvalidDeviceID: i32 = -200220;
+pub const DAQmxErrorInvalidTask: i32 = -200088;
+pub const DAQmxErrorMismatchedInputArraySizes: i32 = -200672;
+pub const DAQmxErrorMultiChanTypesInTask: i32 = -200559;
+pub const DAQmxErrorPhysicalChanDoesNotExist: i32 = -200170;
+pub const DAQmxErrorPreScaledMinEqualMax: i32 = -200602;
+pub const DAQmxErrorReadBufferTooSmall: i32 = -200229;
+pub const DAQmxErrorReversePolyOrderLessThanNumPtsToCompute: i32 = -200403;
+pub const DAQmxErrorReversePolyOrderNotPositive: i32 = -200402;
+pub const DAQmxErrorReversePolynomialCoefNotSpecd: i32 = -200325;
+pub const DAQmxErrorSamplesNotYetAvailable: i32 = -200284;
+pub const DAQmxErrorSamplesWillNeverBeAvailable: i32 = -200278;
+pub const DAQmxErrorScaledMinEqualMax: i32 = -200603;
+pub const DAQmxErrorSignalEventAlreadyRegistered: i32 = -200949;
+pub const DAQmxErrorTEDSSensorNotDetected: i32 = -200709;
+pub const DAQmxErrorTableScaleNumPreScaledAndScaledValsNotEqual: i32 = -200350;
+pub const DAQmxErrorTableScalePreScaledValsNotSpecd: i32 = -200349;
+pub const DAQmxErrorTaskNotInDataNeighborhood: i32 = -200485;
+pub const DAQmxErrorVirtualChanDoesNotExist: i32 = -200488;
+pub const DAQmxErrorWaitUntilDoneDoesNotIndicateDone: i32 = -200560;
+pub const DAQmxWarningCAPIStringTruncatedToFitBuffer: i32 = 200026;
+pub const DAQmx_Val_2Wire: i32 = 2;
+pub const DAQmx_Val_3Wire: i32 = 3;
+pub const DAQmx_Val_4Wire: i32 = 4;
+pub const DAQmx_Val_AC: i32 = 10045;
+pub const DAQmx_Val_AI: i32 = 10100;
+pub const DAQmx_Val_AO: i32 = 10102;
+pub const DAQmx_Val_A_Type_TC: i32 = 16208;
+pub const DAQmx_Val_AboveLvl: i32 = 10093;
+pub const DAQmx_Val_AccelUnit_g: i32 = 10186;
+pub const DAQmx_Val_Acceleration_4WireDCVoltage: i32 = 16106;
+pub const DAQmx_Val_Acceleration_Charge: i32 = 16104;
+pub const DAQmx_Val_Accelerometer: i32 = 10356;
+pub const DAQmx_Val_Acquired_Into_Buffer: i32 = 1;
+pub const DAQmx_Val_Amps: i32 = 10342;
+pub const DAQmx_Val_AnlgEdge: i32 = 10099;
+pub const DAQmx_Val_AnlgLvl: i32 = 10101;
+pub const DAQmx_Val_AnlgWin: i32 = 10103;
+pub const DAQmx_Val_B_Type_TC: i32 = 10047;
+pub const DAQmx_Val_Bar: i32 = 15880;
+pub const DAQmx_Val_BelowLvl: i32 = 10107;
+pub const DAQmx_Val_Bit_TermCfg_Diff: i32 = 4;
+pub const DAQmx_Val_Bit_TermCfg_NRSE: i32 = 2;
+pub const DAQmx_Val_Bit_TermCfg_PseudoDIFF: i32 = 8;
+pub const DAQmx_Val_Bit_TermCfg_RSE: i32 = 1;
+pub const DAQmx_Val_Bridge: i32 = 15908;
+pub const DAQmx_Val_BuiltIn: i32 = 10200;
+pub const DAQmx_Val_CI: i32 = 10131;
+pub const DAQmx_Val_CO: i32 = 10132;
+pub const DAQmx_Val_C_Type_TC: i32 = 16209;
+pub const DAQmx_Val_Cfg_Default: i32 = -1;
+pub const DAQmx_Val_Chan: i32 = 10113;
+pub const DAQmx_Val_ChanForAllLines: i32 = 1;
+pub const DAQmx_Val_ChanPerLine: i32 = 0;
+pub const DAQmx_Val_ChangeDetectionEvent: i32 = 12511;
+pub const DAQmx_Val_Charge: i32 = 16105;
+pub const DAQmx_Val_ConstVal: i32 = 10116;
+pub const DAQmx_Val_ContSamps: i32 = 10123;
+pub const DAQmx_Val_CountDown: i32 = 10124;
+pub const DAQmx_Val_CountEdges: i32 = 10125;
+pub const DAQmx_Val_CountUp: i32 = 10128;
+pub const DAQmx_Val_CounterOutputEvent: i32 = 12494;
+pub const DAQmx_Val_Current: i32 = 10134;
+pub const DAQmx_Val_CurrentRMS: i32 = 10351;
+pub const DAQmx_Val_Custom: i32 = 10137;
+pub const DAQmx_Val_DC: i32 = 10050;
+pub const DAQmx_Val_DI: i32 = 10151;
+pub const DAQmx_Val_DO: i32 = 10153;
+pub const DAQmx_Val_Default: i32 = -1;
+pub const DAQmx_Val_DegC: i32 = 10143;
+pub const DAQmx_Val_DegF: i32 = 10144;
+pub const DAQmx_Val_DegR: i32 = 10145;
+pub const DAQmx_Val_Degrees: i32 = 10146;
+pub const DAQmx_Val_Diff: i32 = 10106;
+pub const DAQmx_Val_DigEdge: i32 = 10150;
+pub const DAQmx_Val_DigLvl: i32 = 10152;
+pub const DAQmx_Val_DynAvg: i32 = 16065;
+pub const DAQmx_Val_E_Type_TC: i32 = 10055;
+pub const DAQmx_Val_EnteringWin: i32 = 10163;
+pub const DAQmx_Val_ExtControlled: i32 = 10326;
+pub const DAQmx_Val_External: i32 = 10167;
+pub const DAQmx_Val_Falling: i32 = 10171;
+pub const DAQmx_Val_FallingSlope: i32 = 10171;
+pub const DAQmx_Val_FiniteSamps: i32 = 10178;
+pub const DAQmx_Val_FootPounds: i32 = 15884;
+pub const DAQmx_Val_Force_Bridge: i32 = 15899;
+pub const DAQmx_Val_Force_IEPESensor: i32 = 15895;
+pub const DAQmx_Val_Freq_Voltage: i32 = 10181;
+pub const DAQmx_Val_FromCustomScale: i32 = 10065;
+pub const DAQmx_Val_FromTEDS: i32 = 12516;
+pub const DAQmx_Val_FullBridge: i32 = 10182;
+pub const DAQmx_Val_FullBridgeI: i32 = 10183;
+pub const DAQmx_Val_FullBridgeII: i32 = 10184;
+pub const DAQmx_Val_FullBridgeIII: i32 = 10185;
+pub const DAQmx_Val_GND: i32 = 10066;
+pub const DAQmx_Val_GroupByChannel: i32 = 0;
+pub const DAQmx_Val_GroupByScanNumber: i32 = 1;
+pub const DAQmx_Val_HWTimedSinglePoint: i32 = 12522;
+pub const DAQmx_Val_HalfBridge: i32 = 10187;
+pub const DAQmx_Val_HalfBridgeI: i32 = 10188;
+pub const DAQmx_Val_HalfBridgeII: i32 = 10189;
+pub const DAQmx_Val_High: i32 = 10192;
+pub const DAQmx_Val_HighFreq2Ctr: i32 = 10157;
+pub const DAQmx_Val_Hz: i32 = 10373;
+pub const DAQmx_Val_Implicit: i32 = 10451;
+pub const DAQmx_Val_InchOunces: i32 = 15882;
+pub const DAQmx_Val_InchPounds: i32 = 15883;
+pub const DAQmx_Val_Inches: i32 = 10379;
+pub const DAQmx_Val_InchesPerSecondSquared: i32 = 12471;
+pub const DAQmx_Val_Internal: i32 = 10200;
+pub const DAQmx_Val_J_Type_TC: i32 = 10072;
+pub const DAQmx_Val_K_Type_TC: i32 = 10073;
+pub const DAQmx_Val_Kelvins: i32 = 10325;
+pub const DAQmx_Val_LargeRng2Ctr: i32 = 10205;
+pub const DAQmx_Val_LeavingWin: i32 = 10208;
+pub const DAQmx_Val_Linear: i32 = 10447;
+pub const DAQmx_Val_Low: i32 = 10214;
+pub const DAQmx_Val_LowFreq1Ctr: i32 = 10105;
+pub const DAQmx_Val_MapRanges: i32 = 10448;
+pub const DAQmx_Val_Meters: i32 = 10219;
+pub const DAQmx_Val_MetersPerSecondSquared: i32 = 12470;
+pub const DAQmx_Val_NRSE: i32 = 10078;
+pub const DAQmx_Val_N_Type_TC: i32 = 10077;
+pub const DAQmx_Val_NewtonMeters: i32 = 15881;
+pub const DAQmx_Val_Newtons: i32 = 15875;
+pub const DAQmx_Val_NoBridge: i32 = 10228;
+pub const DAQmx_Val_None: i32 = 10230;
+pub const DAQmx_Val_Ohms: i32 = 10384;
+pub const DAQmx_Val_OnDemand: i32 = 10390;
+pub const DAQmx_Val_Pascals: i32 = 10081;
+pub const DAQmx_Val_Polynomial: i32 = 10449;
+pub const DAQmx_Val_Position_EddyCurrentProximityProbe: i32 = 14835;
+pub const DAQmx_Val_Position_LVDT: i32 = 10352;
+pub const DAQmx_Val_Position_RVDT: i32 = 10353;
+pub const DAQmx_Val_Pounds: i32 = 15876;
+pub const DAQmx_Val_PoundsPerSquareInch: i32 = 15879;
+pub const DAQmx_Val_Power: i32 = 16201;
+pub const DAQmx_Val_Pressure_Bridge: i32 = 15902;
+pub const DAQmx_Val_PseudoDiff: i32 = 12529;
+pub const DAQmx_Val_Pt3750: i32 = 12481;
+pub const DAQmx_Val_Pt3851: i32 = 10071;
+pub const DAQmx_Val_Pt3911: i32 = 12482;
+pub const DAQmx_Val_Pt3916: i32 = 10069;
+pub const DAQmx_Val_Pt3920: i32 = 10053;
+pub const DAQmx_Val_Pt3928: i32 = 12483;
+pub const DAQmx_Val_QuarterBridge: i32 = 10270;
+pub const DAQmx_Val_QuarterBridgeI: i32 = 10271;
+pub const DAQmx_Val_QuarterBridgeII: i32 = 10272;
+pub const DAQmx_Val_RSE: i32 = 10083;
+pub const DAQmx_Val_R_Type_TC: i32 = 10082;
+pub const DAQmx_Val_Radians: i32 = 10273;
+pub const DAQmx_Val_Resistance: i32 = 10278;
+pub const DAQmx_Val_Rising: i32 = 10280;
+pub const DAQmx_Val_RisingSlope: i32 = 10280;
+pub const DAQmx_Val_Rosette_Strain_Gage: i32 = 15980;
+pub const DAQmx_Val_S_Type_TC: i32 = 10085;
+pub const DAQmx_Val_SampClk: i32 = 10388;
+pub const DAQmx_Val_SampleClock: i32 = 12487;
+pub const DAQmx_Val_SampleCompleteEvent: i32 = 12530;
+pub const DAQmx_Val_Save_AllowInteractiveDeletion: i32 = 4;
+pub const DAQmx_Val_Save_AllowInteractiveEditing: i32 = 2;
+pub const DAQmx_Val_Save_Overwrite: i32 = 1;
+pub const DAQmx_Val_Seconds: i32 = 10364;
+pub const DAQmx_Val_SoundPressure_Microphone: i32 = 10354;
+pub const DAQmx_Val_Strain: i32 = 10299;
+pub const DAQmx_Val_Strain_Gage: i32 = 10300;
+pub const DAQmx_Val_TEDS_Sensor: i32 = 12531;
+pub const DAQmx_Val_T_Type_TC: i32 = 10086;
+pub const DAQmx_Val_Table: i32 = 10450;
+pub const DAQmx_Val_Temp_BuiltInSensor: i32 = 10311;
+pub const DAQmx_Val_Temp_RTD: i32 = 10301;
+pub const DAQmx_Val_Temp_TC: i32 = 10303;
+pub const DAQmx_Val_Temp_Thrmstr: i32 = 10302;
+pub const DAQmx_Val_Ticks: i32 = 10304;
+pub const DAQmx_Val_Torque_Bridge: i32 = 15905;
+pub const DAQmx_Val_Transferred_From_Buffer: i32 = 2;
+pub const DAQmx_Val_TwoPointLinear: i32 = 15898;
+pub const DAQmx_Val_Velocity_IEPESensor: i32 = 15966;
+pub const DAQmx_Val_Voltage: i32 = 10322;
+pub const DAQmx_Val_VoltageRMS: i32 = 10350;
+pub const DAQmx_Val_Voltage_CustomWithExcitation: i32 = 10323;
+pub const DAQmx_Val_Volts: i32 = 10348;
+pub const DAQmx_Val_VoltsPerG: i32 = 12510;
+pub const DAQmx_Val_VoltsPerVolt: i32 = 15896;
+pub const DAQmx_Val_WaitInfinitely: f64 = -1.0;
+pub const DAQmx_Val_g: i32 = 10186;
+pub const DAQmx_Val_mVoltsPerG: i32 = 12509;
+pub const DAQmx_Val_mVoltsPerNewton: i32 = 15891;
+pub const DAQmx_Val_mVoltsPerPound: i32 = 15892;
+pub const DAQmx_Val_mVoltsPerVolt: i32 = 15897;
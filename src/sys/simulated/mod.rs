@@ -0,0 +1,3420 @@
+//! An in-memory simulation of the parts of the NI-DAQmx C API used by this crate.
+//!
+//! The functions shadow those of the sys crate with the same signatures, so the rest of the crate
+//! is unchanged. Tasks acquire at their configured rate in real time and reads return synthetic
+//! waveforms, so timeouts, partial reads and events behave as they do with simulated hardware.
+#![allow(non_snake_case, clippy::too_many_arguments, clippy::missing_safety_doc)]
+
+mod bindings;
+mod model;
+
+use std::ffi::{c_char, c_void};
+
+pub use bindings::*;
+use model::{
+    Channel, ChannelType, DEVICES, Device, Number, Properties, Scale, SimResult, Task, Timing,
+    array_arg, callbacks, channel_names, device, device_name, expand_lines,
+    expand_physical_channels, fail, last_error, physical_channel, read, simulation, sine, status,
+    string_arg, wait_until_done, write, write_string,
+};
+
+/// Run `action` on the task, returning the status.
+fn with_task(handle: TaskHandle, action: impl FnOnce(&mut Task) -> SimResult<()>) -> int32 {
+    let mut simulation = simulation();
+    status(simulation.task(handle).and_then(action))
+}
+
+/// Run `action` on the named channel of the task, returning the status.
+///
+/// # Safety
+///
+/// `channel` must be null or a valid C string.
+unsafe fn with_channel(
+    handle: TaskHandle,
+    channel: *const c_char,
+    action: impl FnOnce(&mut Channel) -> SimResult<()>,
+) -> int32 {
+    let name = unsafe { string_arg(channel) };
+    with_task(handle, |task| action(task.channel(&name)?))
+}
+
+/// Getters for numeric channel properties.
+macro_rules! channel_getters {
+    ($($name:ident: $t:ty = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            channel: *const c_char,
+            data: *mut $t,
+        ) -> int32 {
+            unsafe {
+                with_channel(taskHandle, channel, |channel| {
+                    *data = <$t as Number>::from_f64(channel.properties.number($key));
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Setters for numeric channel properties.
+macro_rules! channel_setters {
+    ($($name:ident: $t:ty = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            channel: *const c_char,
+            data: $t,
+        ) -> int32 {
+            unsafe {
+                with_channel(taskHandle, channel, |channel| {
+                    channel.properties.set_number($key, data);
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Getters for string channel properties.
+macro_rules! channel_string_getters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            channel: *const c_char,
+            data: *mut c_char,
+            bufferSize: uInt32,
+        ) -> int32 {
+            let name = unsafe { string_arg(channel) };
+            let mut simulation = simulation();
+            match simulation.task(taskHandle).and_then(|task| task.channel(&name)) {
+                Ok(channel) => unsafe {
+                    write_string(channel.properties.text($key), data, bufferSize)
+                },
+                Err(code) => code,
+            }
+        }
+    )*};
+}
+
+/// Setters for the custom scale of a channel, which must exist.
+macro_rules! channel_scale_setters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            channel: *const c_char,
+            data: *const c_char,
+        ) -> int32 {
+            let name = unsafe { string_arg(channel) };
+            let scale = unsafe { string_arg(data) };
+            let mut simulation = simulation();
+            let result = check_scale(&simulation.scales, &scale).and_then(|()| {
+                let channel = simulation.task(taskHandle)?.channel(&name)?;
+                channel.properties.set_text($key, scale);
+                Ok(())
+            });
+            status(result)
+        }
+    )*};
+}
+
+/// Resetters for channel properties.
+macro_rules! channel_resetters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(taskHandle: TaskHandle, channel: *const c_char) -> int32 {
+            unsafe {
+                with_channel(taskHandle, channel, |channel| {
+                    channel.properties.reset($key);
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Setters for numeric task properties.
+macro_rules! task_setters {
+    ($($name:ident: $t:ty = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(taskHandle: TaskHandle, data: $t) -> int32 {
+            with_task(taskHandle, |task| {
+                task.properties.set_number($key, data);
+                Ok(())
+            })
+        }
+    )*};
+}
+
+/// Setters for string task properties.
+macro_rules! task_string_setters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(taskHandle: TaskHandle, data: *const c_char) -> int32 {
+            let value = unsafe { string_arg(data) };
+            with_task(taskHandle, |task| {
+                task.properties.set_text($key, value);
+                Ok(())
+            })
+        }
+    )*};
+}
+
+fn check_scale(scales: &std::collections::BTreeMap<String, Scale>, name: &str) -> SimResult<()> {
+    if name.is_empty() || scales.contains_key(name) {
+        Ok(())
+    } else {
+        fail(
+            DAQmxErrorCustomScaleDoesNotExist,
+            format!("Custom scale specified does not exist.\n\nScale Name: {name}"),
+        )
+    }
+}
+
+// Errors.
+
+pub unsafe extern "C" fn DAQmxGetErrorString(
+    errorCode: int32,
+    errorString: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    let message = format!("Simulated DAQmx status code {errorCode}.");
+    unsafe { write_truncated(&message, errorString, bufferSize) }
+}
+
+pub unsafe extern "C" fn DAQmxGetExtendedErrorInfo(
+    errorString: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    unsafe { write_truncated(&last_error(), errorString, bufferSize) }
+}
+
+/// Write as much of the message as fits, as the DAQmx error functions do.
+///
+/// # Safety
+///
+/// `buffer` must be valid for `size` bytes.
+unsafe fn write_truncated(message: &str, buffer: *mut c_char, size: uInt32) -> int32 {
+    if size == 0 {
+        return unsafe { write_string(message, buffer, size) };
+    }
+    let mut end = message.len().min(size as usize - 1);
+    while !message.is_char_boundary(end) {
+        end -= 1;
+    }
+    unsafe { write_string(&message[..end], buffer, size) }
+}
+
+// System and devices.
+
+pub unsafe extern "C" fn DAQmxGetSysDevNames(data: *mut c_char, bufferSize: uInt32) -> int32 {
+    let names: Vec<&str> = DEVICES.iter().map(|device| device.name).collect();
+    unsafe { write_string(&names.join(", "), data, bufferSize) }
+}
+
+/// Run `action` on the named device, returning the status.
+///
+/// # Safety
+///
+/// `device` must be null or a valid C string.
+unsafe fn with_device(
+    device_name: *const c_char,
+    action: impl FnOnce(&Device) -> SimResult<()>,
+) -> int32 {
+    let name = unsafe { string_arg(device_name) };
+    status(device(&name).and_then(action))
+}
+
+/// Getters for the properties of devices.
+macro_rules! device_getters {
+    ($($name:ident: $t:ty = |$device:ident| $value:expr;)*) => {$(
+        pub unsafe extern "C" fn $name(device: *const c_char, data: *mut $t) -> int32 {
+            unsafe {
+                with_device(device, |$device| {
+                    *data = $value;
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Getters for the string properties of devices.
+macro_rules! device_string_getters {
+    ($($name:ident = |$device:ident| $value:expr;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            device: *const c_char,
+            data: *mut c_char,
+            bufferSize: uInt32,
+        ) -> int32 {
+            let name = unsafe { string_arg(device) };
+            match model::device(&name) {
+                Ok($device) => unsafe { write_string(&$value, data, bufferSize) },
+                Err(code) => code,
+            }
+        }
+    )*};
+}
+
+device_getters! {
+    DAQmxGetDevSerialNum: uInt32 = |_device| 0;
+    DAQmxGetDevIsSimulated: bool32 = |_device| 1;
+    DAQmxGetDevPXIChassisNum: uInt32 = |device| device.pxi_chassis;
+    DAQmxGetDevPXISlotNum: uInt32 = |device| device.pxi_slot;
+    DAQmxGetDevAIMaxSingleChanRate: float64 = |_device| 2.0e6;
+    DAQmxGetDevAIMaxMultiChanRate: float64 = |_device| 1.0e6;
+    DAQmxGetDevAOMaxRate: float64 = |_device| 2.86e6;
+    DAQmxGetDevDIMaxRate: float64 = |_device| 10.0e6;
+    DAQmxGetDevDOMaxRate: float64 = |_device| 10.0e6;
+}
+
+device_string_getters! {
+    DAQmxGetDevProductType = |device| device.product_type;
+    DAQmxGetDevAIPhysicalChans = |device| device.ai_physical_channels();
+    DAQmxGetDevAOPhysicalChans = |device| device.ao_physical_channels();
+    DAQmxGetDevDILines = |device| device.lines();
+    DAQmxGetDevDIPorts = |device| device.ports();
+    DAQmxGetDevDOLines = |device| device.lines();
+    DAQmxGetDevDOPorts = |device| device.ports();
+    DAQmxGetDevCIPhysicalChans = |device| device.counters();
+    DAQmxGetDevCOPhysicalChans = |device| device.counters();
+}
+
+/// The simulated devices are all PXI cards so the CompactDAQ properties aren't supported.
+unsafe fn compact_daq_not_supported(device_name: *const c_char) -> int32 {
+    unsafe {
+        with_device(device_name, |device| {
+            fail(
+                DAQmxErrorAttrNotSupported,
+                format!(
+                    "Specified property is not supported by the device or is not applicable to the task.\n\nDevice: {}",
+                    device.name
+                ),
+            )
+        })
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetDevCompactDAQChassisDevName(
+    device: *const c_char,
+    _data: *mut c_char,
+    _bufferSize: uInt32,
+) -> int32 {
+    unsafe { compact_daq_not_supported(device) }
+}
+
+pub unsafe extern "C" fn DAQmxGetDevCompactDAQSlotNum(
+    device: *const c_char,
+    _data: *mut uInt32,
+) -> int32 {
+    unsafe { compact_daq_not_supported(device) }
+}
+
+/// The analog input measurements the simulation can create channels for.
+const AI_MEASUREMENT_TYPES: [int32; 4] = [
+    DAQmx_Val_Voltage,
+    DAQmx_Val_Current,
+    DAQmx_Val_Temp_RTD,
+    DAQmx_Val_Temp_TC,
+];
+
+/// Copy an array out following the DAQmx convention, where a zero size returns the number of elements required.
+///
+/// # Safety
+///
+/// `data` must be valid for `size` elements.
+unsafe fn write_array<T: Copy>(values: &[T], data: *mut T, size: uInt32) -> int32 {
+    if size == 0 || data.is_null() {
+        return values.len() as int32;
+    }
+    if (size as usize) < values.len() {
+        return status(fail(
+            DAQmxErrorBufferTooSmallForString,
+            "Buffer is too small to fit the array.",
+        ));
+    }
+    unsafe { std::ptr::copy_nonoverlapping(values.as_ptr(), data, values.len()) };
+    0
+}
+
+pub unsafe extern "C" fn DAQmxGetDevAISupportedMeasTypes(
+    device: *const c_char,
+    data: *mut int32,
+    arraySizeInElements: uInt32,
+) -> int32 {
+    let name = unsafe { string_arg(device) };
+    match model::device(&name) {
+        Ok(_) => unsafe { write_array(&AI_MEASUREMENT_TYPES, data, arraySizeInElements) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetDevAIVoltageRngs(
+    device: *const c_char,
+    data: *mut float64,
+    arraySizeInElements: uInt32,
+) -> int32 {
+    const RANGES: [float64; 14] = [
+        -0.1, 0.1, -0.2, 0.2, -0.5, 0.5, -1.0, 1.0, -2.0, 2.0, -5.0, 5.0, -10.0, 10.0,
+    ];
+    let name = unsafe { string_arg(device) };
+    match model::device(&name) {
+        Ok(_) => unsafe { write_array(&RANGES, data, arraySizeInElements) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetDevAICurrentRngs(
+    device: *const c_char,
+    data: *mut float64,
+    arraySizeInElements: uInt32,
+) -> int32 {
+    // X Series devices measure current through an external shunt so have no current ranges.
+    let name = unsafe { string_arg(device) };
+    match model::device(&name) {
+        Ok(_) => unsafe { write_array(&[], data, arraySizeInElements) },
+        Err(code) => code,
+    }
+}
+
+// Physical channels.
+
+/// Check the physical channel is an analog input, which the `PhysicalChanAI` properties require.
+///
+/// # Safety
+///
+/// `physical_channel` must be null or a valid C string.
+unsafe fn analog_input(physical_channel: *const c_char) -> SimResult<()> {
+    let name = unsafe { string_arg(physical_channel) };
+    let (_, channel) = model::physical_channel(&name)?;
+    if channel.starts_with("ai") {
+        Ok(())
+    } else {
+        fail(
+            DAQmxErrorAttrNotSupported,
+            format!(
+                "Specified property is not supported by the device or is not applicable to the task.\n\nPhysical Channel: {name}"
+            ),
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetPhysicalChanAITermCfgs(
+    physicalChannel: *const c_char,
+    data: *mut int32,
+) -> int32 {
+    status(unsafe { analog_input(physicalChannel) }.map(|()| unsafe {
+        *data = DAQmx_Val_Bit_TermCfg_RSE | DAQmx_Val_Bit_TermCfg_NRSE | DAQmx_Val_Bit_TermCfg_Diff
+    }))
+}
+
+pub unsafe extern "C" fn DAQmxGetPhysicalChanAISupportedMeasTypes(
+    physicalChannel: *const c_char,
+    data: *mut int32,
+    arraySizeInElements: uInt32,
+) -> int32 {
+    match unsafe { analog_input(physicalChannel) } {
+        Ok(()) => unsafe { write_array(&AI_MEASUREMENT_TYPES, data, arraySizeInElements) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetPhysicalChanTEDSMfgID(
+    physicalChannel: *const c_char,
+    _data: *mut uInt32,
+) -> int32 {
+    // TEDS sensors aren't simulated.
+    let name = unsafe { string_arg(physicalChannel) };
+    status(physical_channel(&name).and_then(|_| {
+        fail(
+            DAQmxErrorTEDSSensorNotDetected,
+            format!("TEDS sensor not detected.\n\nPhysical Channel: {name}"),
+        )
+    }))
+}
+
+// Tasks.
+
+pub unsafe extern "C" fn DAQmxCreateTask(
+    taskName: *const c_char,
+    taskHandle: *mut TaskHandle,
+) -> int32 {
+    let name = unsafe { string_arg(taskName) };
+    match simulation().create_task(&name) {
+        Ok(handle) => {
+            unsafe { *taskHandle = handle };
+            0
+        }
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxClearTask(taskHandle: TaskHandle) -> int32 {
+    let _callbacks = callbacks();
+    status(simulation().clear_task(taskHandle))
+}
+
+pub unsafe extern "C" fn DAQmxStartTask(taskHandle: TaskHandle) -> int32 {
+    status(simulation().start_task(taskHandle, false))
+}
+
+pub unsafe extern "C" fn DAQmxStopTask(taskHandle: TaskHandle) -> int32 {
+    with_task(taskHandle, |task| {
+        task.stop();
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxWaitUntilTaskDone(
+    taskHandle: TaskHandle,
+    timeToWait: float64,
+) -> int32 {
+    status(wait_until_done(taskHandle, timeToWait))
+}
+
+pub unsafe extern "C" fn DAQmxGetTaskName(
+    taskHandle: TaskHandle,
+    data: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    let mut simulation = simulation();
+    match simulation.task(taskHandle) {
+        Ok(task) => unsafe { write_string(&task.name, data, bufferSize) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetTaskChannels(
+    taskHandle: TaskHandle,
+    data: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    let mut simulation = simulation();
+    match simulation.task(taskHandle) {
+        Ok(task) => {
+            let names: Vec<&str> = task
+                .channels
+                .iter()
+                .map(|channel| channel.name.as_str())
+                .collect();
+            unsafe { write_string(&names.join(", "), data, bufferSize) }
+        }
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetTaskNumChans(taskHandle: TaskHandle, data: *mut uInt32) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = task.channels.len() as uInt32 };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetReadAutoStart(taskHandle: TaskHandle, data: *mut bool32) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = task.properties.number("ReadAutoStart") as bool32 };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetReadCurrReadPos(
+    taskHandle: TaskHandle,
+    data: *mut uInt64,
+) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = task.read_position };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetWriteDigitalLinesBytesPerChan(
+    taskHandle: TaskHandle,
+    data: *mut uInt32,
+) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = max_lines(task) };
+        Ok(())
+    })
+}
+
+task_setters! {
+    DAQmxSetReadAutoStart: bool32 = "ReadAutoStart";
+    DAQmxSetPauseTrigType: int32 = "PauseTrigType";
+    DAQmxSetDigLvlPauseTrigWhen: int32 = "DigLvlPauseTrigWhen";
+    DAQmxSetAnlgLvlPauseTrigWhen: int32 = "AnlgLvlPauseTrigWhen";
+    DAQmxSetAnlgLvlPauseTrigLvl: float64 = "AnlgLvlPauseTrigLvl";
+}
+
+task_string_setters! {
+    DAQmxSetDigLvlPauseTrigSrc = "DigLvlPauseTrigSrc";
+    DAQmxSetAnlgLvlPauseTrigSrc = "AnlgLvlPauseTrigSrc";
+}
+
+// Timing.
+
+fn configure_timing(handle: TaskHandle, timing: Timing) -> int32 {
+    with_task(handle, |task| {
+        if task.run.is_some() {
+            return fail(
+                DAQmxErrorCanNotPerformOpWhileTaskRunning,
+                "Specified operation cannot be performed while the task is running.",
+            );
+        }
+        task.timing = Some(timing);
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxCfgSampClkTiming(
+    taskHandle: TaskHandle,
+    source: *const c_char,
+    rate: float64,
+    activeEdge: int32,
+    sampleMode: int32,
+    sampsPerChan: uInt64,
+) -> int32 {
+    let source = unsafe { string_arg(source) };
+    let status = configure_timing(
+        taskHandle,
+        Timing {
+            mode: sampleMode,
+            samples_per_channel: sampsPerChan,
+            rate: Some(rate),
+        },
+    );
+    if status < 0 {
+        return status;
+    }
+    with_task(taskHandle, |task| {
+        task.properties.set_text("SampClkSrc", source);
+        task.properties.set_number("SampClkActiveEdge", activeEdge);
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxCfgImplicitTiming(
+    taskHandle: TaskHandle,
+    sampleMode: int32,
+    sampsPerChan: uInt64,
+) -> int32 {
+    configure_timing(
+        taskHandle,
+        Timing {
+            mode: sampleMode,
+            samples_per_channel: sampsPerChan,
+            rate: None,
+        },
+    )
+}
+
+pub unsafe extern "C" fn DAQmxGetSampTimingType(taskHandle: TaskHandle, data: *mut int32) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = task.sample_timing_type() };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetSampClkRate(taskHandle: TaskHandle, data: *mut float64) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe { *data = task.timing.and_then(|timing| timing.rate).unwrap_or(0.0) };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetSampClkSrc(
+    taskHandle: TaskHandle,
+    data: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    let mut simulation = simulation();
+    match simulation.task(taskHandle) {
+        Ok(task) => unsafe { write_string(task.properties.text("SampClkSrc"), data, bufferSize) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetSampClkActiveEdge(
+    taskHandle: TaskHandle,
+    data: *mut int32,
+) -> int32 {
+    with_task(taskHandle, |task| {
+        let edge = match task.properties.number("SampClkActiveEdge") as int32 {
+            0 => DAQmx_Val_Rising,
+            edge => edge,
+        };
+        unsafe { *data = edge };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetSampQuantSampMode(
+    taskHandle: TaskHandle,
+    data: *mut int32,
+) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe {
+            *data = task
+                .timing
+                .map_or(DAQmx_Val_FiniteSamps, |timing| timing.mode)
+        };
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxGetSampQuantSampPerChan(
+    taskHandle: TaskHandle,
+    data: *mut uInt64,
+) -> int32 {
+    with_task(taskHandle, |task| {
+        unsafe {
+            *data = task
+                .timing
+                .map_or(1000, |timing| timing.samples_per_channel)
+        };
+        Ok(())
+    })
+}
+
+// Triggers. Simulated devices trigger immediately so these are only recorded.
+
+/// Record the trigger settings on the task.
+///
+/// # Safety
+///
+/// `source` must be null or a valid C string.
+unsafe fn configure_trigger(
+    handle: TaskHandle,
+    prefix: &'static str,
+    trigger_type: int32,
+    source: *const c_char,
+    settings: &[(&'static str, f64)],
+) -> int32 {
+    let source = unsafe { string_arg(source) };
+    with_task(handle, |task| {
+        let (type_key, source_key) = match prefix {
+            "Start" => ("StartTrigType", "StartTrigSrc"),
+            _ => ("RefTrigType", "RefTrigSrc"),
+        };
+        task.properties.set_number(type_key, trigger_type);
+        task.properties.set_text(source_key, source);
+        for (key, value) in settings {
+            task.properties.set_number(key, *value);
+        }
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxCfgDigEdgeStartTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerEdge: int32,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Start",
+            DAQmx_Val_DigEdge,
+            triggerSource,
+            &[("StartTrigEdge", triggerEdge as f64)],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCfgAnlgEdgeStartTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerSlope: int32,
+    triggerLevel: float64,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Start",
+            DAQmx_Val_AnlgEdge,
+            triggerSource,
+            &[
+                ("StartTrigSlope", triggerSlope as f64),
+                ("StartTrigLvl", triggerLevel),
+            ],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCfgAnlgWindowStartTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerWhen: int32,
+    windowTop: float64,
+    windowBottom: float64,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Start",
+            DAQmx_Val_AnlgWin,
+            triggerSource,
+            &[
+                ("StartTrigWhen", triggerWhen as f64),
+                ("StartTrigTop", windowTop),
+                ("StartTrigBtm", windowBottom),
+            ],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCfgDigEdgeRefTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerEdge: int32,
+    pretriggerSamples: uInt32,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Ref",
+            DAQmx_Val_DigEdge,
+            triggerSource,
+            &[
+                ("RefTrigEdge", triggerEdge as f64),
+                ("RefTrigPretrigSamples", pretriggerSamples as f64),
+            ],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCfgAnlgEdgeRefTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerSlope: int32,
+    triggerLevel: float64,
+    pretriggerSamples: uInt32,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Ref",
+            DAQmx_Val_AnlgEdge,
+            triggerSource,
+            &[
+                ("RefTrigSlope", triggerSlope as f64),
+                ("RefTrigLvl", triggerLevel),
+                ("RefTrigPretrigSamples", pretriggerSamples as f64),
+            ],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCfgAnlgWindowRefTrig(
+    taskHandle: TaskHandle,
+    triggerSource: *const c_char,
+    triggerWhen: int32,
+    windowTop: float64,
+    windowBottom: float64,
+    pretriggerSamples: uInt32,
+) -> int32 {
+    unsafe {
+        configure_trigger(
+            taskHandle,
+            "Ref",
+            DAQmx_Val_AnlgWin,
+            triggerSource,
+            &[
+                ("RefTrigWhen", triggerWhen as f64),
+                ("RefTrigTop", windowTop),
+                ("RefTrigBtm", windowBottom),
+                ("RefTrigPretrigSamples", pretriggerSamples as f64),
+            ],
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxDisableStartTrig(taskHandle: TaskHandle) -> int32 {
+    with_task(taskHandle, |task| {
+        task.properties.set_number("StartTrigType", DAQmx_Val_None);
+        Ok(())
+    })
+}
+
+pub unsafe extern "C" fn DAQmxDisableRefTrig(taskHandle: TaskHandle) -> int32 {
+    with_task(taskHandle, |task| {
+        task.properties.set_number("RefTrigType", DAQmx_Val_None);
+        Ok(())
+    })
+}
+
+// Events.
+
+/// Register or, with no callback, unregister an event.
+fn register_event(handle: TaskHandle, update: impl FnOnce(&mut Task) -> SimResult<()>) -> int32 {
+    let _callbacks = callbacks();
+    with_task(handle, |task| {
+        task.ensure_stopped()?;
+        update(task)
+    })
+}
+
+pub unsafe extern "C" fn DAQmxRegisterEveryNSamplesEvent(
+    task: TaskHandle,
+    everyNsamplesEventType: int32,
+    nSamples: uInt32,
+    options: uInt32,
+    callbackFunction: DAQmxEveryNSamplesEventCallbackPtr,
+    callbackData: *mut c_void,
+) -> int32 {
+    let _ = options;
+    register_event(task, |task| {
+        match (callbackFunction, &task.callbacks.every_n_samples) {
+            (Some(_), Some(_)) => fail(
+                DAQmxErrorEveryNSampsEventAlreadyRegistered,
+                "Every N Samples event is already registered for the task.",
+            ),
+            (callback, _) => {
+                task.callbacks.every_n_samples = callback.map(|callback| {
+                    (
+                        everyNsamplesEventType,
+                        nSamples,
+                        callback as model::EveryNSamplesCallback,
+                        callbackData.addr(),
+                    )
+                });
+                Ok(())
+            }
+        }
+    })
+}
+
+pub unsafe extern "C" fn DAQmxRegisterDoneEvent(
+    task: TaskHandle,
+    options: uInt32,
+    callbackFunction: DAQmxDoneEventCallbackPtr,
+    callbackData: *mut c_void,
+) -> int32 {
+    let _ = options;
+    register_event(task, |task| {
+        match (callbackFunction, &task.callbacks.done) {
+            (Some(_), Some(_)) => fail(
+                DAQmxErrorDoneEventAlreadyRegistered,
+                "Done event is already registered for the task.",
+            ),
+            (callback, _) => {
+                task.callbacks.done =
+                    callback.map(|callback| (callback as model::DoneCallback, callbackData.addr()));
+                Ok(())
+            }
+        }
+    })
+}
+
+pub unsafe extern "C" fn DAQmxRegisterSignalEvent(
+    task: TaskHandle,
+    signalID: int32,
+    options: uInt32,
+    callbackFunction: DAQmxSignalEventCallbackPtr,
+    callbackData: *mut c_void,
+) -> int32 {
+    let _ = options;
+    register_event(task, |task| {
+        match (callbackFunction, &task.callbacks.signal) {
+            (Some(_), Some(_)) => fail(
+                DAQmxErrorSignalEventAlreadyRegistered,
+                "Signal event is already registered for the task.",
+            ),
+            (callback, _) => {
+                task.callbacks.signal = callback.map(|callback| {
+                    (
+                        signalID,
+                        callback as model::SignalCallback,
+                        callbackData.addr(),
+                    )
+                });
+                Ok(())
+            }
+        }
+    })
+}
+
+// Channel creation.
+
+/// Create a channel for each physical channel, configuring each with `configure`.
+///
+/// # Safety
+///
+/// The strings must be null or valid C strings.
+unsafe fn create_channels(
+    handle: TaskHandle,
+    physical: *const c_char,
+    name: *const c_char,
+    channel_type: ChannelType,
+    scale: *const c_char,
+    configure: impl Fn(&mut Properties, &str),
+) -> int32 {
+    let physical = expand_physical_channels(&unsafe { string_arg(physical) });
+    let name = unsafe { string_arg(name) };
+    let scale = unsafe { string_arg(scale) };
+    add_channels(handle, &physical, &name, channel_type, &scale, configure)
+}
+
+fn add_channels(
+    handle: TaskHandle,
+    physical: &[String],
+    name: &str,
+    channel_type: ChannelType,
+    scale: &str,
+    configure: impl Fn(&mut Properties, &str),
+) -> int32 {
+    let mut simulation = simulation();
+    let result = check_scale(&simulation.scales, scale).and_then(|()| {
+        let task = simulation.task(handle)?;
+        if let Some(existing) = task.channels.first()
+            && existing.channel_type != channel_type
+        {
+            return fail(
+                DAQmxErrorMultiChanTypesInTask,
+                "Task cannot contain multiple channel types.",
+            );
+        }
+        let channels = physical
+            .iter()
+            .zip(channel_names(physical, name))
+            .map(|(physical, name)| {
+                let mut properties = Properties::default();
+                configure(&mut properties, physical);
+                Channel {
+                    name,
+                    physical: physical.clone(),
+                    channel_type,
+                    properties,
+                }
+            })
+            .collect();
+        task.add_channels(channels)
+    });
+    status(result)
+}
+
+/// The default terminals of an X Series counter as `(source, gate, output)`.
+fn counter_terminals(counter: &str) -> (String, String, String) {
+    let device = device_name(counter);
+    let index = counter
+        .rsplit('/')
+        .next()
+        .and_then(|counter| counter.strip_prefix("ctr"))
+        .and_then(|index| index.parse::<usize>().ok())
+        .unwrap_or(0);
+    let (source, gate, output) = [(8, 9, 12), (3, 4, 13), (0, 1, 14), (5, 6, 15)][index % 4];
+    (
+        format!("/{device}/PFI{source}"),
+        format!("/{device}/PFI{gate}"),
+        format!("/{device}/PFI{output}"),
+    )
+}
+
+fn timebase(counter: &str) -> String {
+    format!("/{}/20MHzTimebase", device_name(counter))
+}
+
+/// The Callendar-Van Dusen coefficients of the standard RTD types.
+fn rtd_coefficients(rtd_type: int32) -> (f64, f64, f64) {
+    #[allow(non_upper_case_globals)]
+    match rtd_type {
+        DAQmx_Val_Pt3750 => (3.81e-3, -6.02e-7, -6.0e-12),
+        DAQmx_Val_Pt3911 => (3.9692e-3, -5.8495e-7, -4.2325e-12),
+        DAQmx_Val_Pt3916 => (3.9739e-3, -5.870e-7, -4.4e-12),
+        DAQmx_Val_Pt3920 => (3.9787e-3, -5.8686e-7, -4.167e-12),
+        DAQmx_Val_Pt3928 => (3.9888e-3, -5.915e-7, -3.85e-12),
+        _ => (3.9083e-3, -5.775e-7, -4.183e-12),
+    }
+}
+
+fn terminal_config(config: int32) -> int32 {
+    if config == DAQmx_Val_Cfg_Default {
+        DAQmx_Val_RSE
+    } else {
+        config
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIVoltageChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Voltage);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIVoltageUnits", units);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAICurrentChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    shuntResistorLoc: int32,
+    extShuntResistorVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Current);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AICurrentUnits", units);
+                properties.default_number("AICurrentShuntLoc", shuntResistorLoc);
+                properties.default_number("AICurrentShuntResistance", extShuntResistorVal);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIVoltageRMSChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_VoltageRMS);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIVoltageUnits", units);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAICurrentRMSChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    shuntResistorLoc: int32,
+    extShuntResistorVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_CurrentRMS);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AICurrentUnits", units);
+                properties.default_number("AICurrentShuntLoc", shuntResistorLoc);
+                properties.default_number("AICurrentShuntResistance", extShuntResistorVal);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIFreqVoltageChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    thresholdLevel: float64,
+    hysteresis: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Freq_Voltage);
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIFreqUnits", units);
+                properties.default_number("AIFreqThreshVoltage", thresholdLevel);
+                properties.default_number("AIFreqHyst", hysteresis);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIRTDChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    rtdType: int32,
+    resistanceConfig: int32,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    r0: float64,
+) -> int32 {
+    let (a, b, c) = rtd_coefficients(rtdType);
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            std::ptr::null(),
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Temp_RTD);
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AITempUnits", units);
+                properties.default_number("AIRTDType", rtdType);
+                properties.default_number("AIResistanceCfg", resistanceConfig);
+                properties.default_number("AIExcitSrc", currentExcitSource);
+                properties.default_number("AIExcitVal", currentExcitVal);
+                properties.default_number("AIRTDR0", r0);
+                properties.default_number("AIRTDA", a);
+                properties.default_number("AIRTDB", b);
+                properties.default_number("AIRTDC", c);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIResistanceChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    resistanceConfig: int32,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Resistance);
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIResistanceUnits", units);
+                properties.default_number("AIResistanceCfg", resistanceConfig);
+                properties.default_number("AIExcitSrc", currentExcitSource);
+                properties.default_number("AIExcitVal", currentExcitVal);
+                properties.default_number("AILeadWireResistance", 0.0);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+/// Create thermistor channels, where `excitation` sets the excitation properties.
+///
+/// # Safety
+///
+/// The strings must be null or valid C strings.
+#[allow(clippy::too_many_arguments)]
+unsafe fn create_thermistor_channels(
+    handle: TaskHandle,
+    physical: *const c_char,
+    name: *const c_char,
+    (min, max, units): (float64, float64, int32),
+    resistance_config: int32,
+    (a, b, c): (float64, float64, float64),
+    excitation: impl Fn(&mut Properties),
+) -> int32 {
+    unsafe {
+        create_channels(
+            handle,
+            physical,
+            name,
+            ChannelType::AnalogInput,
+            std::ptr::null(),
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Temp_Thrmstr);
+                properties.default_number("AIMin", min);
+                properties.default_number("AIMax", max);
+                properties.default_number("AITempUnits", units);
+                properties.default_number("AIResistanceCfg", resistance_config);
+                properties.default_number("AIThrmstrA", a);
+                properties.default_number("AIThrmstrB", b);
+                properties.default_number("AIThrmstrC", c);
+                excitation(properties);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIThrmstrChanIex(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    resistanceConfig: int32,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    a: float64,
+    b: float64,
+    c: float64,
+) -> int32 {
+    unsafe {
+        create_thermistor_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            (minVal, maxVal, units),
+            resistanceConfig,
+            (a, b, c),
+            |properties| {
+                properties.default_number("AIExcitSrc", currentExcitSource);
+                properties.default_number("AIExcitVal", currentExcitVal);
+                properties.default_number("AIExcitVoltageOrCurrent", DAQmx_Val_Current);
+                properties.default_number("AIThrmstrR1", 0.0);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIThrmstrChanVex(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    resistanceConfig: int32,
+    voltageExcitSource: int32,
+    voltageExcitVal: float64,
+    a: float64,
+    b: float64,
+    c: float64,
+    r1: float64,
+) -> int32 {
+    unsafe {
+        create_thermistor_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            (minVal, maxVal, units),
+            resistanceConfig,
+            (a, b, c),
+            |properties| {
+                properties.default_number("AIExcitSrc", voltageExcitSource);
+                properties.default_number("AIExcitVal", voltageExcitVal);
+                properties.default_number("AIExcitVoltageOrCurrent", DAQmx_Val_Voltage);
+                properties.default_number("AIThrmstrR1", r1);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAITempBuiltInSensorChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    units: int32,
+) -> int32 {
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            std::ptr::null(),
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Temp_BuiltInSensor);
+                properties.default_number("AIMin", 0.0);
+                properties.default_number("AIMax", 100.0);
+                properties.default_number("AITempUnits", units);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIThrmcplChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    thermocoupleType: int32,
+    cjcSource: int32,
+    cjcVal: float64,
+    cjcChannel: *const c_char,
+) -> int32 {
+    let cjc_channel = unsafe { string_arg(cjcChannel) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            std::ptr::null(),
+            |properties, physical| {
+                properties.default_number("AIMeasType", DAQmx_Val_Temp_TC);
+                #[allow(non_upper_case_globals)]
+                let cjc_channel = match cjcSource {
+                    DAQmx_Val_BuiltIn => format!("{}/_cjtemp", device_name(physical)),
+                    DAQmx_Val_Chan => cjc_channel.clone(),
+                    _ => String::new(),
+                };
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AITempUnits", units);
+                properties.default_number("AIThrmcplType", thermocoupleType);
+                properties.default_number("AIThrmcplScaleType", DAQmx_Val_Polynomial);
+                properties.default_number("AIThrmcplCJCSrc", cjcSource);
+                properties.default_number("AIThrmcplCJCVal", cjcVal);
+                properties.default_text("AIThrmcplCJCChan", cjc_channel);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIAccelChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    sensitivity: float64,
+    sensitivityUnits: int32,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Accelerometer);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIAccelUnits", units);
+                properties.default_number("AIAccelSensitivity", sensitivity);
+                properties.default_number("AIAccelSensitivityUnits", sensitivityUnits);
+                iepe_defaults(properties, currentExcitSource, currentExcitVal);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIMicrophoneChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    units: int32,
+    micSensitivity: float64,
+    maxSndPressLevel: float64,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    // DAQmx derives the range from the maximum sound pressure level, which is in dB re 20 uPa.
+    let max_pressure = 20e-6 * 10f64.powf(maxSndPressLevel / 20.0) * 2f64.sqrt();
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_SoundPressure_Microphone);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", -max_pressure);
+                properties.default_number("AIMax", max_pressure);
+                properties.default_number("AISoundPressureUnits", units);
+                properties.default_number("AIMicrophoneSensitivity", micSensitivity);
+                properties.default_number("AISoundPressureMaxSoundPressureLvl", maxSndPressLevel);
+                iepe_defaults(properties, currentExcitSource, currentExcitVal);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIForceIEPEChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    terminalConfig: int32,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    sensitivity: float64,
+    sensitivityUnits: int32,
+    currentExcitSource: int32,
+    currentExcitVal: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Force_IEPESensor);
+                properties.default_number("AITermCfg", terminal_config(terminalConfig));
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIForceUnits", units);
+                properties.default_number("AIForceIEPESensorSensitivity", sensitivity);
+                properties.default_number("AIForceIEPESensorSensitivityUnits", sensitivityUnits);
+                iepe_defaults(properties, currentExcitSource, currentExcitVal);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+/// Set the excitation and coupling shared by IEPE channels. Like DSA devices, the simulation
+/// defaults to AC coupling when the excitation is on.
+fn iepe_defaults(properties: &mut Properties, excitation_source: int32, excitation_value: float64) {
+    let coupling = if excitation_source == DAQmx_Val_None {
+        DAQmx_Val_DC
+    } else {
+        DAQmx_Val_AC
+    };
+    properties.default_number("AIExcitSrc", excitation_source);
+    properties.default_number("AIExcitVal", excitation_value);
+    properties.default_number("AICoupling", coupling);
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIStrainGageChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    strainConfig: int32,
+    voltageExcitSource: int32,
+    voltageExcitVal: float64,
+    gageFactor: float64,
+    initialBridgeVoltage: float64,
+    nominalGageResistance: float64,
+    poissonRatio: float64,
+    leadWireResistance: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    #[allow(non_upper_case_globals)]
+    let bridge_config = match strainConfig {
+        DAQmx_Val_FullBridgeI | DAQmx_Val_FullBridgeII | DAQmx_Val_FullBridgeIII => {
+            DAQmx_Val_FullBridge
+        }
+        DAQmx_Val_HalfBridgeI | DAQmx_Val_HalfBridgeII => DAQmx_Val_HalfBridge,
+        _ => DAQmx_Val_QuarterBridge,
+    };
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Strain_Gage);
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIStrainUnits", units);
+                properties.default_number("AIStrainGageCfg", strainConfig);
+                properties.default_number("AIBridgeCfg", bridge_config);
+                properties.default_number("AIExcitSrc", voltageExcitSource);
+                properties.default_number("AIExcitVal", voltageExcitVal);
+                properties.default_number("AIStrainGageGageFactor", gageFactor);
+                properties.default_number("AIBridgeInitialVoltage", initialBridgeVoltage);
+                properties.default_number("AIBridgeNomResistance", nominalGageResistance);
+                properties.default_number("AIStrainGagePoissonRatio", poissonRatio);
+                properties.default_number("AILeadWireResistance", leadWireResistance);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAIBridgeChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    bridgeConfig: int32,
+    voltageExcitSource: int32,
+    voltageExcitVal: float64,
+    nominalBridgeResistance: float64,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogInput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AIMeasType", DAQmx_Val_Bridge);
+                properties.default_number("AIMin", minVal);
+                properties.default_number("AIMax", maxVal);
+                properties.default_number("AIBridgeUnits", units);
+                properties.default_number("AIBridgeCfg", bridgeConfig);
+                properties.default_number("AIExcitSrc", voltageExcitSource);
+                properties.default_number("AIExcitVal", voltageExcitVal);
+                properties.default_number("AIBridgeNomResistance", nominalBridgeResistance);
+                properties.default_number("AIBridgeInitialVoltage", 0.0);
+                properties.default_number("AILeadWireResistance", 0.0);
+                properties.default_text("AICustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+/// Create bridge sensor channels, such as load cells, which map the bridge output to physical
+/// units with `calibrate`.
+///
+/// # Safety
+///
+/// The strings must be null or valid C strings.
+#[allow(clippy::too_many_arguments)]
+unsafe fn create_bridge_sensor_channels(
+    handle: TaskHandle,
+    physical: *const c_char,
+    name: *const c_char,
+    measurement_type: int32,
+    units_key: &'static str,
+    (min, max, units): (float64, float64, int32),
+    (bridge_config, excitation_source, excitation_value, nominal_resistance): (
+        int32,
+        int32,
+        float64,
+        float64,
+    ),
+    (electrical_units, physical_units): (int32, int32),
+    custom_scale: *const c_char,
+    calibrate: impl Fn(&mut Properties),
+) -> int32 {
+    let scale = unsafe { string_arg(custom_scale) };
+    unsafe {
+        create_channels(
+            handle,
+            physical,
+            name,
+            ChannelType::AnalogInput,
+            custom_scale,
+            |properties, _| {
+                properties.default_number("AIMeasType", measurement_type);
+                properties.default_number("AIMin", min);
+                properties.default_number("AIMax", max);
+                properties.default_number(units_key, units);
+                properties.default_number("AIBridgeCfg", bridge_config);
+                properties.default_number("AIExcitSrc", excitation_source);
+                properties.default_number("AIExcitVal", excitation_value);
+                properties.default_number("AIBridgeNomResistance", nominal_resistance);
+                properties.default_number("AIBridgeInitialVoltage", 0.0);
+                properties.default_number("AILeadWireResistance", 0.0);
+                properties.default_number("AIBridgeElectricalUnits", electrical_units);
+                properties.default_number("AIBridgePhysicalUnits", physical_units);
+                properties.default_text("AICustomScaleName", scale.as_str());
+                calibrate(properties);
+            },
+        )
+    }
+}
+
+/// The two point linear, table and polynomial create functions of a bridge sensor.
+macro_rules! bridge_sensor_channels {
+    ($($two_point:ident, $table:ident, $polynomial:ident = $measurement:expr, $units_key:literal;)*) => {$(
+        pub unsafe extern "C" fn $two_point(
+            taskHandle: TaskHandle,
+            physicalChannel: *const c_char,
+            nameToAssignToChannel: *const c_char,
+            minVal: float64,
+            maxVal: float64,
+            units: int32,
+            bridgeConfig: int32,
+            voltageExcitSource: int32,
+            voltageExcitVal: float64,
+            nominalBridgeResistance: float64,
+            firstElectricalVal: float64,
+            secondElectricalVal: float64,
+            electricalUnits: int32,
+            firstPhysicalVal: float64,
+            secondPhysicalVal: float64,
+            physicalUnits: int32,
+            customScaleName: *const c_char,
+        ) -> int32 {
+            unsafe {
+                create_bridge_sensor_channels(
+                    taskHandle,
+                    physicalChannel,
+                    nameToAssignToChannel,
+                    $measurement,
+                    $units_key,
+                    (minVal, maxVal, units),
+                    (bridgeConfig, voltageExcitSource, voltageExcitVal, nominalBridgeResistance),
+                    (electricalUnits, physicalUnits),
+                    customScaleName,
+                    |properties| {
+                        properties.default_number("AIBridgeScaleType", DAQmx_Val_TwoPointLinear);
+                        properties.default_number("AIBridgeTwoPointLinFirstElectricalVal", firstElectricalVal);
+                        properties.default_number("AIBridgeTwoPointLinSecondElectricalVal", secondElectricalVal);
+                        properties.default_number("AIBridgeTwoPointLinFirstPhysicalVal", firstPhysicalVal);
+                        properties.default_number("AIBridgeTwoPointLinSecondPhysicalVal", secondPhysicalVal);
+                    },
+                )
+            }
+        }
+
+        pub unsafe extern "C" fn $table(
+            taskHandle: TaskHandle,
+            physicalChannel: *const c_char,
+            nameToAssignToChannel: *const c_char,
+            minVal: float64,
+            maxVal: float64,
+            units: int32,
+            bridgeConfig: int32,
+            voltageExcitSource: int32,
+            voltageExcitVal: float64,
+            nominalBridgeResistance: float64,
+            electricalVals: *const float64,
+            numElectricalVals: uInt32,
+            electricalUnits: int32,
+            physicalVals: *const float64,
+            numPhysicalVals: uInt32,
+            physicalUnits: int32,
+            customScaleName: *const c_char,
+        ) -> int32 {
+            let electrical = unsafe { array_arg(electricalVals, numElectricalVals) };
+            let physical = unsafe { array_arg(physicalVals, numPhysicalVals) };
+            if electrical.len() != physical.len() {
+                return status(fail(
+                    DAQmxErrorMismatchedInputArraySizes,
+                    "The electrical and physical values must be the same length.",
+                ));
+            }
+            unsafe {
+                create_bridge_sensor_channels(
+                    taskHandle,
+                    physicalChannel,
+                    nameToAssignToChannel,
+                    $measurement,
+                    $units_key,
+                    (minVal, maxVal, units),
+                    (bridgeConfig, voltageExcitSource, voltageExcitVal, nominalBridgeResistance),
+                    (electricalUnits, physicalUnits),
+                    customScaleName,
+                    |properties| {
+                        properties.default_number("AIBridgeScaleType", DAQmx_Val_Table);
+                        properties.default_numbers("AIBridgeTableElectricalVals", electrical.clone());
+                        properties.default_numbers("AIBridgeTablePhysicalVals", physical.clone());
+                    },
+                )
+            }
+        }
+
+        pub unsafe extern "C" fn $polynomial(
+            taskHandle: TaskHandle,
+            physicalChannel: *const c_char,
+            nameToAssignToChannel: *const c_char,
+            minVal: float64,
+            maxVal: float64,
+            units: int32,
+            bridgeConfig: int32,
+            voltageExcitSource: int32,
+            voltageExcitVal: float64,
+            nominalBridgeResistance: float64,
+            forwardCoeffs: *const float64,
+            numForwardCoeffs: uInt32,
+            reverseCoeffs: *const float64,
+            numReverseCoeffs: uInt32,
+            electricalUnits: int32,
+            physicalUnits: int32,
+            customScaleName: *const c_char,
+        ) -> int32 {
+            let forward = unsafe { array_arg(forwardCoeffs, numForwardCoeffs) };
+            let reverse = unsafe { array_arg(reverseCoeffs, numReverseCoeffs) };
+            if forward.is_empty() {
+                return status(fail(
+                    DAQmxErrorForwardPolynomialCoefNotSpecd,
+                    "Forward polynomial coefficients must be specified.",
+                ));
+            }
+            if reverse.is_empty() {
+                return status(fail(
+                    DAQmxErrorReversePolynomialCoefNotSpecd,
+                    "Reverse polynomial coefficients must be specified.",
+                ));
+            }
+            unsafe {
+                create_bridge_sensor_channels(
+                    taskHandle,
+                    physicalChannel,
+                    nameToAssignToChannel,
+                    $measurement,
+                    $units_key,
+                    (minVal, maxVal, units),
+                    (bridgeConfig, voltageExcitSource, voltageExcitVal, nominalBridgeResistance),
+                    (electricalUnits, physicalUnits),
+                    customScaleName,
+                    |properties| {
+                        properties.default_number("AIBridgeScaleType", DAQmx_Val_Polynomial);
+                        properties.default_numbers("AIBridgePolyForwardCoeff", forward.clone());
+                        properties.default_numbers("AIBridgePolyReverseCoeff", reverse.clone());
+                    },
+                )
+            }
+        }
+    )*};
+}
+
+bridge_sensor_channels! {
+    DAQmxCreateAIPressureBridgeTwoPointLinChan,
+    DAQmxCreateAIPressureBridgeTableChan,
+    DAQmxCreateAIPressureBridgePolynomialChan = DAQmx_Val_Pressure_Bridge, "AIPressureUnits";
+    DAQmxCreateAIForceBridgeTwoPointLinChan,
+    DAQmxCreateAIForceBridgeTableChan,
+    DAQmxCreateAIForceBridgePolynomialChan = DAQmx_Val_Force_Bridge, "AIForceUnits";
+    DAQmxCreateAITorqueBridgeTwoPointLinChan,
+    DAQmxCreateAITorqueBridgeTableChan,
+    DAQmxCreateAITorqueBridgePolynomialChan = DAQmx_Val_Torque_Bridge, "AITorqueUnits";
+}
+
+/// Getters for array channel properties.
+macro_rules! channel_array_getters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            channel: *const c_char,
+            data: *mut float64,
+            arraySizeInElements: uInt32,
+        ) -> int32 {
+            let mut values = Vec::new();
+            let found = unsafe {
+                with_channel(taskHandle, channel, |channel| {
+                    values = channel.properties.numbers($key).to_vec();
+                    Ok(())
+                })
+            };
+            if found < 0 {
+                return found;
+            }
+            unsafe { write_array(&values, data, arraySizeInElements) }
+        }
+    )*};
+}
+
+channel_array_getters! {
+    DAQmxGetAIBridgeTableElectricalVals = "AIBridgeTableElectricalVals";
+    DAQmxGetAIBridgeTablePhysicalVals = "AIBridgeTablePhysicalVals";
+    DAQmxGetAIBridgePolyForwardCoeff = "AIBridgePolyForwardCoeff";
+    DAQmxGetAIBridgePolyReverseCoeff = "AIBridgePolyReverseCoeff";
+}
+
+pub unsafe extern "C" fn DAQmxCreateAOVoltageChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogOutput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AOMin", minVal);
+                properties.default_number("AOMax", maxVal);
+                properties.default_number("AOVoltageUnits", units);
+                properties.default_text("AOCustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateAOCurrentChan(
+    taskHandle: TaskHandle,
+    physicalChannel: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            physicalChannel,
+            nameToAssignToChannel,
+            ChannelType::AnalogOutput,
+            customScaleName,
+            |properties, _| {
+                properties.default_number("AOMin", minVal);
+                properties.default_number("AOMax", maxVal);
+                properties.default_number("AOCurrentUnits", units);
+                properties.default_text("AOCustomScaleName", scale.as_str());
+            },
+        )
+    }
+}
+
+/// Create digital channels, either one per line or one for all the lines.
+///
+/// # Safety
+///
+/// The strings must be null or valid C strings.
+unsafe fn create_digital_channels(
+    handle: TaskHandle,
+    lines: *const c_char,
+    name: *const c_char,
+    line_grouping: int32,
+    channel_type: ChannelType,
+    prefix: &'static str,
+) -> int32 {
+    let lines_list = unsafe { string_arg(lines) };
+    let name = unsafe { string_arg(name) };
+    let lines = expand_lines(&lines_list);
+    let (number_of_lines_key, invert_key) = match prefix {
+        "DI" => ("DINumLines", "DIInvertLines"),
+        _ => ("DONumLines", "DOInvertLines"),
+    };
+    let (physical, lines_per_channel) = if line_grouping == DAQmx_Val_ChanPerLine {
+        (lines, 1)
+    } else {
+        let count = lines.len() as u32;
+        (vec![lines_list.trim().to_owned()], count)
+    };
+    add_channels(
+        handle,
+        &physical,
+        &name,
+        channel_type,
+        "",
+        |properties, _| {
+            properties.default_number(number_of_lines_key, lines_per_channel);
+            properties.default_number(invert_key, 0u32);
+        },
+    )
+}
+
+pub unsafe extern "C" fn DAQmxCreateDIChan(
+    taskHandle: TaskHandle,
+    lines: *const c_char,
+    nameToAssignToLines: *const c_char,
+    lineGrouping: int32,
+) -> int32 {
+    unsafe {
+        create_digital_channels(
+            taskHandle,
+            lines,
+            nameToAssignToLines,
+            lineGrouping,
+            ChannelType::DigitalInput,
+            "DI",
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateDOChan(
+    taskHandle: TaskHandle,
+    lines: *const c_char,
+    nameToAssignToLines: *const c_char,
+    lineGrouping: int32,
+) -> int32 {
+    unsafe {
+        create_digital_channels(
+            taskHandle,
+            lines,
+            nameToAssignToLines,
+            lineGrouping,
+            ChannelType::DigitalOutput,
+            "DO",
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCICountEdgesChan(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    edge: int32,
+    initialCount: uInt32,
+    countDirection: int32,
+) -> int32 {
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterInput,
+            std::ptr::null(),
+            |properties, counter| {
+                properties.default_number("CICountEdgesActiveEdge", edge);
+                properties.default_number("CICountEdgesInitialCnt", initialCount);
+                properties.default_number("CICountEdgesDir", countDirection);
+                properties.default_number("CIMeasType", DAQmx_Val_CountEdges);
+                properties.default_number("CICount", initialCount);
+                properties.default_text("CICountEdgesTerm", counter_terminals(counter).0);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCIFreqChan(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    edge: int32,
+    measMethod: int32,
+    measTime: float64,
+    divisor: uInt32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterInput,
+            customScaleName,
+            |properties, counter| {
+                properties.default_number("CIMin", minVal);
+                properties.default_number("CIMax", maxVal);
+                properties.default_number("CIFreqUnits", units);
+                properties.default_number("CIFreqStartingEdge", edge);
+                properties.default_number("CIFreqMeasMeth", measMethod);
+                properties.default_number("CIFreqMeasTime", measTime);
+                properties.default_number("CIFreqDiv", divisor);
+                properties.default_text("CICustomScaleName", scale.as_str());
+                properties.default_text("CIFreqTerm", counter_terminals(counter).1);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCIPeriodChan(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    edge: int32,
+    measMethod: int32,
+    measTime: float64,
+    divisor: uInt32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterInput,
+            customScaleName,
+            |properties, counter| {
+                properties.default_number("CIMin", minVal);
+                properties.default_number("CIMax", maxVal);
+                properties.default_number("CIPeriodUnits", units);
+                properties.default_number("CIPeriodStartingEdge", edge);
+                properties.default_number("CIPeriodMeasMeth", measMethod);
+                properties.default_number("CIPeriodMeasTime", measTime);
+                properties.default_number("CIPeriodDiv", divisor);
+                properties.default_text("CICustomScaleName", scale.as_str());
+                properties.default_text("CIPeriodTerm", counter_terminals(counter).1);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCIPulseWidthChan(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    minVal: float64,
+    maxVal: float64,
+    units: int32,
+    startingEdge: int32,
+    customScaleName: *const c_char,
+) -> int32 {
+    let scale = unsafe { string_arg(customScaleName) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterInput,
+            customScaleName,
+            |properties, counter| {
+                properties.default_number("CIMin", minVal);
+                properties.default_number("CIMax", maxVal);
+                properties.default_number("CIPulseWidthUnits", units);
+                properties.default_number("CIPulseWidthStartingEdge", startingEdge);
+                properties.default_text("CICustomScaleName", scale.as_str());
+                properties.default_text("CIPulseWidthTerm", counter_terminals(counter).1);
+            },
+        )
+    }
+}
+
+/// The properties common to all pulse outputs.
+fn configure_pulse(properties: &mut Properties, counter: &str, idle_state: int32) {
+    properties.default_number("COPulseIdleState", idle_state);
+    properties.default_number("COOutputState", idle_state);
+    properties.default_number("COPulseDone", 0u32);
+    properties.default_number("COCount", 0u32);
+    properties.default_text("COPulseTerm", counter_terminals(counter).2);
+}
+
+pub unsafe extern "C" fn DAQmxCreateCOPulseChanFreq(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    units: int32,
+    idleState: int32,
+    initialDelay: float64,
+    freq: float64,
+    dutyCycle: float64,
+) -> int32 {
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterOutput,
+            std::ptr::null(),
+            |properties, counter| {
+                configure_pulse(properties, counter, idleState);
+                properties.default_number("COPulseFreqUnits", units);
+                properties.default_number("COPulseFreqInitialDelay", initialDelay);
+                properties.default_number("COPulseFreq", freq);
+                properties.default_number("COPulseDutyCyc", dutyCycle);
+                properties.default_text("COCtrTimebaseSrc", timebase(counter));
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCOPulseChanTime(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    units: int32,
+    idleState: int32,
+    initialDelay: float64,
+    lowTime: float64,
+    highTime: float64,
+) -> int32 {
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterOutput,
+            std::ptr::null(),
+            |properties, counter| {
+                configure_pulse(properties, counter, idleState);
+                properties.default_number("COPulseTimeUnits", units);
+                properties.default_number("COPulseTimeInitialDelay", initialDelay);
+                properties.default_number("COPulseLowTime", lowTime);
+                properties.default_number("COPulseHighTime", highTime);
+                properties.default_text("COCtrTimebaseSrc", timebase(counter));
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateCOPulseChanTicks(
+    taskHandle: TaskHandle,
+    counter: *const c_char,
+    nameToAssignToChannel: *const c_char,
+    sourceTerminal: *const c_char,
+    idleState: int32,
+    initialDelay: int32,
+    lowTicks: int32,
+    highTicks: int32,
+) -> int32 {
+    let source = unsafe { string_arg(sourceTerminal) };
+    unsafe {
+        create_channels(
+            taskHandle,
+            counter,
+            nameToAssignToChannel,
+            ChannelType::CounterOutput,
+            std::ptr::null(),
+            |properties, counter| {
+                let source = if source.is_empty() {
+                    timebase(counter)
+                } else {
+                    source.clone()
+                };
+                configure_pulse(properties, counter, idleState);
+                properties.default_number("COPulseTicksInitialDelay", initialDelay);
+                properties.default_number("COPulseLowTicks", lowTicks);
+                properties.default_number("COPulseHighTicks", highTicks);
+                properties.default_text("COCtrTimebaseSrc", source);
+            },
+        )
+    }
+}
+
+// Channel properties.
+
+pub unsafe extern "C" fn DAQmxGetPhysicalChanName(
+    taskHandle: TaskHandle,
+    channel: *const c_char,
+    data: *mut c_char,
+    bufferSize: uInt32,
+) -> int32 {
+    let name = unsafe { string_arg(channel) };
+    let mut simulation = simulation();
+    match simulation
+        .task(taskHandle)
+        .and_then(|task| task.channel(&name))
+    {
+        Ok(channel) => unsafe { write_string(&channel.physical, data, bufferSize) },
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxGetChanType(
+    taskHandle: TaskHandle,
+    channel: *const c_char,
+    data: *mut int32,
+) -> int32 {
+    unsafe {
+        with_channel(taskHandle, channel, |channel| {
+            *data = match channel.channel_type {
+                ChannelType::AnalogInput => DAQmx_Val_AI,
+                ChannelType::AnalogOutput => DAQmx_Val_AO,
+                ChannelType::DigitalInput => DAQmx_Val_DI,
+                ChannelType::DigitalOutput => DAQmx_Val_DO,
+                ChannelType::CounterInput => DAQmx_Val_CI,
+                ChannelType::CounterOutput => DAQmx_Val_CO,
+            };
+            Ok(())
+        })
+    }
+}
+
+channel_getters! {
+    DAQmxGetAIMeasType: int32 = "AIMeasType";
+    DAQmxGetAIMax: float64 = "AIMax";
+    DAQmxGetAIMin: float64 = "AIMin";
+    DAQmxGetAITermCfg: int32 = "AITermCfg";
+    DAQmxGetAIVoltageUnits: int32 = "AIVoltageUnits";
+    DAQmxGetAICurrentUnits: int32 = "AICurrentUnits";
+    DAQmxGetAICurrentShuntLoc: int32 = "AICurrentShuntLoc";
+    DAQmxGetAICurrentShuntResistance: float64 = "AICurrentShuntResistance";
+    DAQmxGetAIResistanceCfg: int32 = "AIResistanceCfg";
+    DAQmxGetAIResistanceUnits: int32 = "AIResistanceUnits";
+    DAQmxGetAIFreqUnits: int32 = "AIFreqUnits";
+    DAQmxGetAIFreqThreshVoltage: float64 = "AIFreqThreshVoltage";
+    DAQmxGetAIFreqHyst: float64 = "AIFreqHyst";
+    DAQmxGetAIExcitSrc: int32 = "AIExcitSrc";
+    DAQmxGetAIExcitVal: float64 = "AIExcitVal";
+    DAQmxGetAIStrainUnits: int32 = "AIStrainUnits";
+    DAQmxGetAIStrainGageCfg: int32 = "AIStrainGageCfg";
+    DAQmxGetAIStrainGageGageFactor: float64 = "AIStrainGageGageFactor";
+    DAQmxGetAIStrainGagePoissonRatio: float64 = "AIStrainGagePoissonRatio";
+    DAQmxGetAIBridgeUnits: int32 = "AIBridgeUnits";
+    DAQmxGetAIBridgeCfg: int32 = "AIBridgeCfg";
+    DAQmxGetAIBridgeNomResistance: float64 = "AIBridgeNomResistance";
+    DAQmxGetAIBridgeInitialVoltage: float64 = "AIBridgeInitialVoltage";
+    DAQmxGetAILeadWireResistance: float64 = "AILeadWireResistance";
+    DAQmxGetAICoupling: int32 = "AICoupling";
+    DAQmxGetAIAccelUnits: int32 = "AIAccelUnits";
+    DAQmxGetAIAccelSensitivity: float64 = "AIAccelSensitivity";
+    DAQmxGetAIAccelSensitivityUnits: int32 = "AIAccelSensitivityUnits";
+    DAQmxGetAISoundPressureUnits: int32 = "AISoundPressureUnits";
+    DAQmxGetAIMicrophoneSensitivity: float64 = "AIMicrophoneSensitivity";
+    DAQmxGetAISoundPressureMaxSoundPressureLvl: float64 = "AISoundPressureMaxSoundPressureLvl";
+    DAQmxGetAIForceUnits: int32 = "AIForceUnits";
+    DAQmxGetAIForceIEPESensorSensitivity: float64 = "AIForceIEPESensorSensitivity";
+    DAQmxGetAIForceIEPESensorSensitivityUnits: int32 = "AIForceIEPESensorSensitivityUnits";
+    DAQmxGetAIPressureUnits: int32 = "AIPressureUnits";
+    DAQmxGetAITorqueUnits: int32 = "AITorqueUnits";
+    DAQmxGetAIBridgeElectricalUnits: int32 = "AIBridgeElectricalUnits";
+    DAQmxGetAIBridgePhysicalUnits: int32 = "AIBridgePhysicalUnits";
+    DAQmxGetAIBridgeScaleType: int32 = "AIBridgeScaleType";
+    DAQmxGetAIBridgeTwoPointLinFirstElectricalVal: float64 = "AIBridgeTwoPointLinFirstElectricalVal";
+    DAQmxGetAIBridgeTwoPointLinFirstPhysicalVal: float64 = "AIBridgeTwoPointLinFirstPhysicalVal";
+    DAQmxGetAIBridgeTwoPointLinSecondElectricalVal: float64 = "AIBridgeTwoPointLinSecondElectricalVal";
+    DAQmxGetAIBridgeTwoPointLinSecondPhysicalVal: float64 = "AIBridgeTwoPointLinSecondPhysicalVal";
+    DAQmxGetAITempUnits: int32 = "AITempUnits";
+    DAQmxGetAIRTDType: int32 = "AIRTDType";
+    DAQmxGetAIRTDR0: float64 = "AIRTDR0";
+    DAQmxGetAIRTDA: float64 = "AIRTDA";
+    DAQmxGetAIRTDB: float64 = "AIRTDB";
+    DAQmxGetAIRTDC: float64 = "AIRTDC";
+    DAQmxGetAIThrmstrA: float64 = "AIThrmstrA";
+    DAQmxGetAIThrmstrB: float64 = "AIThrmstrB";
+    DAQmxGetAIThrmstrC: float64 = "AIThrmstrC";
+    DAQmxGetAIThrmstrR1: float64 = "AIThrmstrR1";
+    DAQmxGetAIExcitVoltageOrCurrent: int32 = "AIExcitVoltageOrCurrent";
+    DAQmxGetAIThrmcplType: int32 = "AIThrmcplType";
+    DAQmxGetAIThrmcplScaleType: int32 = "AIThrmcplScaleType";
+    DAQmxGetAIThrmcplCJCSrc: int32 = "AIThrmcplCJCSrc";
+    DAQmxGetAIThrmcplCJCVal: float64 = "AIThrmcplCJCVal";
+    DAQmxGetAOMax: float64 = "AOMax";
+    DAQmxGetAOMin: float64 = "AOMin";
+    DAQmxGetAOVoltageUnits: int32 = "AOVoltageUnits";
+    DAQmxGetAOCurrentUnits: int32 = "AOCurrentUnits";
+    DAQmxGetDIInvertLines: bool32 = "DIInvertLines";
+    DAQmxGetDINumLines: uInt32 = "DINumLines";
+    DAQmxGetDOInvertLines: bool32 = "DOInvertLines";
+    DAQmxGetDONumLines: uInt32 = "DONumLines";
+    DAQmxGetCIMax: float64 = "CIMax";
+    DAQmxGetCIMin: float64 = "CIMin";
+    DAQmxGetCICount: uInt32 = "CICount";
+    DAQmxGetCICountEdgesActiveEdge: int32 = "CICountEdgesActiveEdge";
+    DAQmxGetCICountEdgesDir: int32 = "CICountEdgesDir";
+    DAQmxGetCICountEdgesInitialCnt: uInt32 = "CICountEdgesInitialCnt";
+    DAQmxGetCIFreqDiv: uInt32 = "CIFreqDiv";
+    DAQmxGetCIFreqMeasMeth: int32 = "CIFreqMeasMeth";
+    DAQmxGetCIFreqMeasTime: float64 = "CIFreqMeasTime";
+    DAQmxGetCIFreqStartingEdge: int32 = "CIFreqStartingEdge";
+    DAQmxGetCIFreqUnits: int32 = "CIFreqUnits";
+    DAQmxGetCIPeriodDiv: uInt32 = "CIPeriodDiv";
+    DAQmxGetCIPeriodMeasMeth: int32 = "CIPeriodMeasMeth";
+    DAQmxGetCIPeriodMeasTime: float64 = "CIPeriodMeasTime";
+    DAQmxGetCIPeriodStartingEdge: int32 = "CIPeriodStartingEdge";
+    DAQmxGetCIPeriodUnits: int32 = "CIPeriodUnits";
+    DAQmxGetCIPulseWidthStartingEdge: int32 = "CIPulseWidthStartingEdge";
+    DAQmxGetCIPulseWidthUnits: int32 = "CIPulseWidthUnits";
+    DAQmxGetCOCount: uInt32 = "COCount";
+    DAQmxGetCOOutputState: int32 = "COOutputState";
+    DAQmxGetCOPulseDone: bool32 = "COPulseDone";
+    DAQmxGetCOPulseDutyCyc: float64 = "COPulseDutyCyc";
+    DAQmxGetCOPulseFreq: float64 = "COPulseFreq";
+    DAQmxGetCOPulseFreqInitialDelay: float64 = "COPulseFreqInitialDelay";
+    DAQmxGetCOPulseHighTicks: uInt32 = "COPulseHighTicks";
+    DAQmxGetCOPulseHighTime: float64 = "COPulseHighTime";
+    DAQmxGetCOPulseIdleState: int32 = "COPulseIdleState";
+    DAQmxGetCOPulseLowTicks: uInt32 = "COPulseLowTicks";
+    DAQmxGetCOPulseLowTime: float64 = "COPulseLowTime";
+    DAQmxGetCOPulseTicksInitialDelay: uInt32 = "COPulseTicksInitialDelay";
+    DAQmxGetCOPulseTimeInitialDelay: float64 = "COPulseTimeInitialDelay";
+}
+
+channel_setters! {
+    DAQmxSetAIMax: float64 = "AIMax";
+    DAQmxSetAIMin: float64 = "AIMin";
+    DAQmxSetAITermCfg: int32 = "AITermCfg";
+    DAQmxSetAIVoltageUnits: int32 = "AIVoltageUnits";
+    DAQmxSetAICurrentUnits: int32 = "AICurrentUnits";
+    DAQmxSetAIResistanceCfg: int32 = "AIResistanceCfg";
+    DAQmxSetAIResistanceUnits: int32 = "AIResistanceUnits";
+    DAQmxSetAIFreqUnits: int32 = "AIFreqUnits";
+    DAQmxSetAIFreqThreshVoltage: float64 = "AIFreqThreshVoltage";
+    DAQmxSetAIFreqHyst: float64 = "AIFreqHyst";
+    DAQmxSetAIExcitSrc: int32 = "AIExcitSrc";
+    DAQmxSetAIExcitVal: float64 = "AIExcitVal";
+    DAQmxSetAIStrainUnits: int32 = "AIStrainUnits";
+    DAQmxSetAIStrainGageCfg: int32 = "AIStrainGageCfg";
+    DAQmxSetAIStrainGageGageFactor: float64 = "AIStrainGageGageFactor";
+    DAQmxSetAIStrainGagePoissonRatio: float64 = "AIStrainGagePoissonRatio";
+    DAQmxSetAIBridgeUnits: int32 = "AIBridgeUnits";
+    DAQmxSetAIBridgeCfg: int32 = "AIBridgeCfg";
+    DAQmxSetAIBridgeNomResistance: float64 = "AIBridgeNomResistance";
+    DAQmxSetAIBridgeInitialVoltage: float64 = "AIBridgeInitialVoltage";
+    DAQmxSetAILeadWireResistance: float64 = "AILeadWireResistance";
+    DAQmxSetAICoupling: int32 = "AICoupling";
+    DAQmxSetAIAccelUnits: int32 = "AIAccelUnits";
+    DAQmxSetAIAccelSensitivity: float64 = "AIAccelSensitivity";
+    DAQmxSetAIAccelSensitivityUnits: int32 = "AIAccelSensitivityUnits";
+    DAQmxSetAISoundPressureUnits: int32 = "AISoundPressureUnits";
+    DAQmxSetAIMicrophoneSensitivity: float64 = "AIMicrophoneSensitivity";
+    DAQmxSetAISoundPressureMaxSoundPressureLvl: float64 = "AISoundPressureMaxSoundPressureLvl";
+    DAQmxSetAIForceUnits: int32 = "AIForceUnits";
+    DAQmxSetAIForceIEPESensorSensitivity: float64 = "AIForceIEPESensorSensitivity";
+    DAQmxSetAIForceIEPESensorSensitivityUnits: int32 = "AIForceIEPESensorSensitivityUnits";
+    DAQmxSetAIPressureUnits: int32 = "AIPressureUnits";
+    DAQmxSetAITorqueUnits: int32 = "AITorqueUnits";
+    DAQmxSetAIBridgeElectricalUnits: int32 = "AIBridgeElectricalUnits";
+    DAQmxSetAIBridgePhysicalUnits: int32 = "AIBridgePhysicalUnits";
+    DAQmxSetAITempUnits: int32 = "AITempUnits";
+    DAQmxSetAIRTDType: int32 = "AIRTDType";
+    DAQmxSetAIRTDR0: float64 = "AIRTDR0";
+    DAQmxSetAIRTDA: float64 = "AIRTDA";
+    DAQmxSetAIRTDB: float64 = "AIRTDB";
+    DAQmxSetAIRTDC: float64 = "AIRTDC";
+    DAQmxSetAIThrmstrA: float64 = "AIThrmstrA";
+    DAQmxSetAIThrmstrB: float64 = "AIThrmstrB";
+    DAQmxSetAIThrmstrC: float64 = "AIThrmstrC";
+    DAQmxSetAIThrmcplType: int32 = "AIThrmcplType";
+    DAQmxSetAIThrmcplScaleType: int32 = "AIThrmcplScaleType";
+    DAQmxSetAIThrmcplCJCVal: float64 = "AIThrmcplCJCVal";
+    DAQmxSetAOMax: float64 = "AOMax";
+    DAQmxSetAOMin: float64 = "AOMin";
+    DAQmxSetAOVoltageUnits: int32 = "AOVoltageUnits";
+    DAQmxSetAOCurrentUnits: int32 = "AOCurrentUnits";
+    DAQmxSetDIInvertLines: bool32 = "DIInvertLines";
+    DAQmxSetDOInvertLines: bool32 = "DOInvertLines";
+    DAQmxSetCIMax: float64 = "CIMax";
+    DAQmxSetCIMin: float64 = "CIMin";
+    DAQmxSetCICountEdgesActiveEdge: int32 = "CICountEdgesActiveEdge";
+    DAQmxSetCICountEdgesDir: int32 = "CICountEdgesDir";
+    DAQmxSetCICountEdgesInitialCnt: uInt32 = "CICountEdgesInitialCnt";
+    DAQmxSetCIFreqDiv: uInt32 = "CIFreqDiv";
+    DAQmxSetCIFreqMeasMeth: int32 = "CIFreqMeasMeth";
+    DAQmxSetCIFreqMeasTime: float64 = "CIFreqMeasTime";
+    DAQmxSetCIFreqStartingEdge: int32 = "CIFreqStartingEdge";
+    DAQmxSetCIFreqUnits: int32 = "CIFreqUnits";
+    DAQmxSetCIPeriodDiv: uInt32 = "CIPeriodDiv";
+    DAQmxSetCIPeriodMeasMeth: int32 = "CIPeriodMeasMeth";
+    DAQmxSetCIPeriodMeasTime: float64 = "CIPeriodMeasTime";
+    DAQmxSetCIPeriodStartingEdge: int32 = "CIPeriodStartingEdge";
+    DAQmxSetCIPeriodUnits: int32 = "CIPeriodUnits";
+    DAQmxSetCIPulseWidthStartingEdge: int32 = "CIPulseWidthStartingEdge";
+    DAQmxSetCIPulseWidthUnits: int32 = "CIPulseWidthUnits";
+    DAQmxSetCOPulseDutyCyc: float64 = "COPulseDutyCyc";
+    DAQmxSetCOPulseFreq: float64 = "COPulseFreq";
+    DAQmxSetCOPulseFreqInitialDelay: float64 = "COPulseFreqInitialDelay";
+    DAQmxSetCOPulseHighTicks: uInt32 = "COPulseHighTicks";
+    DAQmxSetCOPulseHighTime: float64 = "COPulseHighTime";
+    DAQmxSetCOPulseIdleState: int32 = "COPulseIdleState";
+    DAQmxSetCOPulseLowTicks: uInt32 = "COPulseLowTicks";
+    DAQmxSetCOPulseLowTime: float64 = "COPulseLowTime";
+    DAQmxSetCOPulseTicksInitialDelay: uInt32 = "COPulseTicksInitialDelay";
+    DAQmxSetCOPulseTimeInitialDelay: float64 = "COPulseTimeInitialDelay";
+}
+
+channel_string_getters! {
+    DAQmxGetAICustomScaleName = "AICustomScaleName";
+    DAQmxGetAIThrmcplCJCChan = "AIThrmcplCJCChan";
+    DAQmxGetAOCustomScaleName = "AOCustomScaleName";
+    DAQmxGetCICustomScaleName = "CICustomScaleName";
+    DAQmxGetCICountEdgesTerm = "CICountEdgesTerm";
+    DAQmxGetCIFreqTerm = "CIFreqTerm";
+    DAQmxGetCIPeriodTerm = "CIPeriodTerm";
+    DAQmxGetCIPulseWidthTerm = "CIPulseWidthTerm";
+    DAQmxGetCOCtrTimebaseSrc = "COCtrTimebaseSrc";
+    DAQmxGetCOPulseTerm = "COPulseTerm";
+}
+
+channel_scale_setters! {
+    DAQmxSetAICustomScaleName = "AICustomScaleName";
+    DAQmxSetAOCustomScaleName = "AOCustomScaleName";
+    DAQmxSetCICustomScaleName = "CICustomScaleName";
+}
+
+channel_resetters! {
+    DAQmxResetAICustomScaleName = "AICustomScaleName";
+    DAQmxResetAILeadWireResistance = "AILeadWireResistance";
+    DAQmxResetAIFreqThreshVoltage = "AIFreqThreshVoltage";
+    DAQmxResetAIFreqHyst = "AIFreqHyst";
+    DAQmxResetAITempUnits = "AITempUnits";
+    DAQmxResetAIRTDType = "AIRTDType";
+    DAQmxResetAIRTDR0 = "AIRTDR0";
+    DAQmxResetAIRTDA = "AIRTDA";
+    DAQmxResetAIRTDB = "AIRTDB";
+    DAQmxResetAIRTDC = "AIRTDC";
+    DAQmxResetAIThrmcplType = "AIThrmcplType";
+    DAQmxResetAIThrmcplScaleType = "AIThrmcplScaleType";
+    DAQmxResetAIThrmcplCJCVal = "AIThrmcplCJCVal";
+    DAQmxResetAOCustomScaleName = "AOCustomScaleName";
+    DAQmxResetDIInvertLines = "DIInvertLines";
+    DAQmxResetDOInvertLines = "DOInvertLines";
+    DAQmxResetCIMax = "CIMax";
+    DAQmxResetCIMin = "CIMin";
+    DAQmxResetCICustomScaleName = "CICustomScaleName";
+    DAQmxResetCICountEdgesActiveEdge = "CICountEdgesActiveEdge";
+    DAQmxResetCICountEdgesDir = "CICountEdgesDir";
+    DAQmxResetCICountEdgesInitialCnt = "CICountEdgesInitialCnt";
+    DAQmxResetCIFreqDiv = "CIFreqDiv";
+    DAQmxResetCIFreqMeasMeth = "CIFreqMeasMeth";
+    DAQmxResetCIFreqMeasTime = "CIFreqMeasTime";
+    DAQmxResetCIFreqStartingEdge = "CIFreqStartingEdge";
+    DAQmxResetCIFreqUnits = "CIFreqUnits";
+    DAQmxResetCIPeriodDiv = "CIPeriodDiv";
+    DAQmxResetCIPeriodMeasMeth = "CIPeriodMeasMeth";
+    DAQmxResetCIPeriodMeasTime = "CIPeriodMeasTime";
+    DAQmxResetCIPeriodStartingEdge = "CIPeriodStartingEdge";
+    DAQmxResetCIPeriodUnits = "CIPeriodUnits";
+    DAQmxResetCIPulseWidthStartingEdge = "CIPulseWidthStartingEdge";
+    DAQmxResetCIPulseWidthUnits = "CIPulseWidthUnits";
+    DAQmxResetCOPulseDutyCyc = "COPulseDutyCyc";
+    DAQmxResetCOPulseFreq = "COPulseFreq";
+    DAQmxResetCOPulseFreqInitialDelay = "COPulseFreqInitialDelay";
+    DAQmxResetCOPulseHighTicks = "COPulseHighTicks";
+    DAQmxResetCOPulseHighTime = "COPulseHighTime";
+    DAQmxResetCOPulseIdleState = "COPulseIdleState";
+    DAQmxResetCOPulseLowTicks = "COPulseLowTicks";
+    DAQmxResetCOPulseLowTime = "COPulseLowTime";
+    DAQmxResetCOPulseTicksInitialDelay = "COPulseTicksInitialDelay";
+    DAQmxResetCOPulseTimeInitialDelay = "COPulseTimeInitialDelay";
+}
+
+// Persistence to the MAX configuration, which lasts for the life of the process.
+
+pub unsafe extern "C" fn DAQmxSaveTask(
+    taskHandle: TaskHandle,
+    saveAs: *const c_char,
+    _author: *const c_char,
+    options: uInt32,
+) -> int32 {
+    let save_as = unsafe { string_arg(saveAs) };
+    let overwrite = options & DAQmx_Val_Save_Overwrite as uInt32 != 0;
+    status(simulation().save_task(taskHandle, &save_as, overwrite))
+}
+
+pub unsafe extern "C" fn DAQmxSaveGlobalChan(
+    taskHandle: TaskHandle,
+    channelName: *const c_char,
+    saveAs: *const c_char,
+    _author: *const c_char,
+    options: uInt32,
+) -> int32 {
+    let channel = unsafe { string_arg(channelName) };
+    let save_as = unsafe { string_arg(saveAs) };
+    let overwrite = options & DAQmx_Val_Save_Overwrite as uInt32 != 0;
+    status(simulation().save_channel(taskHandle, &channel, &save_as, overwrite))
+}
+
+pub unsafe extern "C" fn DAQmxSaveScale(
+    scaleName: *const c_char,
+    saveAs: *const c_char,
+    _author: *const c_char,
+    options: uInt32,
+) -> int32 {
+    let name = unsafe { string_arg(scaleName) };
+    let save_as = unsafe { string_arg(saveAs) };
+    let overwrite = options & DAQmx_Val_Save_Overwrite as uInt32 != 0;
+    status(simulation().save_scale(&name, &save_as, overwrite))
+}
+
+pub unsafe extern "C" fn DAQmxLoadTask(
+    taskName: *const c_char,
+    taskHandle: *mut TaskHandle,
+) -> int32 {
+    let name = unsafe { string_arg(taskName) };
+    match simulation().load_task(&name) {
+        Ok(handle) => {
+            unsafe { *taskHandle = handle };
+            0
+        }
+        Err(code) => code,
+    }
+}
+
+pub unsafe extern "C" fn DAQmxDeleteSavedTask(taskName: *const c_char) -> int32 {
+    let name = unsafe { string_arg(taskName) };
+    match simulation().saved_tasks.remove(&name) {
+        Some(_) => 0,
+        None => status(fail(
+            DAQmxErrorTaskNotInDataNeighborhood,
+            format!("Task specified is not in MAX.\n\nTask Name: {name}"),
+        )),
+    }
+}
+
+pub unsafe extern "C" fn DAQmxDeleteSavedGlobalChan(channelName: *const c_char) -> int32 {
+    let name = unsafe { string_arg(channelName) };
+    match simulation().saved_channels.remove(&name) {
+        Some(_) => 0,
+        None => status(fail(
+            DAQmxErrorVirtualChanDoesNotExist,
+            format!("Global channel specified does not exist.\n\nChannel Name: {name}"),
+        )),
+    }
+}
+
+pub unsafe extern "C" fn DAQmxDeleteSavedScale(scaleName: *const c_char) -> int32 {
+    let name = unsafe { string_arg(scaleName) };
+    match simulation().saved_scales.remove(&name) {
+        Some(_) => 0,
+        None => status(fail(
+            DAQmxErrorCustomScaleDoesNotExist,
+            format!("Custom scale specified does not exist.\n\nScale Name: {name}"),
+        )),
+    }
+}
+
+/// Write the names of saved items as a list.
+///
+/// # Safety
+///
+/// `data` must be valid for `size` bytes.
+unsafe fn write_names<'a>(
+    names: impl Iterator<Item = &'a String>,
+    data: *mut c_char,
+    size: uInt32,
+) -> int32 {
+    let names: Vec<&str> = names.map(String::as_str).collect();
+    unsafe { write_string(&names.join(", "), data, size) }
+}
+
+pub unsafe extern "C" fn DAQmxGetSysTasks(data: *mut c_char, bufferSize: uInt32) -> int32 {
+    unsafe { write_names(simulation().saved_tasks.keys(), data, bufferSize) }
+}
+
+pub unsafe extern "C" fn DAQmxGetSysGlobalChans(data: *mut c_char, bufferSize: uInt32) -> int32 {
+    unsafe { write_names(simulation().saved_channels.keys(), data, bufferSize) }
+}
+
+pub unsafe extern "C" fn DAQmxGetSysScales(data: *mut c_char, bufferSize: uInt32) -> int32 {
+    unsafe { write_names(simulation().saved_scales.keys(), data, bufferSize) }
+}
+
+// Scales.
+
+/// Create a scale with the properties common to every scale type, replacing any with the same name.
+///
+/// # Safety
+///
+/// `name` and `scaled_units` must be null or valid C strings.
+unsafe fn create_scale(
+    name: *const c_char,
+    scale_type: int32,
+    pre_scaled_units: int32,
+    scaled_units: *const c_char,
+    configure: impl FnOnce(&mut Properties),
+) -> int32 {
+    let name = unsafe { string_arg(name) };
+    let scaled_units = unsafe { string_arg(scaled_units) };
+    let mut scale = Scale::default();
+    scale.properties.default_number("ScaleType", scale_type);
+    scale
+        .properties
+        .default_number("ScalePreScaledUnits", pre_scaled_units);
+    scale
+        .properties
+        .default_text("ScaleScaledUnits", scaled_units);
+    scale.properties.default_text("ScaleDescr", "");
+    configure(&mut scale.properties);
+    simulation().scales.insert(name, scale);
+    0
+}
+
+pub unsafe extern "C" fn DAQmxCreateLinScale(
+    name: *const c_char,
+    slope: float64,
+    yIntercept: float64,
+    preScaledUnits: int32,
+    scaledUnits: *const c_char,
+) -> int32 {
+    unsafe {
+        create_scale(
+            name,
+            DAQmx_Val_Linear,
+            preScaledUnits,
+            scaledUnits,
+            |properties| {
+                properties.default_number("ScaleLinSlope", slope);
+                properties.default_number("ScaleLinYIntercept", yIntercept);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateMapScale(
+    name: *const c_char,
+    prescaledMin: float64,
+    prescaledMax: float64,
+    scaledMin: float64,
+    scaledMax: float64,
+    preScaledUnits: int32,
+    scaledUnits: *const c_char,
+) -> int32 {
+    if prescaledMin == prescaledMax {
+        return status(fail(
+            DAQmxErrorPreScaledMinEqualMax,
+            "Pre-scaled minimum cannot equal pre-scaled maximum.",
+        ));
+    }
+    if scaledMin == scaledMax {
+        return status(fail(
+            DAQmxErrorScaledMinEqualMax,
+            "Scaled minimum cannot equal scaled maximum.",
+        ));
+    }
+    unsafe {
+        create_scale(
+            name,
+            DAQmx_Val_MapRanges,
+            preScaledUnits,
+            scaledUnits,
+            |properties| {
+                properties.default_number("ScaleMapPreScaledMin", prescaledMin);
+                properties.default_number("ScaleMapPreScaledMax", prescaledMax);
+                properties.default_number("ScaleMapScaledMin", scaledMin);
+                properties.default_number("ScaleMapScaledMax", scaledMax);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreatePolynomialScale(
+    name: *const c_char,
+    forwardCoeffs: *const float64,
+    numForwardCoeffsIn: uInt32,
+    reverseCoeffs: *const float64,
+    numReverseCoeffsIn: uInt32,
+    preScaledUnits: int32,
+    scaledUnits: *const c_char,
+) -> int32 {
+    let forward = unsafe { array_arg(forwardCoeffs, numForwardCoeffsIn) };
+    let reverse = unsafe { array_arg(reverseCoeffs, numReverseCoeffsIn) };
+    if forward.is_empty() {
+        return status(fail(
+            DAQmxErrorForwardPolynomialCoefNotSpecd,
+            "Forward polynomial coefficients must be specified.",
+        ));
+    }
+    if reverse.is_empty() {
+        return status(fail(
+            DAQmxErrorReversePolynomialCoefNotSpecd,
+            "Reverse polynomial coefficients must be specified.",
+        ));
+    }
+    unsafe {
+        create_scale(
+            name,
+            DAQmx_Val_Polynomial,
+            preScaledUnits,
+            scaledUnits,
+            |properties| {
+                properties.default_numbers("ScalePolyForwardCoeff", forward);
+                properties.default_numbers("ScalePolyReverseCoeff", reverse);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCreateTableScale(
+    name: *const c_char,
+    prescaledVals: *const float64,
+    numPrescaledValsIn: uInt32,
+    scaledVals: *const float64,
+    numScaledValsIn: uInt32,
+    preScaledUnits: int32,
+    scaledUnits: *const c_char,
+) -> int32 {
+    let pre_scaled = unsafe { array_arg(prescaledVals, numPrescaledValsIn) };
+    let scaled = unsafe { array_arg(scaledVals, numScaledValsIn) };
+    if pre_scaled.is_empty() {
+        return status(fail(
+            DAQmxErrorTableScalePreScaledValsNotSpecd,
+            "Pre-scaled values must be specified for a table scale.",
+        ));
+    }
+    if pre_scaled.len() != scaled.len() {
+        return status(fail(
+            DAQmxErrorTableScaleNumPreScaledAndScaledValsNotEqual,
+            "Number of pre-scaled values does not equal the number of scaled values.",
+        ));
+    }
+    unsafe {
+        create_scale(
+            name,
+            DAQmx_Val_Table,
+            preScaledUnits,
+            scaledUnits,
+            |properties| {
+                properties.default_numbers("ScaleTablePreScaledVals", pre_scaled);
+                properties.default_numbers("ScaleTableScaledVals", scaled);
+            },
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxCalculateReversePolyCoeff(
+    forwardCoeffs: *const float64,
+    numForwardCoeffsIn: uInt32,
+    minValX: float64,
+    maxValX: float64,
+    numPointsToCompute: int32,
+    reversePolyOrder: int32,
+    reverseCoeffs: *mut float64,
+) -> int32 {
+    let forward = unsafe { array_arg(forwardCoeffs, numForwardCoeffsIn) };
+    if forward.is_empty() {
+        return status(fail(
+            DAQmxErrorForwardPolynomialCoefNotSpecd,
+            "Forward polynomial coefficients must be specified.",
+        ));
+    }
+    let order = match reversePolyOrder {
+        -1 => forward.len() as int32 - 1,
+        order => order,
+    };
+    if order <= 0 {
+        return status(fail(
+            DAQmxErrorReversePolyOrderNotPositive,
+            "Reverse polynomial order must be positive.",
+        ));
+    }
+    if numPointsToCompute <= order {
+        return status(fail(
+            DAQmxErrorReversePolyOrderLessThanNumPtsToCompute,
+            "Reverse polynomial order must be less than the number of points to compute.",
+        ));
+    }
+    let points = (0..numPointsToCompute).map(|index| {
+        let x = minValX + (maxValX - minValX) * index as f64 / (numPointsToCompute - 1) as f64;
+        (polynomial(&forward, x), x)
+    });
+    let reverse = fit_polynomial(points, order as usize);
+    unsafe { std::ptr::copy_nonoverlapping(reverse.as_ptr(), reverseCoeffs, reverse.len()) };
+    0
+}
+
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |sum, coefficient| sum * x + coefficient)
+}
+
+/// Least squares fit of a polynomial of the given order to the `(x, y)` points.
+fn fit_polynomial(points: impl Iterator<Item = (f64, f64)>, order: usize) -> Vec<f64> {
+    let size = order + 1;
+    // The normal equations, as an augmented matrix.
+    let mut matrix = vec![vec![0.0; size + 1]; size];
+    for (x, y) in points {
+        let powers: Vec<f64> = (0..=2 * order).map(|power| x.powi(power as i32)).collect();
+        for (row, equation) in matrix.iter_mut().enumerate() {
+            for (column, value) in equation[..size].iter_mut().enumerate() {
+                *value += powers[row + column];
+            }
+            equation[size] += y * powers[row];
+        }
+    }
+    // Gaussian elimination with partial pivoting.
+    for pivot in 0..size {
+        let best = (pivot..size)
+            .max_by(|a, b| matrix[*a][pivot].abs().total_cmp(&matrix[*b][pivot].abs()))
+            .unwrap_or(pivot);
+        matrix.swap(pivot, best);
+        let (upper, lower) = matrix.split_at_mut(pivot + 1);
+        let pivot_row = &upper[pivot];
+        for row in lower {
+            let factor = row[pivot] / pivot_row[pivot];
+            for (value, pivot_value) in row[pivot..].iter_mut().zip(&pivot_row[pivot..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut coefficients = vec![0.0; size];
+    for row in (0..size).rev() {
+        let known: f64 = (row + 1..size)
+            .map(|column| matrix[row][column] * coefficients[column])
+            .sum();
+        coefficients[row] = (matrix[row][size] - known) / matrix[row][row];
+    }
+    coefficients
+}
+
+/// Run `action` on the named scale, returning the status.
+///
+/// # Safety
+///
+/// `name` must be null or a valid C string.
+unsafe fn with_scale(
+    name: *const c_char,
+    action: impl FnOnce(&mut Scale) -> SimResult<()>,
+) -> int32 {
+    let name = unsafe { string_arg(name) };
+    let mut simulation = simulation();
+    let result = match simulation.scales.get_mut(&name) {
+        Some(scale) => action(scale),
+        None => fail(
+            DAQmxErrorCustomScaleDoesNotExist,
+            format!("Custom scale specified does not exist.\n\nScale Name: {name}"),
+        ),
+    };
+    status(result)
+}
+
+/// Getters and setters for numeric scale properties.
+macro_rules! scale_properties {
+    ($($getter:ident / $setter:ident: $t:ty = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $getter(scaleName: *const c_char, data: *mut $t) -> int32 {
+            unsafe {
+                with_scale(scaleName, |scale| {
+                    *data = <$t as Number>::from_f64(scale.properties.number($key));
+                    Ok(())
+                })
+            }
+        }
+
+        pub unsafe extern "C" fn $setter(scaleName: *const c_char, data: $t) -> int32 {
+            unsafe {
+                with_scale(scaleName, |scale| {
+                    scale.properties.set_number($key, data);
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Getters and setters for string scale properties.
+macro_rules! scale_string_properties {
+    ($($getter:ident / $setter:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $getter(
+            scaleName: *const c_char,
+            data: *mut c_char,
+            bufferSize: uInt32,
+        ) -> int32 {
+            let mut text = String::new();
+            let found = unsafe {
+                with_scale(scaleName, |scale| {
+                    text = scale.properties.text($key).to_owned();
+                    Ok(())
+                })
+            };
+            if found < 0 {
+                return found;
+            }
+            unsafe { write_string(&text, data, bufferSize) }
+        }
+
+        pub unsafe extern "C" fn $setter(scaleName: *const c_char, data: *const c_char) -> int32 {
+            let value = unsafe { string_arg(data) };
+            unsafe {
+                with_scale(scaleName, |scale| {
+                    scale.properties.set_text($key, value);
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+/// Getters for array scale properties.
+macro_rules! scale_array_getters {
+    ($($name:ident = $key:literal;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            scaleName: *const c_char,
+            data: *mut float64,
+            arraySizeInElements: uInt32,
+        ) -> int32 {
+            let mut values = Vec::new();
+            let found = unsafe {
+                with_scale(scaleName, |scale| {
+                    values = scale.properties.numbers($key).to_vec();
+                    Ok(())
+                })
+            };
+            if found < 0 {
+                return found;
+            }
+            unsafe { write_array(&values, data, arraySizeInElements) }
+        }
+    )*};
+}
+
+pub unsafe extern "C" fn DAQmxGetScaleType(scaleName: *const c_char, data: *mut int32) -> int32 {
+    unsafe {
+        with_scale(scaleName, |scale| {
+            *data = scale.properties.number("ScaleType") as int32;
+            Ok(())
+        })
+    }
+}
+
+scale_properties! {
+    DAQmxGetScalePreScaledUnits / DAQmxSetScalePreScaledUnits: int32 = "ScalePreScaledUnits";
+    DAQmxGetScaleLinSlope / DAQmxSetScaleLinSlope: float64 = "ScaleLinSlope";
+    DAQmxGetScaleLinYIntercept / DAQmxSetScaleLinYIntercept: float64 = "ScaleLinYIntercept";
+    DAQmxGetScaleMapPreScaledMin / DAQmxSetScaleMapPreScaledMin: float64 = "ScaleMapPreScaledMin";
+    DAQmxGetScaleMapPreScaledMax / DAQmxSetScaleMapPreScaledMax: float64 = "ScaleMapPreScaledMax";
+    DAQmxGetScaleMapScaledMin / DAQmxSetScaleMapScaledMin: float64 = "ScaleMapScaledMin";
+    DAQmxGetScaleMapScaledMax / DAQmxSetScaleMapScaledMax: float64 = "ScaleMapScaledMax";
+}
+
+scale_string_properties! {
+    DAQmxGetScaleDescr / DAQmxSetScaleDescr = "ScaleDescr";
+    DAQmxGetScaleScaledUnits / DAQmxSetScaleScaledUnits = "ScaleScaledUnits";
+}
+
+scale_array_getters! {
+    DAQmxGetScalePolyForwardCoeff = "ScalePolyForwardCoeff";
+    DAQmxGetScalePolyReverseCoeff = "ScalePolyReverseCoeff";
+    DAQmxGetScaleTablePreScaledVals = "ScaleTablePreScaledVals";
+    DAQmxGetScaleTableScaledVals = "ScaleTableScaledVals";
+}
+
+// Reads.
+
+/// The most lines in any channel, which is the bytes per sample of a line read.
+fn max_lines(task: &Task) -> u32 {
+    task.channels
+        .iter()
+        .map(|channel| {
+            let lines = channel.properties.number("DINumLines") as u32;
+            lines.max(channel.properties.number("DONumLines") as u32)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Read into the buffer, where each sample has `width` values laid out by the fill mode.
+///
+/// # Safety
+///
+/// `buffer` must be valid for `size` values.
+unsafe fn read_values<T>(
+    handle: TaskHandle,
+    requested: int32,
+    timeout: float64,
+    fill_mode: bool32,
+    buffer: *mut T,
+    size: uInt32,
+    samples_read: *mut int32,
+    width: impl Fn(&Task) -> usize,
+    value: impl Fn(&Task, usize, u64) -> T,
+) -> int32 {
+    let width = {
+        let mut simulation = simulation();
+        match simulation.task(handle) {
+            Ok(task) => width(task).max(1),
+            Err(code) => return code,
+        }
+    };
+    let capacity = size as u64 / width as u64;
+    let result = read(
+        handle,
+        requested,
+        timeout,
+        capacity,
+        |task, first, samples| {
+            for index in 0..samples {
+                for slot in 0..width {
+                    let offset = if fill_mode == DAQmx_Val_GroupByChannel as bool32 {
+                        slot * samples as usize + index as usize
+                    } else {
+                        index as usize * width + slot
+                    };
+                    unsafe { buffer.add(offset).write(value(task, slot, first + index)) };
+                }
+            }
+        },
+    );
+    match result {
+        Ok(read) => {
+            if !samples_read.is_null() {
+                unsafe { *samples_read = read.samples as int32 };
+            }
+            read.status
+        }
+        Err(code) => {
+            if !samples_read.is_null() {
+                unsafe { *samples_read = 0 };
+            }
+            code
+        }
+    }
+}
+
+/// The synthetic value of a channel as a floating point number.
+fn analog_value(task: &Task, channel_index: usize, sample: u64) -> f64 {
+    let channel = &task.channels[channel_index];
+    let properties = &channel.properties;
+    match channel.channel_type {
+        ChannelType::AnalogInput => sine(
+            properties.number("AIMin"),
+            properties.number("AIMax"),
+            channel_index,
+            sample,
+        ),
+        _ if properties.number("CIMeasType") as int32 == DAQmx_Val_CountEdges => {
+            counter_value(task, channel_index, sample) as f64
+        }
+        _ => sine(
+            properties.number("CIMin"),
+            properties.number("CIMax"),
+            channel_index,
+            sample,
+        ),
+    }
+}
+
+/// Counters count one edge per sample from their initial count.
+fn counter_value(task: &Task, channel_index: usize, sample: u64) -> u32 {
+    let initial = task.channels[channel_index]
+        .properties
+        .number("CICountEdgesInitialCnt") as u32;
+    initial.wrapping_add(sample as u32)
+}
+
+/// Digital ports count up with the sample, offset by the channel.
+fn digital_value(channel_index: usize, sample: u64) -> u64 {
+    sample.wrapping_add(channel_index as u64)
+}
+
+fn channel_count(task: &Task) -> usize {
+    task.channels.len()
+}
+
+pub unsafe extern "C" fn DAQmxReadAnalogF64(
+    taskHandle: TaskHandle,
+    numSampsPerChan: int32,
+    timeout: float64,
+    fillMode: bool32,
+    readArray: *mut float64,
+    arraySizeInSamps: uInt32,
+    sampsPerChanRead: *mut int32,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = reserved;
+    unsafe {
+        read_values(
+            taskHandle,
+            numSampsPerChan,
+            timeout,
+            fillMode,
+            readArray,
+            arraySizeInSamps,
+            sampsPerChanRead,
+            channel_count,
+            analog_value,
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxReadAnalogScalarF64(
+    taskHandle: TaskHandle,
+    timeout: float64,
+    value: *mut float64,
+    reserved: *mut bool32,
+) -> int32 {
+    unsafe {
+        DAQmxReadAnalogF64(
+            taskHandle,
+            1,
+            timeout,
+            DAQmx_Val_GroupByChannel as bool32,
+            value,
+            1,
+            std::ptr::null_mut(),
+            reserved,
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxReadCounterF64(
+    taskHandle: TaskHandle,
+    numSampsPerChan: int32,
+    timeout: float64,
+    readArray: *mut float64,
+    arraySizeInSamps: uInt32,
+    sampsPerChanRead: *mut int32,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = reserved;
+    unsafe {
+        read_values(
+            taskHandle,
+            numSampsPerChan,
+            timeout,
+            DAQmx_Val_GroupByChannel as bool32,
+            readArray,
+            arraySizeInSamps,
+            sampsPerChanRead,
+            channel_count,
+            analog_value,
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxReadCounterU32(
+    taskHandle: TaskHandle,
+    numSampsPerChan: int32,
+    timeout: float64,
+    readArray: *mut uInt32,
+    arraySizeInSamps: uInt32,
+    sampsPerChanRead: *mut int32,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = reserved;
+    unsafe {
+        read_values(
+            taskHandle,
+            numSampsPerChan,
+            timeout,
+            DAQmx_Val_GroupByChannel as bool32,
+            readArray,
+            arraySizeInSamps,
+            sampsPerChanRead,
+            channel_count,
+            counter_value,
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxReadCounterScalarF64(
+    taskHandle: TaskHandle,
+    timeout: float64,
+    value: *mut float64,
+    reserved: *mut bool32,
+) -> int32 {
+    unsafe {
+        DAQmxReadCounterF64(
+            taskHandle,
+            1,
+            timeout,
+            value,
+            1,
+            std::ptr::null_mut(),
+            reserved,
+        )
+    }
+}
+
+pub unsafe extern "C" fn DAQmxReadCounterScalarU32(
+    taskHandle: TaskHandle,
+    timeout: float64,
+    value: *mut uInt32,
+    reserved: *mut bool32,
+) -> int32 {
+    unsafe {
+        DAQmxReadCounterU32(
+            taskHandle,
+            1,
+            timeout,
+            value,
+            1,
+            std::ptr::null_mut(),
+            reserved,
+        )
+    }
+}
+
+/// Digital port reads of each width.
+macro_rules! digital_port_reads {
+    ($($name:ident: $t:ty;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            numSampsPerChan: int32,
+            timeout: float64,
+            fillMode: bool32,
+            readArray: *mut $t,
+            arraySizeInSamps: uInt32,
+            sampsPerChanRead: *mut int32,
+            reserved: *mut bool32,
+        ) -> int32 {
+            let _ = reserved;
+            unsafe {
+                read_values(
+                    taskHandle,
+                    numSampsPerChan,
+                    timeout,
+                    fillMode,
+                    readArray,
+                    arraySizeInSamps,
+                    sampsPerChanRead,
+                    channel_count,
+                    |_, channel_index, sample| digital_value(channel_index, sample) as $t,
+                )
+            }
+        }
+    )*};
+}
+
+digital_port_reads! {
+    DAQmxReadDigitalU8: uInt8;
+    DAQmxReadDigitalU16: uInt16;
+    DAQmxReadDigitalU32: uInt32;
+}
+
+pub unsafe extern "C" fn DAQmxReadDigitalLines(
+    taskHandle: TaskHandle,
+    numSampsPerChan: int32,
+    timeout: float64,
+    fillMode: bool32,
+    readArray: *mut uInt8,
+    arraySizeInBytes: uInt32,
+    sampsPerChanRead: *mut int32,
+    numBytesPerSamp: *mut int32,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = reserved;
+    let bytes_per_sample = {
+        let mut simulation = simulation();
+        match simulation.task(taskHandle) {
+            Ok(task) => max_lines(task).max(1) as usize,
+            Err(code) => return code,
+        }
+    };
+    if !numBytesPerSamp.is_null() {
+        unsafe { *numBytesPerSamp = bytes_per_sample as int32 };
+    }
+    unsafe {
+        read_values(
+            taskHandle,
+            numSampsPerChan,
+            timeout,
+            fillMode,
+            readArray,
+            arraySizeInBytes,
+            sampsPerChanRead,
+            |task| task.channels.len() * bytes_per_sample,
+            |_, slot, sample| {
+                let (channel_index, line) = (slot / bytes_per_sample, slot % bytes_per_sample);
+                ((digital_value(channel_index, sample) >> (line % 64)) & 1) as uInt8
+            },
+        )
+    }
+}
+
+// Writes. The data is accepted but not stored.
+
+/// Report the samples as written, starting the task if requested.
+///
+/// # Safety
+///
+/// `written` must be null or valid to write.
+unsafe fn write_samples(
+    handle: TaskHandle,
+    samples: int32,
+    auto_start: bool32,
+    written: *mut int32,
+) -> int32 {
+    match write(handle, samples, auto_start) {
+        Ok(samples) => {
+            if !written.is_null() {
+                unsafe { *written = samples };
+            }
+            0
+        }
+        Err(code) => code,
+    }
+}
+
+/// Array writes of each data type.
+macro_rules! array_writes {
+    ($($name:ident: $t:ty;)*) => {$(
+        pub unsafe extern "C" fn $name(
+            taskHandle: TaskHandle,
+            numSampsPerChan: int32,
+            autoStart: bool32,
+            timeout: float64,
+            dataLayout: bool32,
+            writeArray: *const $t,
+            sampsPerChanWritten: *mut int32,
+            reserved: *mut bool32,
+        ) -> int32 {
+            let _ = (timeout, dataLayout, writeArray, reserved);
+            unsafe { write_samples(taskHandle, numSampsPerChan, autoStart, sampsPerChanWritten) }
+        }
+    )*};
+}
+
+array_writes! {
+    DAQmxWriteAnalogF64: float64;
+    DAQmxWriteDigitalLines: uInt8;
+    DAQmxWriteDigitalU8: uInt8;
+    DAQmxWriteDigitalU16: uInt16;
+    DAQmxWriteDigitalU32: uInt32;
+}
+
+pub unsafe extern "C" fn DAQmxWriteAnalogScalarF64(
+    taskHandle: TaskHandle,
+    autoStart: bool32,
+    timeout: float64,
+    value: float64,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = (timeout, value, reserved);
+    unsafe { write_samples(taskHandle, 1, autoStart, std::ptr::null_mut()) }
+}
+
+pub unsafe extern "C" fn DAQmxWriteDigitalScalarU32(
+    taskHandle: TaskHandle,
+    autoStart: bool32,
+    timeout: float64,
+    value: uInt32,
+    reserved: *mut bool32,
+) -> int32 {
+    let _ = (timeout, value, reserved);
+    unsafe { write_samples(taskHandle, 1, autoStart, std::ptr::null_mut()) }
+}
@@ -0,0 +1,1082 @@
+//! The state of the simulated driver and the behaviour shared by the simulated functions.
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::bindings::*;
+
+/// How often waiting operations and the event thread check the simulation.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The rate used for implicitly timed tasks where the rate isn't known from the channel.
+const IMPLICIT_RATE: f64 = 1000.0;
+
+/// The result of an internal operation. The error is the DAQmx status code.
+pub(super) type SimResult<T> = Result<T, int32>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Record the message for `DAQmxGetExtendedErrorInfo` and return the code as an error.
+pub(super) fn fail<T>(code: int32, message: impl Into<String>) -> SimResult<T> {
+    let message = format!("{}\n\nStatus Code: {code}", message.into());
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+    Err(code)
+}
+
+pub(super) fn last_error() -> String {
+    LAST_ERROR.with(|last| last.borrow().clone())
+}
+
+/// Convert an internal result to a DAQmx status code.
+pub(super) fn status(result: SimResult<()>) -> int32 {
+    match result {
+        Ok(()) => 0,
+        Err(code) => code,
+    }
+}
+
+/// Read a C string argument, treating null as empty.
+///
+/// # Safety
+///
+/// `value` must be null or a valid C string.
+pub(super) unsafe fn string_arg(value: *const c_char) -> String {
+    if value.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(value) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Read an array argument, treating null as empty.
+///
+/// # Safety
+///
+/// `values` must be null or valid for `length` elements.
+pub(super) unsafe fn array_arg(values: *const f64, length: uInt32) -> Vec<f64> {
+    if values.is_null() {
+        return Vec::new();
+    }
+    unsafe { std::slice::from_raw_parts(values, length as usize) }.to_vec()
+}
+
+/// Copy a string out following the DAQmx convention, where a zero size returns the size required.
+///
+/// # Safety
+///
+/// `buffer` must be valid for `size` bytes.
+pub(super) unsafe fn write_string(value: &str, buffer: *mut c_char, size: uInt32) -> int32 {
+    let required = value.len() + 1;
+    if size == 0 || buffer.is_null() {
+        return required as int32;
+    }
+    if (size as usize) < required {
+        return status(fail(
+            DAQmxErrorBufferTooSmallForString,
+            "Buffer is too small to fit the string.",
+        ));
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(value.as_ptr() as *const c_char, buffer, value.len());
+        *buffer.add(value.len()) = 0;
+    }
+    0
+}
+
+/// A property value. Numbers of every width are stored as `f64` which holds them exactly.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Number(f64),
+    Numbers(Vec<f64>),
+    Text(String),
+}
+
+/// Conversion of the numeric DAQmx types to and from the stored value.
+pub(super) trait Number: Copy {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+number!(i32, u32, u64, f64);
+
+/// The properties of a channel or task. Resetting a property returns it to the value it was created with.
+#[derive(Debug, Default, Clone)]
+pub(super) struct Properties {
+    values: BTreeMap<&'static str, Value>,
+    defaults: BTreeMap<&'static str, Value>,
+}
+
+impl Properties {
+    pub fn number(&self, key: &str) -> f64 {
+        match self.values.get(key) {
+            Some(Value::Number(value)) => *value,
+            _ => 0.0,
+        }
+    }
+
+    pub fn numbers(&self, key: &str) -> &[f64] {
+        match self.values.get(key) {
+            Some(Value::Numbers(values)) => values,
+            _ => &[],
+        }
+    }
+
+    pub fn text(&self, key: &str) -> &str {
+        match self.values.get(key) {
+            Some(Value::Text(value)) => value,
+            _ => "",
+        }
+    }
+
+    pub fn set_number(&mut self, key: &'static str, value: impl Number) {
+        self.values.insert(key, Value::Number(value.to_f64()));
+    }
+
+    pub fn set_text(&mut self, key: &'static str, value: impl Into<String>) {
+        self.values.insert(key, Value::Text(value.into()));
+    }
+
+    /// Set the value and the value it is reset to.
+    pub fn default_number(&mut self, key: &'static str, value: impl Number) {
+        self.set_number(key, value);
+        self.defaults.insert(key, Value::Number(value.to_f64()));
+    }
+
+    /// Set the values and the values they are reset to.
+    pub fn default_numbers(&mut self, key: &'static str, values: Vec<f64>) {
+        let values = Value::Numbers(values);
+        self.values.insert(key, values.clone());
+        self.defaults.insert(key, values);
+    }
+
+    /// Set the text and the text it is reset to.
+    pub fn default_text(&mut self, key: &'static str, value: impl Into<String>) {
+        let value = Value::Text(value.into());
+        self.values.insert(key, value.clone());
+        self.defaults.insert(key, value);
+    }
+
+    pub fn reset(&mut self, key: &'static str) {
+        match self.defaults.get(key) {
+            Some(value) => self.values.insert(key, value.clone()),
+            None => self.values.remove(key),
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ChannelType {
+    AnalogInput,
+    AnalogOutput,
+    DigitalInput,
+    DigitalOutput,
+    CounterInput,
+    CounterOutput,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Channel {
+    pub name: String,
+    pub physical: String,
+    pub channel_type: ChannelType,
+    pub properties: Properties,
+}
+
+/// The device part of a physical channel name, ignoring any leading slash.
+pub(super) fn device_name(physical: &str) -> &str {
+    let physical = physical.trim_start_matches('/');
+    physical.split('/').next().unwrap_or(physical)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Timing {
+    pub mode: int32,
+    pub samples_per_channel: u64,
+    /// [`None`] for implicit timing.
+    pub rate: Option<f64>,
+}
+
+pub(super) type EveryNSamplesCallback =
+    unsafe extern "C" fn(TaskHandle, int32, uInt32, *mut c_void) -> int32;
+pub(super) type DoneCallback = unsafe extern "C" fn(TaskHandle, int32, *mut c_void) -> int32;
+pub(super) type SignalCallback = unsafe extern "C" fn(TaskHandle, int32, *mut c_void) -> int32;
+
+/// The callbacks registered on a task. The data pointers are stored as `usize` so the state is `Send`.
+#[derive(Debug, Default)]
+pub(super) struct Callbacks {
+    pub every_n_samples: Option<(int32, u32, EveryNSamplesCallback, usize)>,
+    pub done: Option<(DoneCallback, usize)>,
+    pub signal: Option<(int32, SignalCallback, usize)>,
+}
+
+impl Callbacks {
+    fn any(&self) -> bool {
+        self.every_n_samples.is_some() || self.done.is_some() || self.signal.is_some()
+    }
+}
+
+/// The state of a running task.
+#[derive(Debug)]
+pub(super) struct Run {
+    started: Instant,
+    auto_started: bool,
+    generation: u64,
+    /// Whether an event thread was started for this run.
+    events: bool,
+    /// Set once the event thread has made its last callback.
+    events_finished: bool,
+    next_event: u64,
+    signalled: u64,
+}
+
+#[derive(Debug)]
+pub(super) struct Task {
+    pub name: String,
+    pub channels: Vec<Channel>,
+    pub properties: Properties,
+    pub timing: Option<Timing>,
+    pub run: Option<Run>,
+    pub read_position: u64,
+    pub callbacks: Callbacks,
+}
+
+impl Task {
+    fn new(name: String) -> Self {
+        let mut properties = Properties::default();
+        properties.default_number("ReadAutoStart", 1u32);
+        Self {
+            name,
+            channels: Vec::new(),
+            properties,
+            timing: None,
+            run: None,
+            read_position: 0,
+            callbacks: Callbacks::default(),
+        }
+    }
+
+    /// Find a channel by name. An empty name refers to the first channel.
+    pub fn channel(&mut self, name: &str) -> SimResult<&mut Channel> {
+        let name = name.trim();
+        let position = if name.is_empty() {
+            (!self.channels.is_empty()).then_some(0)
+        } else {
+            self.channels
+                .iter()
+                .position(|channel| channel.name.eq_ignore_ascii_case(name))
+        };
+        match position {
+            Some(position) => Ok(&mut self.channels[position]),
+            None => fail(
+                DAQmxErrorChanNotInTask,
+                format!("Specified channel is not in the task.\n\nChannel Name: {name}"),
+            ),
+        }
+    }
+
+    pub fn add_channels(&mut self, channels: Vec<Channel>) -> SimResult<()> {
+        for channel in &channels {
+            if self
+                .channels
+                .iter()
+                .any(|existing| existing.name.eq_ignore_ascii_case(&channel.name))
+            {
+                return fail(
+                    DAQmxErrorChanAlreadyInTask,
+                    format!(
+                        "Channel is already in the task.\n\nChannel Name: {}",
+                        channel.name
+                    ),
+                );
+            }
+        }
+        self.channels.extend(channels);
+        Ok(())
+    }
+
+    pub fn sample_timing_type(&self) -> int32 {
+        match self.timing {
+            Some(Timing { rate: Some(_), .. }) => DAQmx_Val_SampClk,
+            Some(Timing { rate: None, .. }) => DAQmx_Val_Implicit,
+            None => DAQmx_Val_OnDemand,
+        }
+    }
+
+    /// The rate samples are acquired or generated at, or [`None`] if the task is software timed.
+    pub fn rate(&self) -> Option<f64> {
+        let timing = self.timing?;
+        if let Some(rate) = timing.rate {
+            return Some(rate);
+        }
+        // Counter outputs generate at the pulse frequency.
+        let properties = &self.channels.first()?.properties;
+        let period = properties.number("COPulseHighTime") + properties.number("COPulseLowTime");
+        if properties.number("COPulseFreq") > 0.0 {
+            Some(properties.number("COPulseFreq"))
+        } else if period > 0.0 {
+            Some(1.0 / period)
+        } else {
+            Some(IMPLICIT_RATE)
+        }
+    }
+
+    /// The number of samples per channel acquired or generated since the task started.
+    ///
+    /// Software timed tasks always have a sample available so this is [`u64::MAX`].
+    pub fn acquired(&self, now: Instant) -> u64 {
+        let Some(run) = &self.run else {
+            return 0;
+        };
+        let Some(rate) = self.rate() else {
+            return u64::MAX;
+        };
+        let acquired = (now.duration_since(run.started).as_secs_f64() * rate) as u64;
+        match self.finite_samples() {
+            Some(total) => acquired.min(total),
+            None => acquired,
+        }
+    }
+
+    /// The total samples per channel for a finite task.
+    pub fn finite_samples(&self) -> Option<u64> {
+        self.timing
+            .filter(|timing| timing.mode == DAQmx_Val_FiniteSamps)
+            .map(|timing| timing.samples_per_channel)
+    }
+
+    /// Whether the measurement or generation is complete. Continuous tasks never complete.
+    pub fn is_done(&self, now: Instant) -> bool {
+        match (self.timing, self.finite_samples()) {
+            (None, _) => true,
+            (Some(_), Some(total)) => self.acquired(now) >= total,
+            (Some(_), None) => false,
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.run = None;
+    }
+
+    pub fn ensure_stopped(&self) -> SimResult<()> {
+        if self.run.is_some() {
+            return fail(
+                DAQmxErrorCannotRegisterDAQmxSoftwareEventWhileTaskIsRunning,
+                "DAQmx software events cannot be registered or unregistered while the task is running.",
+            );
+        }
+        Ok(())
+    }
+
+    /// Stop a task started by a read or write once it has completed.
+    fn auto_stop(&mut self) {
+        let complete = match self.finite_samples() {
+            Some(total) => self.read_position >= total,
+            None => self.timing.is_none(),
+        };
+        if complete && self.run.as_ref().is_some_and(|run| run.auto_started) {
+            self.stop();
+        }
+    }
+}
+
+/// A device in the simulated system.
+pub(super) struct Device {
+    pub name: &'static str,
+    pub product_type: &'static str,
+    pub pxi_chassis: u32,
+    pub pxi_slot: u32,
+}
+
+/// The simulated devices. These are X Series cards, as the integration tests expect.
+pub(super) const DEVICES: [Device; 1] = [Device {
+    name: "PXI1Slot2",
+    product_type: "PXIe-6363",
+    pxi_chassis: 1,
+    pxi_slot: 2,
+}];
+
+/// Find a device by name. Like DAQmx, the name isn't case sensitive.
+pub(super) fn device(name: &str) -> SimResult<&'static Device> {
+    match DEVICES
+        .iter()
+        .find(|device| device.name.eq_ignore_ascii_case(name))
+    {
+        Some(device) => Ok(device),
+        None => fail(
+            DAQmxErrorInvalidDeviceID,
+            format!("Device identifier is invalid.\n\nDevice Specified: {name}"),
+        ),
+    }
+}
+
+/// Find the device a physical channel is on, checking the channel exists.
+///
+/// Returns the device and the channel name on the device, e.g. `ai0`.
+pub(super) fn physical_channel(name: &str) -> SimResult<(&'static Device, String)> {
+    let not_found = || {
+        fail(
+            DAQmxErrorPhysicalChanDoesNotExist,
+            format!(
+                "Physical channel specified does not exist on this device.\n\nPhysical Channel Name: {name}"
+            ),
+        )
+    };
+    let name = name.trim_start_matches('/');
+    let Some((device_name, channel)) = name.split_once('/') else {
+        return not_found();
+    };
+    let device = device(device_name)?;
+    let all = [
+        device.ai_physical_channels(),
+        device.ao_physical_channels(),
+        device.ports(),
+        device.lines(),
+        device.counters(),
+    ];
+    let exists = all
+        .iter()
+        .flat_map(|list| list.split(", "))
+        .any(|existing| existing.eq_ignore_ascii_case(name));
+    if !exists {
+        return not_found();
+    }
+    Ok((device, channel.to_ascii_lowercase()))
+}
+
+impl Device {
+    fn list(&self, channels: impl Iterator<Item = String>) -> String {
+        channels
+            .map(|channel| format!("{}/{channel}", self.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn ai_physical_channels(&self) -> String {
+        self.list((0..32).map(|index| format!("ai{index}")))
+    }
+
+    pub fn ao_physical_channels(&self) -> String {
+        self.list((0..4).map(|index| format!("ao{index}")))
+    }
+
+    pub fn ports(&self) -> String {
+        self.list((0..3).map(|index| format!("port{index}")))
+    }
+
+    pub fn lines(&self) -> String {
+        expand_lines(&self.ports()).join(", ")
+    }
+
+    pub fn counters(&self) -> String {
+        self.list((0..4).map(|index| format!("ctr{index}")))
+    }
+}
+
+/// A scale created in the simulation.
+#[derive(Debug, Default, Clone)]
+pub(super) struct Scale {
+    pub properties: Properties,
+}
+
+/// The configuration of a task saved to the simulated MAX configuration.
+#[derive(Debug, Clone)]
+pub(super) struct SavedTask {
+    channels: Vec<Channel>,
+    properties: Properties,
+    timing: Option<Timing>,
+}
+
+pub(super) struct Simulation {
+    tasks: BTreeMap<usize, Task>,
+    next_handle: usize,
+    pub scales: BTreeMap<String, Scale>,
+    pub saved_tasks: BTreeMap<String, SavedTask>,
+    pub saved_channels: BTreeMap<String, Channel>,
+    pub saved_scales: BTreeMap<String, Scale>,
+}
+
+static SIMULATION: Mutex<Simulation> = Mutex::new(Simulation {
+    tasks: BTreeMap::new(),
+    next_handle: 1,
+    scales: BTreeMap::new(),
+    saved_tasks: BTreeMap::new(),
+    saved_channels: BTreeMap::new(),
+    saved_scales: BTreeMap::new(),
+});
+
+/// Held while callbacks are being made or changed so callback data is never used after it is unregistered.
+///
+/// This must be locked before the simulation when both are needed.
+static CALLBACKS: Mutex<()> = Mutex::new(());
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+pub(super) fn simulation() -> MutexGuard<'static, Simulation> {
+    SIMULATION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub(super) fn callbacks() -> MutexGuard<'static, ()> {
+    CALLBACKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn handle_id(handle: TaskHandle) -> usize {
+    handle.addr()
+}
+
+fn raw_handle(id: usize) -> TaskHandle {
+    std::ptr::without_provenance_mut(id)
+}
+
+impl Simulation {
+    pub fn create_task(&mut self, name: &str) -> SimResult<TaskHandle> {
+        let id = self.next_handle;
+        let name = if name.is_empty() {
+            format!("_unnamedTask<{id}>")
+        } else {
+            name.to_owned()
+        };
+        if self
+            .tasks
+            .values()
+            .any(|task| task.name.eq_ignore_ascii_case(&name))
+        {
+            return fail(
+                DAQmxErrorDuplicateTask,
+                format!(
+                    "Task name specified conflicts with an existing task name.\n\nTask Name: {name}"
+                ),
+            );
+        }
+        self.next_handle += 1;
+        self.tasks.insert(id, Task::new(name));
+        Ok(raw_handle(id))
+    }
+
+    pub fn clear_task(&mut self, handle: TaskHandle) -> SimResult<()> {
+        match self.tasks.remove(&handle_id(handle)) {
+            Some(_) => Ok(()),
+            None => invalid_task(),
+        }
+    }
+
+    pub fn task(&mut self, handle: TaskHandle) -> SimResult<&mut Task> {
+        match self.tasks.get_mut(&handle_id(handle)) {
+            Some(task) => Ok(task),
+            None => invalid_task(),
+        }
+    }
+
+    pub fn save_task(
+        &mut self,
+        handle: TaskHandle,
+        save_as: &str,
+        overwrite: bool,
+    ) -> SimResult<()> {
+        let task = self.task(handle)?;
+        let save_as = if save_as.is_empty() {
+            task.name.clone()
+        } else {
+            save_as.to_owned()
+        };
+        let saved = SavedTask {
+            channels: task.channels.clone(),
+            properties: task.properties.clone(),
+            timing: task.timing,
+        };
+        if !overwrite && self.saved_tasks.contains_key(&save_as) {
+            return fail(
+                DAQmxErrorCantSaveTaskWithoutReplace,
+                format!(
+                    "Task cannot be saved without replacing an existing task.\n\nTask Name: {save_as}"
+                ),
+            );
+        }
+        self.saved_tasks.insert(save_as, saved);
+        Ok(())
+    }
+
+    pub fn load_task(&mut self, name: &str) -> SimResult<TaskHandle> {
+        let Some(saved) = self.saved_tasks.get(name).cloned() else {
+            return fail(
+                DAQmxErrorTaskNotInDataNeighborhood,
+                format!("Task specified is not in MAX.\n\nTask Name: {name}"),
+            );
+        };
+        let handle = self.create_task(name)?;
+        let task = self.task(handle)?;
+        task.channels = saved.channels;
+        task.properties = saved.properties;
+        task.timing = saved.timing;
+        Ok(handle)
+    }
+
+    pub fn save_channel(
+        &mut self,
+        handle: TaskHandle,
+        channel: &str,
+        save_as: &str,
+        overwrite: bool,
+    ) -> SimResult<()> {
+        let channel = self.task(handle)?.channel(channel)?.clone();
+        let save_as = if save_as.is_empty() {
+            channel.name.clone()
+        } else {
+            save_as.to_owned()
+        };
+        if !overwrite && self.saved_channels.contains_key(&save_as) {
+            return fail(
+                DAQmxErrorCantSaveChanWithoutReplace,
+                format!(
+                    "Channel cannot be saved without replacing an existing channel.\n\nChannel Name: {save_as}"
+                ),
+            );
+        }
+        self.saved_channels.insert(save_as, channel);
+        Ok(())
+    }
+
+    pub fn save_scale(&mut self, name: &str, save_as: &str, overwrite: bool) -> SimResult<()> {
+        let Some(scale) = self.scales.get(name).cloned() else {
+            return fail(
+                DAQmxErrorCustomScaleDoesNotExist,
+                format!("Custom scale specified does not exist.\n\nScale Name: {name}"),
+            );
+        };
+        let save_as = if save_as.is_empty() { name } else { save_as };
+        if !overwrite && self.saved_scales.contains_key(save_as) {
+            return fail(
+                DAQmxErrorCantSaveChanWithoutReplace,
+                format!(
+                    "Scale cannot be saved without replacing an existing scale.\n\nScale Name: {save_as}"
+                ),
+            );
+        }
+        self.saved_scales.insert(save_as.to_owned(), scale);
+        Ok(())
+    }
+
+    pub fn start_task(&mut self, handle: TaskHandle, auto_started: bool) -> SimResult<()> {
+        let task = self.task(handle)?;
+        if task.run.is_some() {
+            return fail(
+                DAQmxErrorCanNotPerformOpWhileTaskRunning,
+                "Specified operation cannot be performed while the task is running.",
+            );
+        }
+        let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+        let events = task.callbacks.any() && task.timing.is_some();
+        task.run = Some(Run {
+            started: Instant::now(),
+            auto_started,
+            generation,
+            events,
+            events_finished: false,
+            next_event: task
+                .callbacks
+                .every_n_samples
+                .map_or(0, |(_, samples, _, _)| samples as u64),
+            signalled: 0,
+        });
+        task.read_position = 0;
+        if events {
+            spawn_event_thread(handle_id(handle), generation);
+        }
+        Ok(())
+    }
+}
+
+fn invalid_task<T>() -> SimResult<T> {
+    fail(
+        DAQmxErrorInvalidTask,
+        "Task specified is invalid or does not exist.",
+    )
+}
+
+/// Expand a list of physical channels, e.g. `Dev1/ai0:2, Dev1/ai4`, into the individual channels.
+pub(super) fn expand_physical_channels(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .flat_map(expand_range)
+        .collect()
+}
+
+/// Expand a single range such as `Dev1/ai0:2` or `Dev1/port0/line4:7`.
+fn expand_range(entry: &str) -> Vec<String> {
+    let (path, last) = match entry.rfind('/') {
+        Some(index) => entry.split_at(index + 1),
+        None => ("", entry),
+    };
+    let Some((start, end)) = last.split_once(':') else {
+        return vec![entry.to_owned()];
+    };
+    let prefix_length = start.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (prefix, first) = start.split_at(prefix_length);
+    let end = end.trim_start_matches(prefix);
+    match (first.parse::<u32>(), end.parse::<u32>()) {
+        (Ok(first), Ok(end)) if first <= end => (first..=end)
+            .map(|index| format!("{path}{prefix}{index}"))
+            .collect(),
+        (Ok(first), Ok(end)) => (end..=first)
+            .rev()
+            .map(|index| format!("{path}{prefix}{index}"))
+            .collect(),
+        _ => vec![entry.to_owned()],
+    }
+}
+
+/// Expand digital ports into their lines. X Series devices have 32 lines on port 0 and 8 on the others.
+pub(super) fn expand_lines(list: &str) -> Vec<String> {
+    expand_physical_channels(list)
+        .into_iter()
+        .flat_map(|line| {
+            let last = line.rsplit('/').next().unwrap_or(&line);
+            match last.strip_prefix("port") {
+                Some(port) => {
+                    let width = if port == "0" { 32 } else { 8 };
+                    (0..width)
+                        .map(|index| format!("{line}/line{index}"))
+                        .collect()
+                }
+                None => vec![line],
+            }
+        })
+        .collect()
+}
+
+/// Name the channels created from the physical channels.
+///
+/// Without a name, DAQmx uses the physical channel. A single name for multiple channels is numbered.
+pub(super) fn channel_names(physical: &[String], name: &str) -> Vec<String> {
+    let names: Vec<&str> = name
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+    physical
+        .iter()
+        .enumerate()
+        .map(|(index, physical_name)| match names.as_slice() {
+            [] => physical_name.clone(),
+            [name] if physical.len() == 1 => (*name).to_owned(),
+            [name] => format!("{name}{index}"),
+            names => names
+                .get(index)
+                .map_or_else(|| physical_name.clone(), |name| (*name).to_owned()),
+        })
+        .collect()
+}
+
+/// The synthetic waveform: a sine wave in the middle half of the range,
+/// with each channel phase shifted so they can be told apart.
+pub(super) fn sine(min: f64, max: f64, channel_index: usize, sample: u64) -> f64 {
+    let middle = (max + min) / 2.0;
+    let amplitude = (max - min) / 4.0;
+    let phase = std::f64::consts::TAU * sample as f64 / 100.0 + channel_index as f64;
+    middle + amplitude * phase.sin()
+}
+
+/// The result of a read. A partial read on timeout has a non-zero status.
+pub(super) struct Read {
+    pub samples: u64,
+    pub status: int32,
+}
+
+/// Wait for the requested samples per channel and pass their position to `fill`.
+///
+/// `requested` of -1 reads all samples of a finite task, or those available for a continuous task.
+/// A negative timeout waits forever.
+pub(super) fn read(
+    handle: TaskHandle,
+    requested: int32,
+    timeout: float64,
+    capacity: u64,
+    fill: impl FnOnce(&Task, u64, u64),
+) -> SimResult<Read> {
+    let deadline = (timeout >= 0.0).then(|| Instant::now() + Duration::from_secs_f64(timeout));
+    {
+        let mut simulation = simulation();
+        let task = simulation.task(handle)?;
+        if task.run.is_none() {
+            if task.properties.number("ReadAutoStart") == 0.0 {
+                return not_running();
+            }
+            simulation.start_task(handle, true)?;
+        }
+    }
+
+    loop {
+        {
+            let mut simulation = simulation();
+            let task = simulation.task(handle)?;
+            if task.run.is_none() {
+                return not_running();
+            }
+            let now = Instant::now();
+            let available = task.acquired(now).saturating_sub(task.read_position);
+            let remaining = task
+                .finite_samples()
+                .map(|total| total.saturating_sub(task.read_position));
+            let wanted = match (requested, remaining) {
+                (-1, _) if task.timing.is_none() => Some(1),
+                (-1, remaining) => remaining,
+                (requested, _) => Some(requested.max(0) as u64),
+            };
+            if let (Some(wanted), Some(remaining)) = (wanted, remaining)
+                && wanted > remaining
+            {
+                return fail(
+                    DAQmxErrorSamplesWillNeverBeAvailable,
+                    "Attempted to read samples that will never be available. The requested samples are beyond the end of the finite acquisition.",
+                );
+            }
+
+            let complete = wanted.is_none_or(|wanted| available >= wanted);
+            let timed_out = deadline.is_some_and(|deadline| now >= deadline);
+            if complete || timed_out {
+                let samples = wanted.map_or(available, |wanted| wanted.min(available));
+                if samples > capacity {
+                    return fail(
+                        DAQmxErrorReadBufferTooSmall,
+                        "Buffer is too small to fit read data.",
+                    );
+                }
+                fill(task, task.read_position, samples);
+                task.read_position += samples;
+                task.auto_stop();
+                let status = if complete {
+                    0
+                } else {
+                    status(fail(
+                        DAQmxErrorSamplesNotYetAvailable,
+                        "Some or all of the samples requested have not yet been acquired.\n\nTo wait for the samples to become available use a longer read timeout.",
+                    ))
+                };
+                return Ok(Read { samples, status });
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn not_running<T>() -> SimResult<T> {
+    fail(
+        DAQmxErrorCannotReadWhenAutoStartFalseAndTaskNotRunningOrCommitted,
+        "Read cannot be performed when the auto start input to DAQmx Read is false and the task is not running or committed.",
+    )
+}
+
+/// Accept samples written to a task, starting a timed task if requested.
+pub(super) fn write(handle: TaskHandle, samples: int32, auto_start: bool32) -> SimResult<int32> {
+    let mut simulation = simulation();
+    let task = simulation.task(handle)?;
+    if auto_start != 0 && task.run.is_none() && task.timing.is_some() {
+        simulation.start_task(handle, true)?;
+    }
+    Ok(samples)
+}
+
+/// Wait for a finite task to complete, including any event callbacks for it.
+pub(super) fn wait_until_done(handle: TaskHandle, timeout: float64) -> SimResult<()> {
+    let deadline = (timeout >= 0.0).then(|| Instant::now() + Duration::from_secs_f64(timeout));
+    loop {
+        {
+            let mut simulation = simulation();
+            let task = simulation.task(handle)?;
+            let now = Instant::now();
+            let done = match &task.run {
+                None => true,
+                Some(run) => task.is_done(now) && (!run.events || run.events_finished),
+            };
+            if done {
+                return Ok(());
+            }
+            if deadline.is_some_and(|deadline| now >= deadline) {
+                return fail(
+                    DAQmxErrorWaitUntilDoneDoesNotIndicateDone,
+                    "Wait Until Done did not indicate that the task was done within the specified timeout.",
+                );
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// A callback to make once the simulation is unlocked.
+enum Call {
+    EveryNSamples(EveryNSamplesCallback, int32, u32, usize),
+    Done(DoneCallback, usize),
+    Signal(SignalCallback, int32, usize),
+}
+
+impl Call {
+    /// # Safety
+    ///
+    /// The callback data must still be registered.
+    unsafe fn invoke(self, handle: TaskHandle) {
+        unsafe {
+            match self {
+                Call::EveryNSamples(callback, event_type, samples, data) => {
+                    callback(handle, event_type, samples, data as *mut c_void);
+                }
+                Call::Done(callback, data) => {
+                    callback(handle, 0, data as *mut c_void);
+                }
+                Call::Signal(callback, signal, data) => {
+                    callback(handle, signal, data as *mut c_void);
+                }
+            }
+        }
+    }
+}
+
+/// Make the registered callbacks of a timed task while it runs.
+fn spawn_event_thread(id: usize, generation: u64) {
+    thread::spawn(move || {
+        let handle = raw_handle(id);
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let _callbacks = callbacks();
+            let mut calls = Vec::new();
+            let done;
+            {
+                let mut simulation = simulation();
+                let Ok(task) = simulation.task(handle) else {
+                    return;
+                };
+                let now = Instant::now();
+                let acquired = task.acquired(now);
+                done = task.is_done(now);
+                let callbacks = &task.callbacks;
+                let Some(run) = task.run.as_mut().filter(|run| run.generation == generation) else {
+                    return;
+                };
+
+                if let Some((event_type, samples, callback, data)) = callbacks.every_n_samples {
+                    while samples > 0 && acquired >= run.next_event {
+                        calls.push(Call::EveryNSamples(callback, event_type, samples, data));
+                        run.next_event += samples as u64;
+                    }
+                }
+                if let Some((signal, callback, data)) = callbacks.signal
+                    && signal == DAQmx_Val_SampleClock
+                {
+                    while run.signalled < acquired {
+                        calls.push(Call::Signal(callback, signal, data));
+                        run.signalled += 1;
+                    }
+                }
+                if let (true, Some((callback, data))) = (done, callbacks.done) {
+                    calls.push(Call::Done(callback, data));
+                }
+            }
+
+            for call in calls {
+                unsafe { call.invoke(handle) };
+            }
+            if done {
+                let mut simulation = simulation();
+                if let Some(run) = simulation
+                    .task(handle)
+                    .ok()
+                    .and_then(|task| task.run.as_mut())
+                    .filter(|run| run.generation == generation)
+                {
+                    run.events_finished = true;
+                }
+                return;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_physical_channels() {
+        assert_eq!(
+            expand_physical_channels("Dev1/ai0:2, Dev1/ai5"),
+            vec!["Dev1/ai0", "Dev1/ai1", "Dev1/ai2", "Dev1/ai5"]
+        );
+        assert_eq!(
+            expand_physical_channels("/Dev1/port0/line3:1"),
+            vec![
+                "/Dev1/port0/line3",
+                "/Dev1/port0/line2",
+                "/Dev1/port0/line1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_lines() {
+        assert_eq!(expand_lines("Dev1/port1").len(), 8);
+        assert_eq!(expand_lines("Dev1/port0").len(), 32);
+        assert_eq!(expand_lines("Dev1/port1/line0:1").len(), 2);
+    }
+
+    #[test]
+    fn test_channel_names() {
+        let physical = expand_physical_channels("Dev1/ai0:1");
+        assert_eq!(channel_names(&physical, ""), vec!["Dev1/ai0", "Dev1/ai1"]);
+        assert_eq!(channel_names(&physical, "a, b"), vec!["a", "b"]);
+        assert_eq!(channel_names(&physical, "a"), vec!["a0", "a1"]);
+        assert_eq!(channel_names(&physical[..1], "a"), vec!["a"]);
+    }
+
+    #[test]
+    fn test_device_lookup() {
+        assert_eq!(device("pxi1slot2").unwrap().name, "PXI1Slot2");
+        assert_eq!(device("Dev9").err(), Some(DAQmxErrorInvalidDeviceID));
+    }
+
+    #[test]
+    fn test_physical_channel_lookup() {
+        let (device, channel) = physical_channel("/PXI1Slot2/AI3").unwrap();
+        assert_eq!(device.name, "PXI1Slot2");
+        assert_eq!(channel, "ai3");
+        assert_eq!(
+            physical_channel("PXI1Slot2/ai32").err(),
+            Some(DAQmxErrorPhysicalChanDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_device_lines() {
+        let lines = DEVICES[0].lines();
+        assert!(lines.starts_with("PXI1Slot2/port0/line0, PXI1Slot2/port0/line1"));
+        assert_eq!(lines.split(", ").count(), 48);
+    }
+
+    #[test]
+    fn test_device_name() {
+        assert_eq!(device_name("/PXI1Slot2/ctr0"), "PXI1Slot2");
+        assert_eq!(device_name("PXI1Slot2/port0/line1"), "PXI1Slot2");
+    }
+}
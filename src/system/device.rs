@@ -0,0 +1,154 @@
+//! Properties of a single device.
+
+use super::{ArrayGetter, get_array};
+use crate::channels::ai_channels::MeasurementType;
+use crate::channels::properties::PropertyValue;
+use crate::error::Result;
+use crate::sys::*;
+use std::ffi::CString;
+
+/// An input range supported by a device, in the units of the measurement.
+///
+/// These are the values to use for the minimum and maximum of a channel to select the range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+/// A DAQmx device such as a PXI card or CompactDAQ module.
+///
+/// The device is not checked when it is created. If it isn't installed, reading the properties will return an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Device {
+    name: CString,
+}
+
+impl Device {
+    /// Refer to the device with the given name, e.g. `PXI1Slot2`.
+    pub fn new(name: &str) -> Result<Self> {
+        Ok(Self {
+            name: CString::new(name)?,
+        })
+    }
+
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        // Created from a &str so this is always valid.
+        self.name.to_str().unwrap_or_default()
+    }
+
+    system_property!(
+        /// The product type of the device, e.g. `PXIe-6363`.
+        get_string product_type = DAQmxGetDevProductType
+    );
+    system_property!(
+        /// The serial number of the device.
+        get serial_number: u32 = DAQmxGetDevSerialNum
+    );
+    system_property!(
+        /// Whether the device is a simulated device rather than installed hardware.
+        get is_simulated: bool = DAQmxGetDevIsSimulated
+    );
+
+    system_property!(
+        /// The number of the PXI chassis containing the device, as configured in MAX.
+        get pxi_chassis_number: u32 = DAQmxGetDevPXIChassisNum
+    );
+    system_property!(
+        /// The slot of the PXI chassis containing the device.
+        get pxi_slot_number: u32 = DAQmxGetDevPXISlotNum
+    );
+    system_property!(
+        /// The name of the CompactDAQ chassis containing the module.
+        get_string compact_daq_chassis_name = DAQmxGetDevCompactDAQChassisDevName
+    );
+    system_property!(
+        /// The slot of the CompactDAQ chassis containing the module.
+        get compact_daq_slot_number: u32 = DAQmxGetDevCompactDAQSlotNum
+    );
+
+    system_property!(
+        /// The analog input physical channels of the device.
+        get_list ai_physical_channels = DAQmxGetDevAIPhysicalChans
+    );
+    system_property!(
+        /// The analog output physical channels of the device.
+        get_list ao_physical_channels = DAQmxGetDevAOPhysicalChans
+    );
+    system_property!(
+        /// The digital input lines of the device.
+        get_list di_lines = DAQmxGetDevDILines
+    );
+    system_property!(
+        /// The digital input ports of the device.
+        get_list di_ports = DAQmxGetDevDIPorts
+    );
+    system_property!(
+        /// The digital output lines of the device.
+        get_list do_lines = DAQmxGetDevDOLines
+    );
+    system_property!(
+        /// The digital output ports of the device.
+        get_list do_ports = DAQmxGetDevDOPorts
+    );
+    system_property!(
+        /// The counter input physical channels of the device.
+        get_list ci_physical_channels = DAQmxGetDevCIPhysicalChans
+    );
+    system_property!(
+        /// The counter output physical channels of the device.
+        get_list co_physical_channels = DAQmxGetDevCOPhysicalChans
+    );
+
+    /// The measurement types supported by the analog input channels of the device.
+    pub fn ai_supported_measurement_types(&self) -> Result<Vec<MeasurementType>> {
+        get_array(DAQmxGetDevAISupportedMeasTypes, &self.name)?
+            .into_iter()
+            .map(MeasurementType::from_raw)
+            .collect()
+    }
+
+    /// The input ranges supported by the analog input channels of the device for voltage measurements.
+    pub fn ai_voltage_ranges(&self) -> Result<Vec<InputRange>> {
+        self.ranges(DAQmxGetDevAIVoltageRngs)
+    }
+
+    /// The input ranges supported by the analog input channels of the device for current measurements.
+    pub fn ai_current_ranges(&self) -> Result<Vec<InputRange>> {
+        self.ranges(DAQmxGetDevAICurrentRngs)
+    }
+
+    /// DAQmx returns ranges as a flat array of minimum and maximum pairs.
+    fn ranges(&self, daqmx_fn: ArrayGetter<f64>) -> Result<Vec<InputRange>> {
+        Ok(get_array(daqmx_fn, &self.name)?
+            .chunks_exact(2)
+            .map(|pair| InputRange {
+                min: pair[0],
+                max: pair[1],
+            })
+            .collect())
+    }
+
+    system_property!(
+        /// The maximum analog input rate in samples per second when the task has a single channel.
+        get ai_max_single_channel_rate: f64 = DAQmxGetDevAIMaxSingleChanRate
+    );
+    system_property!(
+        /// The maximum analog input rate in samples per second for each channel when the task has
+        /// several channels.
+        get ai_max_multi_channel_rate: f64 = DAQmxGetDevAIMaxMultiChanRate
+    );
+    system_property!(
+        /// The maximum analog output rate in samples per second.
+        get ao_max_rate: f64 = DAQmxGetDevAOMaxRate
+    );
+    system_property!(
+        /// The maximum digital input rate in samples per second.
+        get di_max_rate: f64 = DAQmxGetDevDIMaxRate
+    );
+    system_property!(
+        /// The maximum digital output rate in samples per second.
+        get do_max_rate: f64 = DAQmxGetDevDOMaxRate
+    );
+}
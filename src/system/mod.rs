@@ -0,0 +1,166 @@
+//! Discovery of the hardware installed in the system.
+//!
+//! This lets you check the devices a configuration expects are present before creating tasks for them.
+//!
+//! ```no_run
+//! use daqmx::system;
+//!
+//! for device in system::devices().unwrap() {
+//!     println!("{}: {}", device.name(), device.product_type().unwrap());
+//! }
+//! ```
+
+/// Generates getters for properties of a device or physical channel held in `self.name`.
+macro_rules! system_property {
+    ($(#[$meta:meta])* get $name:ident: $ty:ty = $getter:path) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> crate::error::Result<$ty> {
+            crate::system::get_scalar($getter, &self.name)
+        }
+    };
+    ($(#[$meta:meta])* get_string $name:ident = $getter:path) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> crate::error::Result<String> {
+            crate::system::get_string($getter, &self.name)
+        }
+    };
+    ($(#[$meta:meta])* get_list $name:ident = $getter:path) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> crate::error::Result<Vec<String>> {
+            Ok(crate::system::split_list(&crate::system::get_string(
+                $getter, &self.name,
+            )?))
+        }
+    };
+}
+
+mod device;
+mod physical_channel;
+
+pub use device::{Device, InputRange};
+pub use physical_channel::PhysicalChannel;
+
+use crate::channels::properties::PropertyValue;
+use crate::error::{DaqmxError, Result, handle_error, string_property_size_error};
+use crate::types::buffer_to_string;
+use std::ffi::{CStr, c_char};
+
+/// Gets the names of all devices installed in the system, including simulated devices.
+pub fn device_names() -> Result<Vec<String>> {
+    get_system_list(crate::sys::DAQmxGetSysDevNames)
+}
+
+/// Gets all devices installed in the system, including simulated devices.
+pub fn devices() -> Result<Vec<Device>> {
+    device_names()?
+        .iter()
+        .map(|name| Device::new(name))
+        .collect()
+}
+
+// int32 DAQmxGetSysXXX(char* data, uInt32 bufferSize)
+pub(crate) type SystemListGetter = unsafe extern "C" fn(*mut c_char, u32) -> i32;
+// int32 DAQmxGetXXX(const char* name, T* data)
+pub(crate) type ScalarGetter<T> = unsafe extern "C" fn(*const c_char, *mut T) -> i32;
+// int32 DAQmxGetXXX(const char* name, char* data, uInt32 bufferSize)
+pub(crate) type StringGetter = unsafe extern "C" fn(*const c_char, *mut c_char, u32) -> i32;
+// int32 DAQmxGetXXX(const char* name, T* data, uInt32 arraySizeInElements)
+pub(crate) type ArrayGetter<T> = unsafe extern "C" fn(*const c_char, *mut T, u32) -> i32;
+
+/// Read a list property of the system, given a raw DAQmx function.
+pub(crate) fn get_system_list(daqmx_fn: SystemListGetter) -> Result<Vec<String>> {
+    //first call to get size.
+    let return_value = unsafe { daqmx_fn(std::ptr::null_mut(), 0) };
+    if return_value < 0 {
+        handle_error(return_value)?;
+    }
+
+    let buffer_size = return_value as u32;
+    let mut buffer: Vec<i8> = vec![0i8; buffer_size as usize];
+    let return_value = unsafe { daqmx_fn(buffer.as_mut_ptr(), buffer_size) };
+    if string_property_size_error(return_value)? {
+        return Err(DaqmxError::StringPropertyLengthChanged);
+    }
+
+    Ok(split_list(&buffer_to_string(buffer)))
+}
+
+/// Read a property of a named object such as a device or scale, given a raw DAQmx function.
+pub(crate) fn get_scalar<T: PropertyValue>(
+    daqmx_fn: ScalarGetter<T::Raw>,
+    name: &CStr,
+) -> Result<T> {
+    let mut value = T::Raw::default();
+    crate::daqmx_call!(daqmx_fn(name.as_ptr(), &mut value))?;
+    T::from_raw(value)
+}
+
+/// Read a string property of a named object, given a raw DAQmx function.
+pub(crate) fn get_string(daqmx_fn: StringGetter, name: &CStr) -> Result<String> {
+    //first call to get size.
+    let return_value = unsafe { daqmx_fn(name.as_ptr(), std::ptr::null_mut(), 0) };
+    if return_value < 0 {
+        handle_error(return_value)?;
+    }
+
+    let buffer_size = return_value as u32;
+    let mut buffer: Vec<i8> = vec![0i8; buffer_size as usize];
+    let return_value = unsafe { daqmx_fn(name.as_ptr(), buffer.as_mut_ptr(), buffer_size) };
+    if string_property_size_error(return_value)? {
+        return Err(DaqmxError::StringPropertyLengthChanged);
+    }
+
+    Ok(buffer_to_string(buffer))
+}
+
+/// Read an array property of a named object, given a raw DAQmx function.
+///
+/// Like strings, calling with an empty array returns the number of elements required.
+pub(crate) fn get_array<T: Default + Clone>(
+    daqmx_fn: ArrayGetter<T>,
+    name: &CStr,
+) -> Result<Vec<T>> {
+    let return_value = unsafe { daqmx_fn(name.as_ptr(), std::ptr::null_mut(), 0) };
+    if return_value < 0 {
+        handle_error(return_value)?;
+    }
+
+    let mut values = vec![T::default(); return_value as usize];
+    if values.is_empty() {
+        return Ok(values);
+    }
+    crate::daqmx_call!(daqmx_fn(
+        name.as_ptr(),
+        values.as_mut_ptr(),
+        values.len() as u32
+    ))?;
+
+    Ok(values)
+}
+
+/// Split a comma separated list returned by DAQmx into its entries.
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list("PXI1Slot2, PXI1Slot3,Dev1"),
+            vec!["PXI1Slot2", "PXI1Slot3", "Dev1"]
+        );
+    }
+
+    #[test]
+    fn test_split_empty_list() {
+        assert!(split_list("").is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! Capabilities of a single physical channel.
+
+use super::{Device, InputRange, get_array};
+use crate::channels::ai_channels::{AnalogTerminalConfig, MeasurementType};
+use crate::channels::properties::PropertyValue;
+use crate::error::{DaqmxError, Result};
+use crate::sys::*;
+use std::ffi::CString;
+
+/// A physical channel on a device, e.g. `PXI1Slot2/ai0`.
+///
+/// This is the name passed to [`ChannelBuilder::new`](crate::channels::ChannelBuilder::new),
+/// and can be used to check the channel supports a configuration before creating it.
+///
+/// The channel is not checked when it is created. If it doesn't exist, reading the properties will return an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhysicalChannel {
+    name: CString,
+}
+
+impl PhysicalChannel {
+    /// Refer to the physical channel with the given name, e.g. `PXI1Slot2/ai0`.
+    pub fn new(name: &str) -> Result<Self> {
+        Ok(Self {
+            name: CString::new(name)?,
+        })
+    }
+
+    /// The name of the physical channel.
+    pub fn name(&self) -> &str {
+        // Created from a &str so this is always valid.
+        self.name.to_str().unwrap_or_default()
+    }
+
+    /// The device the channel is on, taken from the start of the name.
+    pub fn device(&self) -> Result<Device> {
+        let name = self.name().trim_start_matches('/');
+        Device::new(name.split('/').next().unwrap_or(name))
+    }
+
+    /// The terminal configurations the channel supports for analog inputs.
+    ///
+    /// [`AnalogTerminalConfig::Default`] is always valid so it isn't included.
+    pub fn ai_terminal_configs(&self) -> Result<Vec<AnalogTerminalConfig>> {
+        let bits: i32 = crate::system::get_scalar(DAQmxGetPhysicalChanAITermCfgs, &self.name)?;
+        Ok(terminal_configs_from_bits(bits))
+    }
+
+    /// The measurement types the channel supports for analog inputs.
+    pub fn ai_supported_measurement_types(&self) -> Result<Vec<MeasurementType>> {
+        get_array(DAQmxGetPhysicalChanAISupportedMeasTypes, &self.name)?
+            .into_iter()
+            .map(MeasurementType::from_raw)
+            .collect()
+    }
+
+    /// The voltage input ranges the channel supports.
+    ///
+    /// DAQmx reports ranges for the whole device so these are read from [`Self::device`].
+    pub fn ai_voltage_ranges(&self) -> Result<Vec<InputRange>> {
+        self.device()?.ai_voltage_ranges()
+    }
+
+    /// The current input ranges the channel supports.
+    ///
+    /// DAQmx reports ranges for the whole device so these are read from [`Self::device`].
+    pub fn ai_current_ranges(&self) -> Result<Vec<InputRange>> {
+        self.device()?.ai_current_ranges()
+    }
+
+    /// Whether a TEDS sensor is connected to the channel and has been detected.
+    pub fn has_teds(&self) -> Result<bool> {
+        #[allow(non_upper_case_globals)]
+        match crate::system::get_scalar::<u32>(DAQmxGetPhysicalChanTEDSMfgID, &self.name) {
+            Ok(_) => Ok(true),
+            Err(DaqmxError::DaqmxError(DAQmxErrorTEDSSensorNotDetected, _)) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The terminal configurations are returned as a bit field.
+fn terminal_configs_from_bits(bits: i32) -> Vec<AnalogTerminalConfig> {
+    [
+        (DAQmx_Val_Bit_TermCfg_RSE, AnalogTerminalConfig::RSE),
+        (DAQmx_Val_Bit_TermCfg_NRSE, AnalogTerminalConfig::NRSE),
+        (
+            DAQmx_Val_Bit_TermCfg_Diff,
+            AnalogTerminalConfig::Differential,
+        ),
+        (
+            DAQmx_Val_Bit_TermCfg_PseudoDIFF,
+            AnalogTerminalConfig::PseudoDifferential,
+        ),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| bits & bit != 0)
+    .map(|(_, config)| config)
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_terminal_configs_from_bits() {
+        assert_eq!(
+            terminal_configs_from_bits(DAQmx_Val_Bit_TermCfg_RSE | DAQmx_Val_Bit_TermCfg_Diff),
+            vec![
+                AnalogTerminalConfig::RSE,
+                AnalogTerminalConfig::Differential
+            ]
+        );
+        assert!(terminal_configs_from_bits(0).is_empty());
+    }
+
+    #[test]
+    fn test_device_from_name() {
+        let channel = PhysicalChannel::new("/PXI1Slot2/port0/line1").unwrap();
+        assert_eq!(channel.device().unwrap().name(), "PXI1Slot2");
+    }
+}
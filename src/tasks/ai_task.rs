@@ -1,11 +1,12 @@
 use crate::channels::{ChannelBuilder, TaskChannel};
 use crate::daqmx_call;
-use crate::error::Result;
+use crate::error::{DaqmxError, Result};
 use crate::types::Timeout;
-use std::ffi::CString;
 use std::ptr;
-use crate::channels::ai_channels::AnalogInputKind;
-use super::input::{DAQmxInput, InputTask};
+use crate::channels::ai_channels::{
+    AnalogInputKind, AnyAnalogInput, AnyAnalogInputChannel, MeasurementType,
+};
+use super::input::{DAQmxInput, InputTask, SingleValueInput};
 use super::{Task, task::AnalogInput};
 
 impl Task<AnalogInput> {
@@ -16,17 +17,95 @@ impl Task<AnalogInput> {
         builder.add_to_task(self.raw_handle())
     }
 
+    /// Gets a channel in the task as the given kind.
+    ///
+    /// This checks the channel is in the task and that its measurement type matches the kind.
     pub fn get_channel<K: AnalogInputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
-        //todo: Check the channel exists and it is the correct type.
-        let name = CString::new(name)?;
-        Ok(TaskChannel::new(self.raw_handle(), name))
+        let channel: TaskChannel<K> = self.existing_channel(name)?;
+        if let Some(expected) = K::MEASUREMENT_TYPE {
+            let actual = channel.measurement_type()?;
+            if actual != expected {
+                return Err(DaqmxError::ChannelKindMismatch(
+                    name.to_owned(),
+                    expected,
+                    actual,
+                ));
+            }
+        }
+        Ok(channel)
+    }
+
+    /// Gets a channel in the task when the kind isn't known until runtime, e.g. for generic tools.
+    ///
+    /// Match on the result to use the properties of the specific kind.
+    pub fn get_any_channel(&self, name: &str) -> Result<AnyAnalogInputChannel> {
+        let channel: TaskChannel<AnyAnalogInput> = self.existing_channel(name)?;
+        let measurement_type = channel.measurement_type()?;
+        let handle = self.raw_handle();
+        let name = channel.name().to_owned();
+        let channel = match measurement_type {
+            MeasurementType::Voltage => {
+                AnyAnalogInputChannel::Voltage(TaskChannel::new(handle, name))
+            }
+            MeasurementType::VoltageRms => {
+                AnyAnalogInputChannel::VoltageRms(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Current => {
+                AnyAnalogInputChannel::Current(TaskChannel::new(handle, name))
+            }
+            MeasurementType::CurrentRms => {
+                AnyAnalogInputChannel::CurrentRms(TaskChannel::new(handle, name))
+            }
+            MeasurementType::FrequencyVoltage => {
+                AnyAnalogInputChannel::FrequencyVoltage(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Rtd => AnyAnalogInputChannel::Rtd(TaskChannel::new(handle, name)),
+            MeasurementType::Thermocouple => {
+                AnyAnalogInputChannel::Thermocouple(TaskChannel::new(handle, name))
+            }
+            MeasurementType::StrainGage => {
+                AnyAnalogInputChannel::StrainGage(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Bridge => {
+                AnyAnalogInputChannel::Bridge(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Accelerometer => {
+                AnyAnalogInputChannel::Accelerometer(TaskChannel::new(handle, name))
+            }
+            MeasurementType::SoundPressureMicrophone => {
+                AnyAnalogInputChannel::Microphone(TaskChannel::new(handle, name))
+            }
+            MeasurementType::ForceIepe => {
+                AnyAnalogInputChannel::ForceIepe(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Thermistor => {
+                AnyAnalogInputChannel::Thermistor(TaskChannel::new(handle, name))
+            }
+            MeasurementType::BuiltInTemperatureSensor => {
+                AnyAnalogInputChannel::BuiltInTemperatureSensor(TaskChannel::new(handle, name))
+            }
+            MeasurementType::Resistance => {
+                AnyAnalogInputChannel::Resistance(TaskChannel::new(handle, name))
+            }
+            MeasurementType::PressureBridge => {
+                AnyAnalogInputChannel::PressureBridge(TaskChannel::new(handle, name))
+            }
+            MeasurementType::ForceBridge => {
+                AnyAnalogInputChannel::ForceBridge(TaskChannel::new(handle, name))
+            }
+            MeasurementType::TorqueBridge => {
+                AnyAnalogInputChannel::TorqueBridge(TaskChannel::new(handle, name))
+            }
+            _ => AnyAnalogInputChannel::Other(measurement_type, channel),
+        };
+        Ok(channel)
     }
 }
 
 impl InputTask<f64> for Task<AnalogInput> {
     fn read_scalar(&mut self, timeout: Timeout) -> Result<f64> {
         let mut value = 0.0;
-        daqmx_call!(ni_daqmx_sys::DAQmxReadAnalogScalarF64(
+        daqmx_call!(crate::sys::DAQmxReadAnalogScalarF64(
             self.raw_handle(),
             timeout.into(),
             &mut value,
@@ -36,18 +115,20 @@ impl InputTask<f64> for Task<AnalogInput> {
     }
 }
 
+impl SingleValueInput<f64> for Task<AnalogInput> {}
+
 impl DAQmxInput<f64> for Task<AnalogInput> {
     unsafe fn daqmx_read(
         &mut self,
         samples_per_channel: i32,
         timeout: f64,
-        fill_mode: ni_daqmx_sys::bool32,
+        fill_mode: crate::sys::bool32,
         buffer: *mut f64,
         buffer_size: u32,
         actual_samples_per_channel: *mut i32,
     ) -> i32 {
         unsafe {
-            ni_daqmx_sys::DAQmxReadAnalogF64(
+            crate::sys::DAQmxReadAnalogF64(
                 self.raw_handle(),
                 samples_per_channel,
                 timeout,
@@ -0,0 +1,62 @@
+use crate::channels::{AnalogOutputKind, ChannelBuilder, TaskChannel};
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::types::Timeout;
+use std::ptr;
+
+use super::output::{DAQmxOutput, OutputTask};
+use super::{Task, task::AnalogOutput};
+
+impl Task<AnalogOutput> {
+    pub fn create_channel<K: AnalogOutputKind, B: ChannelBuilder<Kind = K>>(
+        &mut self,
+        builder: B,
+    ) -> Result<TaskChannel<K>> {
+        builder.add_to_task(self.raw_handle())
+    }
+
+    pub fn get_channel<K: AnalogOutputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        self.existing_channel(name)
+    }
+}
+
+impl OutputTask<f64> for Task<AnalogOutput> {
+    fn write_scalar(&mut self, auto_start: bool, timeout: Timeout, value: f64) -> Result<()> {
+        daqmx_call!(crate::sys::DAQmxWriteAnalogScalarF64(
+            self.raw_handle(),
+            auto_start as crate::sys::bool32,
+            timeout.into(),
+            value,
+            ptr::null_mut(),
+        ))
+    }
+}
+
+impl DAQmxOutput<f64> for Task<AnalogOutput> {
+    fn values_per_sample(&self) -> Result<u32> {
+        self.number_of_channels()
+    }
+
+    unsafe fn daqmx_write(
+        &mut self,
+        samples_per_channel: i32,
+        auto_start: crate::sys::bool32,
+        timeout: f64,
+        fill_mode: crate::sys::bool32,
+        buffer: *const f64,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32 {
+        unsafe {
+            crate::sys::DAQmxWriteAnalogF64(
+                self.raw_handle(),
+                samples_per_channel,
+                auto_start,
+                timeout,
+                fill_mode,
+                buffer,
+                actual_samples_per_channel,
+                ptr::null_mut(),
+            )
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Async reads for analog input tasks.
+//!
+//! Rather than blocking a thread in the DAQmx read, the every N samples event tells us when a block
+//! of samples is in the buffer. The read is then made without waiting.
+//!
+//! This is runtime agnostic so works with tokio or any other executor.
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+use super::{AnalogInput, EventRegistration, InputTask, ReadOutcome, Task};
+use crate::error::{DaqmxError, Result};
+use crate::types::{DataFillMode, EveryNSamplesEventType, Timeout};
+
+/// State shared with the DAQmx event callbacks.
+#[derive(Default)]
+struct EventState {
+    blocks_ready: u32,
+    done: bool,
+    error: Option<DaqmxError>,
+    waker: Option<Waker>,
+}
+
+impl EventState {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+enum Event {
+    Block,
+    Done(Option<DaqmxError>),
+}
+
+fn lock(state: &Mutex<EventState>) -> MutexGuard<'_, EventState> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Reads an analog input task asynchronously in blocks of samples.
+///
+/// The reader can be awaited directly with [`AsyncReader::read`] or used as a [`Stream`] of blocks,
+/// where each block is the samples of every channel grouped by channel.
+///
+/// The reader must be created before the task is started and should be dropped after it is stopped.
+/// A read won't complete if the task is stopped while it is waiting, except for a finite task
+/// completing or an error stopping the task.
+///
+/// # Example
+/// ```no_run
+/// use daqmx::channels::ChannelBuilder;
+/// use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+/// use daqmx::tasks::{AnalogInput, AsyncReader, Task};
+/// use daqmx::types::{ClockEdge, DataFillMode, SampleMode};
+///
+/// # async fn run() -> daqmx::error::Result<()> {
+/// let mut task = Task::<AnalogInput>::new("")?;
+/// task.create_channel(VoltageChannelBuilder::new("PXI1Slot2/ai0")?)?;
+/// task.configure_sample_clock_timing(None, 1000.0, ClockEdge::Rising, SampleMode::ContinuousSamples, 1000)?;
+///
+/// let mut reader = AsyncReader::new(task, 100)?;
+/// reader.task().start()?;
+/// let mut buffer = [0.0; 100];
+/// let outcome = reader.read(DataFillMode::GroupByChannel, &mut buffer).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncReader {
+    task: Task<AnalogInput>,
+    state: Arc<Mutex<EventState>>,
+    samples_per_event: u32,
+    number_of_channels: usize,
+    pending_error: Option<DaqmxError>,
+    finished: bool,
+    _samples_event: EventRegistration<AnalogInput>,
+    _done_event: EventRegistration<AnalogInput>,
+}
+
+impl AsyncReader {
+    /// Create a reader which is notified each time `samples_per_event` samples per channel are acquired.
+    ///
+    /// Some devices require the buffer size to be a multiple of `samples_per_event`.
+    pub fn new(mut task: Task<AnalogInput>, samples_per_event: u32) -> Result<Self> {
+        let number_of_channels = task.number_of_channels()? as usize;
+        let state = Arc::new(Mutex::new(EventState::default()));
+
+        let samples_state = state.clone();
+        let samples_event = task.register_every_n_samples_event(
+            EveryNSamplesEventType::AcquiredIntoBuffer,
+            samples_per_event,
+            move |_| {
+                let mut state = lock(&samples_state);
+                state.blocks_ready += 1;
+                state.wake();
+            },
+        )?;
+
+        let done_state = state.clone();
+        let done_event = task.register_done_event(move |status| {
+            let mut state = lock(&done_state);
+            state.done = true;
+            state.error = status.err();
+            state.wake();
+        })?;
+
+        Ok(Self {
+            task,
+            state,
+            samples_per_event,
+            number_of_channels,
+            pending_error: None,
+            finished: false,
+            _samples_event: samples_event,
+            _done_event: done_event,
+        })
+    }
+
+    /// The task being read from. Use this to start and stop the task.
+    pub fn task(&mut self) -> &mut Task<AnalogInput> {
+        &mut self.task
+    }
+
+    /// The number of samples per channel in each block.
+    pub fn samples_per_event(&self) -> u32 {
+        self.samples_per_event
+    }
+
+    /// Wait for the next block of samples and read it into the buffer.
+    ///
+    /// The buffer should hold [`AsyncReader::samples_per_event`] * the number of channels.
+    /// Once a finite task is done, this reads any remaining samples.
+    pub async fn read(
+        &mut self,
+        fill_mode: DataFillMode,
+        buffer: &mut [f64],
+    ) -> Result<ReadOutcome> {
+        let event = poll_fn(|cx| self.poll_event(cx)).await;
+        self.read_event(event, fill_mode, buffer)
+    }
+
+    fn poll_event(&self, cx: &mut Context<'_>) -> Poll<Event> {
+        let mut state = lock(&self.state);
+        if state.blocks_ready > 0 {
+            state.blocks_ready -= 1;
+            return Poll::Ready(Event::Block);
+        }
+        if state.done {
+            return Poll::Ready(Event::Done(state.error.take()));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn read_event(
+        &mut self,
+        event: Event,
+        fill_mode: DataFillMode,
+        buffer: &mut [f64],
+    ) -> Result<ReadOutcome> {
+        let samples_per_channel = match event {
+            Event::Block => Some(self.samples_per_event),
+            Event::Done(Some(error)) => return Err(error),
+            Event::Done(None) => None,
+        };
+        self.task
+            .read(Timeout::NoWait, fill_mode, samples_per_channel, buffer)
+    }
+}
+
+impl Stream for AsyncReader {
+    type Item = Result<Vec<f64>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(error) = this.pending_error.take() {
+            return Poll::Ready(Some(Err(error)));
+        }
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        let event = match this.poll_event(cx) {
+            Poll::Ready(event) => event,
+            Poll::Pending => return Poll::Pending,
+        };
+        this.finished = matches!(event, Event::Done(_));
+
+        let mut buffer = vec![0.0; this.samples_per_event as usize * this.number_of_channels];
+        match this.read_event(event, DataFillMode::GroupByChannel, &mut buffer) {
+            Ok(outcome) if this.finished && outcome.samples_per_channel == 0 => Poll::Ready(None),
+            Ok(outcome) => {
+                buffer.truncate(outcome.samples_per_channel as usize * this.number_of_channels);
+                this.pending_error = outcome.error;
+                Poll::Ready(Some(Ok(buffer)))
+            }
+            Err(error) => Poll::Ready(Some(Err(error))),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::channels::{ChannelBuilder, CounterInputKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::types::Timeout;
+use std::ptr;
+
+use super::input::{DAQmxInput, InputTask, SingleValueInput};
+use super::{Task, task::CounterInput};
+
+impl Task<CounterInput> {
+    pub fn create_channel<K: CounterInputKind, B: ChannelBuilder<Kind = K>>(
+        &mut self,
+        builder: B,
+    ) -> Result<TaskChannel<K>> {
+        builder.add_to_task(self.raw_handle())
+    }
+
+    pub fn get_channel<K: CounterInputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        self.existing_channel(name)
+    }
+}
+
+/// Reads the measurement in the channel units, e.g. Hz for a frequency channel.
+impl InputTask<f64> for Task<CounterInput> {
+    fn read_scalar(&mut self, timeout: Timeout) -> Result<f64> {
+        let mut value = 0.0;
+        daqmx_call!(crate::sys::DAQmxReadCounterScalarF64(
+            self.raw_handle(),
+            timeout.into(),
+            &mut value,
+            ptr::null_mut(),
+        ))?;
+        Ok(value)
+    }
+}
+
+impl SingleValueInput<f64> for Task<CounterInput> {}
+
+impl DAQmxInput<f64> for Task<CounterInput> {
+    /// Counter reads have no data layout since a task only has a single channel so `fill_mode` is ignored.
+    unsafe fn daqmx_read(
+        &mut self,
+        samples_per_channel: i32,
+        timeout: f64,
+        _fill_mode: crate::sys::bool32,
+        buffer: *mut f64,
+        buffer_size: u32,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32 {
+        unsafe {
+            crate::sys::DAQmxReadCounterF64(
+                self.raw_handle(),
+                samples_per_channel,
+                timeout,
+                buffer,
+                buffer_size,
+                actual_samples_per_channel,
+                ptr::null_mut(),
+            )
+        }
+    }
+}
+
+/// Reads the raw count of the counter, e.g. for edge counting.
+impl InputTask<u32> for Task<CounterInput> {
+    fn read_scalar(&mut self, timeout: Timeout) -> Result<u32> {
+        let mut value = 0;
+        daqmx_call!(crate::sys::DAQmxReadCounterScalarU32(
+            self.raw_handle(),
+            timeout.into(),
+            &mut value,
+            ptr::null_mut(),
+        ))?;
+        Ok(value)
+    }
+}
+
+impl SingleValueInput<u32> for Task<CounterInput> {}
+
+impl DAQmxInput<u32> for Task<CounterInput> {
+    /// Counter reads have no data layout since a task only has a single channel so `fill_mode` is ignored.
+    unsafe fn daqmx_read(
+        &mut self,
+        samples_per_channel: i32,
+        timeout: f64,
+        _fill_mode: crate::sys::bool32,
+        buffer: *mut u32,
+        buffer_size: u32,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32 {
+        unsafe {
+            crate::sys::DAQmxReadCounterU32(
+                self.raw_handle(),
+                samples_per_channel,
+                timeout,
+                buffer,
+                buffer_size,
+                actual_samples_per_channel,
+                ptr::null_mut(),
+            )
+        }
+    }
+}
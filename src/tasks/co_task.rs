@@ -0,0 +1,17 @@
+use crate::channels::{ChannelBuilder, CounterOutputKind, TaskChannel};
+use crate::error::Result;
+
+use super::{Task, task::CounterOutput};
+
+impl Task<CounterOutput> {
+    pub fn create_channel<K: CounterOutputKind, B: ChannelBuilder<Kind = K>>(
+        &mut self,
+        builder: B,
+    ) -> Result<TaskChannel<K>> {
+        builder.add_to_task(self.raw_handle())
+    }
+
+    pub fn get_channel<K: CounterOutputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        self.existing_channel(name)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::channels::{ChannelBuilder, DigitalInputKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::types::{DataFillMode, Timeout};
+use std::ptr;
+
+use super::input::{DAQmxInput, InputTask, SingleValueInput};
+use super::{Task, task::DigitalInput};
+
+impl Task<DigitalInput> {
+    pub fn create_channel<K: DigitalInputKind, B: ChannelBuilder<Kind = K>>(
+        &mut self,
+        builder: B,
+    ) -> Result<TaskChannel<K>> {
+        builder.add_to_task(self.raw_handle())
+    }
+
+    pub fn get_channel<K: DigitalInputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        self.existing_channel(name)
+    }
+}
+
+/// Implements the port based reads for each integer width.
+///
+/// The scalar read uses the array read for a single sample so that it is the correct width.
+macro_rules! digital_port_input {
+    ($t:ty, $read:path) => {
+        impl InputTask<$t> for Task<DigitalInput> {
+            fn read_scalar(&mut self, timeout: Timeout) -> Result<$t> {
+                let mut value: [$t; 1] = [0];
+                self.read(timeout, DataFillMode::GroupByChannel, Some(1), &mut value)?
+                    .into_result()?;
+                Ok(value[0])
+            }
+        }
+
+        impl SingleValueInput<$t> for Task<DigitalInput> {}
+
+        impl DAQmxInput<$t> for Task<DigitalInput> {
+            unsafe fn daqmx_read(
+                &mut self,
+                samples_per_channel: i32,
+                timeout: f64,
+                fill_mode: crate::sys::bool32,
+                buffer: *mut $t,
+                buffer_size: u32,
+                actual_samples_per_channel: *mut i32,
+            ) -> i32 {
+                unsafe {
+                    $read(
+                        self.raw_handle(),
+                        samples_per_channel,
+                        timeout,
+                        fill_mode,
+                        buffer,
+                        buffer_size,
+                        actual_samples_per_channel,
+                        ptr::null_mut(),
+                    )
+                }
+            }
+        }
+    };
+}
+
+digital_port_input!(u8, crate::sys::DAQmxReadDigitalU8);
+digital_port_input!(u16, crate::sys::DAQmxReadDigitalU16);
+digital_port_input!(u32, crate::sys::DAQmxReadDigitalU32);
+
+/// Per-line reads. Each line in each channel produces one value per sample.
+impl InputTask<bool> for Task<DigitalInput> {
+    fn read_scalar(&mut self, timeout: Timeout) -> Result<bool> {
+        let mut value = 0u8;
+        let mut samples_read = 0;
+        let mut bytes_per_sample = 0;
+        daqmx_call!(crate::sys::DAQmxReadDigitalLines(
+            self.raw_handle(),
+            1,
+            timeout.into(),
+            DataFillMode::GroupByChannel.into(),
+            &mut value,
+            1,
+            &mut samples_read,
+            &mut bytes_per_sample,
+            ptr::null_mut(),
+        ))?;
+        Ok(value != 0)
+    }
+}
+
+impl DAQmxInput<bool> for Task<DigitalInput> {
+    unsafe fn daqmx_read(
+        &mut self,
+        samples_per_channel: i32,
+        timeout: f64,
+        fill_mode: crate::sys::bool32,
+        buffer: *mut bool,
+        buffer_size: u32,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32 {
+        // DAQmx reads lines as bytes but we can't guarantee they are valid bools
+        // so read into a temporary buffer and convert.
+        let mut lines = vec![0u8; buffer_size as usize];
+        let mut bytes_per_sample = 0;
+        let return_code = unsafe {
+            crate::sys::DAQmxReadDigitalLines(
+                self.raw_handle(),
+                samples_per_channel,
+                timeout,
+                fill_mode,
+                lines.as_mut_ptr(),
+                buffer_size,
+                actual_samples_per_channel,
+                &mut bytes_per_sample,
+                ptr::null_mut(),
+            )
+        };
+        for (index, line) in lines.into_iter().enumerate() {
+            unsafe { buffer.add(index).write(line != 0) };
+        }
+        return_code
+    }
+}
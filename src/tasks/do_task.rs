@@ -0,0 +1,118 @@
+use crate::channels::{ChannelBuilder, DigitalOutputKind, TaskChannel};
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::types::{DataFillMode, Timeout};
+use std::ptr;
+
+use super::output::{DAQmxOutput, OutputTask};
+use super::{Task, task::DigitalOutput};
+
+impl Task<DigitalOutput> {
+    pub fn create_channel<K: DigitalOutputKind, B: ChannelBuilder<Kind = K>>(
+        &mut self,
+        builder: B,
+    ) -> Result<TaskChannel<K>> {
+        builder.add_to_task(self.raw_handle())
+    }
+
+    pub fn get_channel<K: DigitalOutputKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        self.existing_channel(name)
+    }
+}
+
+/// Implements the port based writes for each integer width.
+///
+/// The scalar write uses the U32 scalar write since the value is just widened.
+macro_rules! digital_port_output {
+    ($t:ty, $write:path) => {
+        impl OutputTask<$t> for Task<DigitalOutput> {
+            fn write_scalar(&mut self, auto_start: bool, timeout: Timeout, value: $t) -> Result<()> {
+                daqmx_call!(crate::sys::DAQmxWriteDigitalScalarU32(
+                    self.raw_handle(),
+                    auto_start as crate::sys::bool32,
+                    timeout.into(),
+                    value.into(),
+                    ptr::null_mut(),
+                ))
+            }
+        }
+
+        impl DAQmxOutput<$t> for Task<DigitalOutput> {
+            fn values_per_sample(&self) -> Result<u32> {
+                self.number_of_channels()
+            }
+
+            unsafe fn daqmx_write(
+                &mut self,
+                samples_per_channel: i32,
+                auto_start: crate::sys::bool32,
+                timeout: f64,
+                fill_mode: crate::sys::bool32,
+                buffer: *const $t,
+                actual_samples_per_channel: *mut i32,
+            ) -> i32 {
+                unsafe {
+                    $write(
+                        self.raw_handle(),
+                        samples_per_channel,
+                        auto_start,
+                        timeout,
+                        fill_mode,
+                        buffer,
+                        actual_samples_per_channel,
+                        ptr::null_mut(),
+                    )
+                }
+            }
+        }
+    };
+}
+
+digital_port_output!(u8, crate::sys::DAQmxWriteDigitalU8);
+digital_port_output!(u16, crate::sys::DAQmxWriteDigitalU16);
+digital_port_output!(u32, crate::sys::DAQmxWriteDigitalU32);
+
+/// Per-line writes. Each line in each channel takes one value per sample.
+impl OutputTask<bool> for Task<DigitalOutput> {
+    /// Writes a single line. This will error if the task contains more than one line.
+    fn write_scalar(&mut self, auto_start: bool, timeout: Timeout, value: bool) -> Result<()> {
+        self.write(auto_start, timeout, DataFillMode::GroupByChannel, &[value])?;
+        Ok(())
+    }
+}
+
+impl DAQmxOutput<bool> for Task<DigitalOutput> {
+    /// For line writes each channel takes a value for each of its lines.
+    fn values_per_sample(&self) -> Result<u32> {
+        let mut lines_per_channel = 0;
+        daqmx_call!(crate::sys::DAQmxGetWriteDigitalLinesBytesPerChan(
+            self.raw_handle(),
+            &mut lines_per_channel
+        ))?;
+        Ok(self.number_of_channels()? * lines_per_channel)
+    }
+
+    unsafe fn daqmx_write(
+        &mut self,
+        samples_per_channel: i32,
+        auto_start: crate::sys::bool32,
+        timeout: f64,
+        fill_mode: crate::sys::bool32,
+        buffer: *const bool,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32 {
+        // bool has the same layout as u8 with values of 0 or 1 which is what DAQmx expects.
+        unsafe {
+            crate::sys::DAQmxWriteDigitalLines(
+                self.raw_handle(),
+                samples_per_channel,
+                auto_start,
+                timeout,
+                fill_mode,
+                buffer as *const u8,
+                actual_samples_per_channel,
+                ptr::null_mut(),
+            )
+        }
+    }
+}
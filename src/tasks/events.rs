@@ -0,0 +1,265 @@
+//! Registration of Rust closures for the DAQmx task events.
+//!
+//! The closure is boxed and passed to DAQmx as the callback data. It is freed when the
+//! [`EventRegistration`] is dropped. If DAQmx refuses to unregister it because the task is running,
+//! it is kept until the task is stopped with [`Task::stop`] or cleared.
+use std::any::Any;
+use std::ffi::c_void;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::ptr;
+use std::sync::Mutex;
+
+use crate::sys::TaskHandle;
+
+use super::Task;
+use crate::error::{Result, handle_error};
+use crate::types::{EveryNSamplesEventType, Signal};
+
+/// The event a closure is registered for. Used to unregister it.
+#[derive(Clone, Copy)]
+pub(super) enum Event {
+    EveryNSamples(EveryNSamplesEventType, u32),
+    Done,
+    Signal(Signal),
+}
+
+/// A registered event callback. The callback is unregistered when this is dropped.
+///
+/// DAQmx only allows registering or unregistering events while the task is not running.
+/// If unregistering fails the callback may still be called, so it is kept until the task is
+/// stopped with [`Task::stop`] or cleared.
+///
+/// If the closure holds a clone of the task, the task can't be cleared while the closure is kept.
+/// Dropping the registration while the task is running and never stopping the task leaks both.
+#[must_use = "the callback is unregistered when the registration is dropped"]
+pub struct EventRegistration<TYPE> {
+    task: Task<TYPE>,
+    event: Event,
+    callback: Option<Box<dyn Any + Send>>,
+}
+
+impl<TYPE> EventRegistration<TYPE> {
+    /// Unregister the callback, returning any error from DAQmx.
+    ///
+    /// On error the callback is kept until the task is stopped with [`Task::stop`] or cleared.
+    pub fn unregister(mut self) -> Result<()> {
+        self.unregister_callback()
+    }
+
+    fn unregister_callback(&mut self) -> Result<()> {
+        let Some(callback) = self.callback.take() else {
+            return Ok(());
+        };
+
+        let result = self.event.unregister(self.task.raw_handle());
+        if result.is_err() {
+            // DAQmx may still call back so the closure must live until the task is stopped.
+            self.task.park_callback(ParkedCallback {
+                event: self.event,
+                _callback: callback,
+            });
+        }
+        result
+    }
+}
+
+impl Event {
+    /// Register no callback for the event, which removes the existing one.
+    fn unregister(&self, handle: TaskHandle) -> Result<()> {
+        let return_code = unsafe {
+            match *self {
+                Event::EveryNSamples(event_type, samples) => {
+                    crate::sys::DAQmxRegisterEveryNSamplesEvent(
+                        handle,
+                        event_type.into(),
+                        samples,
+                        0,
+                        None,
+                        ptr::null_mut(),
+                    )
+                }
+                Event::Done => crate::sys::DAQmxRegisterDoneEvent(handle, 0, None, ptr::null_mut()),
+                Event::Signal(signal) => crate::sys::DAQmxRegisterSignalEvent(
+                    handle,
+                    signal.into(),
+                    0,
+                    None,
+                    ptr::null_mut(),
+                ),
+            }
+        };
+        handle_error(return_code)
+    }
+}
+
+/// A callback which DAQmx refused to unregister, kept with the task until it can be.
+pub(super) struct ParkedCallback {
+    event: Event,
+    /// Only held to be freed once DAQmx can no longer call it.
+    _callback: Box<dyn Any + Send>,
+}
+
+impl<TYPE> Task<TYPE> {
+    /// Retry unregistering the parked callbacks, such as once the task is stopped.
+    ///
+    /// The callbacks which unregister are dropped. This breaks the reference cycle if they hold a
+    /// clone of this task.
+    pub(super) fn release_parked_callbacks(&self) {
+        let handle = self.raw_handle();
+        let released: Vec<ParkedCallback> = {
+            let mut parked = self.parked_callbacks();
+            let (released, kept) = std::mem::take(&mut *parked)
+                .into_iter()
+                .partition(|parked| parked.event.unregister(handle).is_ok());
+            *parked = kept;
+            released
+        };
+        // Dropped outside the lock as a callback may hold the last other clone of the task.
+        drop(released);
+    }
+}
+
+impl<TYPE> Drop for EventRegistration<TYPE> {
+    fn drop(&mut self) {
+        let _ = self.unregister_callback();
+    }
+}
+
+/// Call the boxed closure from the callback data, without letting a panic unwind into DAQmx.
+///
+/// # Safety
+///
+/// `data` must point to a live `Mutex<F>`.
+unsafe fn with_callback<F>(data: *mut c_void, invoke: impl FnOnce(&mut F)) {
+    let callback = unsafe { &*(data as *const Mutex<F>) };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let mut callback = callback
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        invoke(&mut callback);
+    }));
+}
+
+unsafe extern "C" fn every_n_samples_callback<F: FnMut(u32)>(
+    _task: TaskHandle,
+    _event_type: i32,
+    samples: u32,
+    data: *mut c_void,
+) -> i32 {
+    unsafe { with_callback::<F>(data, |callback| callback(samples)) };
+    0
+}
+
+unsafe extern "C" fn done_callback<F: FnMut(Result<()>)>(
+    _task: TaskHandle,
+    status: i32,
+    data: *mut c_void,
+) -> i32 {
+    unsafe { with_callback::<F>(data, |callback| callback(handle_error(status))) };
+    0
+}
+
+unsafe extern "C" fn signal_callback<F: FnMut()>(
+    _task: TaskHandle,
+    _signal: i32,
+    data: *mut c_void,
+) -> i32 {
+    unsafe { with_callback::<F>(data, |callback| callback()) };
+    0
+}
+
+impl<TYPE: Clone> Task<TYPE> {
+    /// Register the closure to box and pass to DAQmx and build the registration for it.
+    fn register_event<F: Send + 'static>(
+        &self,
+        event: Event,
+        callback: F,
+        register: impl FnOnce(*mut c_void) -> i32,
+    ) -> Result<EventRegistration<TYPE>> {
+        let callback = Box::new(Mutex::new(callback));
+        let data = &*callback as *const Mutex<F> as *mut c_void;
+        handle_error(register(data))?;
+
+        Ok(EventRegistration {
+            task: self.clone(),
+            event,
+            callback: Some(callback),
+        })
+    }
+
+    /// Call the closure each time `samples` samples are acquired into, or transferred from, the buffer.
+    ///
+    /// The closure is given the number of samples and is called from a DAQmx thread.
+    /// This must be registered before the task is started.
+    ///
+    /// Stop the task before dropping the registration if the closure holds a clone of the task,
+    /// otherwise both are leaked. See [`EventRegistration`].
+    pub fn register_every_n_samples_event<F>(
+        &mut self,
+        event_type: EveryNSamplesEventType,
+        samples: u32,
+        callback: F,
+    ) -> Result<EventRegistration<TYPE>>
+    where
+        F: FnMut(u32) + Send + 'static,
+    {
+        let handle = self.raw_handle();
+        self.register_event(
+            Event::EveryNSamples(event_type, samples),
+            callback,
+            |data| unsafe {
+                crate::sys::DAQmxRegisterEveryNSamplesEvent(
+                    handle,
+                    event_type.into(),
+                    samples,
+                    0,
+                    Some(every_n_samples_callback::<F>),
+                    data,
+                )
+            },
+        )
+    }
+
+    /// Call the closure when the task stops, either because it completed or because of an error.
+    ///
+    /// The closure is given the status of the task and is called from a DAQmx thread.
+    /// It is not called when the task is stopped with [`Task::stop`].
+    ///
+    /// Stop the task before dropping the registration if the closure holds a clone of the task,
+    /// otherwise both are leaked. See [`EventRegistration`].
+    pub fn register_done_event<F>(&mut self, callback: F) -> Result<EventRegistration<TYPE>>
+    where
+        F: FnMut(Result<()>) + Send + 'static,
+    {
+        let handle = self.raw_handle();
+        self.register_event(Event::Done, callback, |data| unsafe {
+            crate::sys::DAQmxRegisterDoneEvent(handle, 0, Some(done_callback::<F>), data)
+        })
+    }
+
+    /// Call the closure each time the hardware signal occurs.
+    ///
+    /// The closure is called from a DAQmx thread.
+    ///
+    /// Stop the task before dropping the registration if the closure holds a clone of the task,
+    /// otherwise both are leaked. See [`EventRegistration`].
+    pub fn register_signal_event<F>(
+        &mut self,
+        signal: Signal,
+        callback: F,
+    ) -> Result<EventRegistration<TYPE>>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let handle = self.raw_handle();
+        self.register_event(Event::Signal(signal), callback, |data| unsafe {
+            crate::sys::DAQmxRegisterSignalEvent(
+                handle,
+                signal.into(),
+                0,
+                Some(signal_callback::<F>),
+                data,
+            )
+        })
+    }
+}
@@ -1,13 +1,39 @@
 /// Provides traits around input task behaviours - notably reading.
 ///
-/// In future it may expose a reader struct for managing the buffers and providing
+/// See [`Reader`](super::Reader) for managing the buffers and providing
 /// the different data representations for us.
-use ni_daqmx_sys::bool32;
+use crate::sys::bool32;
 
-use crate::daqmx_call;
-use crate::error::Result;
+use crate::error::{DaqmxError, Result, handle_error};
 use crate::types::{DataFillMode, Timeout};
 
+/// The outcome of a read where samples were read.
+///
+/// DAQmx can return an error such as a timeout (-200284) or a buffer overflow after filling part of the buffer.
+/// In this case the samples are still valid so the error is returned alongside them rather than discarding them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReadOutcome {
+    /// The number of samples read for each channel.
+    pub samples_per_channel: i32,
+    /// The error reported by DAQmx after reading the samples, if any.
+    pub error: Option<DaqmxError>,
+}
+
+impl ReadOutcome {
+    /// True if the read completed without an error.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Convert to a result which discards the samples read if there was an error.
+    pub fn into_result(self) -> Result<i32> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.samples_per_channel),
+        }
+    }
+}
+
 pub trait InputTask<T>: DAQmxInput<T> {
     /// Read a single value from the task with the given timeout.
     fn read_scalar(&mut self, timeout: Timeout) -> Result<T>;
@@ -24,14 +50,17 @@ pub trait InputTask<T>: DAQmxInput<T> {
     ///
     /// The buffer should be large enough to contain the number of samples * the number of channels that you want to read.
     ///
-    /// todo: If we timeout we may still read samples - that needs to be expressed with a more complicated return type.
+    /// # Errors
+    ///
+    /// If DAQmx reports an error but some samples were read, such as on a timeout, this returns [`Ok`]
+    /// with the error in the [`ReadOutcome`]. If no samples were read the error is returned directly.
     fn read(
         &mut self,
         timeout: Timeout,
         fill_mode: DataFillMode,
         samples_per_channel: Option<u32>,
         buffer: &mut [T],
-    ) -> Result<i32> {
+    ) -> Result<ReadOutcome> {
         let mut actual_samples_per_channel = 0;
         let requested_samples_per_channel = match samples_per_channel {
             Some(val) => val as i32,
@@ -41,19 +70,34 @@ pub trait InputTask<T>: DAQmxInput<T> {
         // If it is larger, this will still be memory safe.
         let buffer_length = buffer.len().try_into().unwrap_or(u32::MAX);
 
-        daqmx_call!(self.daqmx_read(
-            requested_samples_per_channel,
-            timeout.into(),
-            fill_mode.into(),
-            buffer.as_mut_ptr(),
-            buffer_length,
-            &mut actual_samples_per_channel as *mut i32
-        ))?;
+        let return_code = unsafe {
+            self.daqmx_read(
+                requested_samples_per_channel,
+                timeout.into(),
+                fill_mode.into(),
+                buffer.as_mut_ptr(),
+                buffer_length,
+                &mut actual_samples_per_channel as *mut i32,
+            )
+        };
 
-        Ok(actual_samples_per_channel)
+        match handle_error(return_code) {
+            Err(error) if actual_samples_per_channel <= 0 => Err(error),
+            result => Ok(ReadOutcome {
+                samples_per_channel: actual_samples_per_channel,
+                error: result.err(),
+            }),
+        }
     }
 }
 
+/// Marks reads which give a single value for each channel in each sample, so the data can be laid
+/// out by channel and sample as in a [`Reader`](super::Reader).
+///
+/// Line reads of digital channels don't qualify as a channel with several lines gives a value
+/// for each line.
+pub trait SingleValueInput<T>: InputTask<T> {}
+
 pub trait DAQmxInput<T> {
     /// A basic wrapper for the daqmx read function so that implementers don't have to repeat common setup for input task.
     unsafe fn daqmx_read(
@@ -1,6 +1,23 @@
 mod ai_task;
+#[cfg(feature = "async")]
+mod async_reader;
+mod ao_task;
+mod ci_task;
+mod co_task;
+mod di_task;
+mod do_task;
+mod events;
 mod input;
+mod output;
+mod persistence;
+mod reader;
 mod task;
+mod triggers;
 
+#[cfg(feature = "async")]
+pub use async_reader::*;
+pub use events::*;
 pub use input::*;
+pub use output::*;
+pub use reader::*;
 pub use task::*;
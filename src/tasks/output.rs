@@ -0,0 +1,71 @@
+/// Provides traits around output task behaviours - notably writing.
+use crate::sys::bool32;
+
+use crate::daqmx_call;
+use crate::error::{DaqmxError, Result};
+use crate::types::{DataFillMode, Timeout};
+
+pub trait OutputTask<T>: DAQmxOutput<T> {
+    /// Write a single value to the task with the given timeout.
+    ///
+    /// If `auto_start` is true then the task will be started if it is not already running.
+    fn write_scalar(&mut self, auto_start: bool, timeout: Timeout, value: T) -> Result<()>;
+
+    /// Writes an array of samples to the task where the array can hold multiple channels and/or multiple samples.
+    ///
+    /// The samples per channel is determined from the length of the buffer and the number of values in each sample,
+    /// which is normally the number of channels in the task. The buffer length must be a multiple of this.
+    ///
+    /// If `auto_start` is true then the task will be started if it is not already running.
+    ///
+    /// Returns the number of samples per channel that were actually written.
+    fn write(
+        &mut self,
+        auto_start: bool,
+        timeout: Timeout,
+        fill_mode: DataFillMode,
+        buffer: &[T],
+    ) -> Result<i32> {
+        let channels = self.values_per_sample()?;
+        if channels == 0 || !buffer.len().is_multiple_of(channels as usize) {
+            return Err(DaqmxError::WriteBufferSize(buffer.len(), channels));
+        }
+        let samples_per_channel = (buffer.len() / channels as usize)
+            .try_into()
+            .map_err(|_| DaqmxError::WriteBufferSize(buffer.len(), channels))?;
+        let mut actual_samples_per_channel = 0;
+
+        daqmx_call!(self.daqmx_write(
+            samples_per_channel,
+            auto_start as bool32,
+            timeout.into(),
+            fill_mode.into(),
+            buffer.as_ptr(),
+            &mut actual_samples_per_channel as *mut i32
+        ))?;
+
+        Ok(actual_samples_per_channel)
+    }
+}
+
+pub trait DAQmxOutput<T> {
+    /// The number of values in a single sample of the task. Used to determine the samples per channel of a write.
+    ///
+    /// For most tasks this is the number of channels.
+    fn values_per_sample(&self) -> Result<u32>;
+
+    /// A basic wrapper for the daqmx write function so that implementers don't have to repeat common setup for output tasks.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must contain at least `samples_per_channel` * [`DAQmxOutput::values_per_sample`] values.
+    unsafe fn daqmx_write(
+        &mut self,
+        samples_per_channel: i32,
+        auto_start: bool32,
+        timeout: f64,
+        fill_mode: bool32,
+        buffer: *const T,
+        actual_samples_per_channel: *mut i32,
+    ) -> i32;
+}
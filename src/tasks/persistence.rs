@@ -0,0 +1,63 @@
+//! Saving tasks to the MAX configuration and loading saved tasks.
+use crate::daqmx_call;
+use crate::error::{DaqmxError, Result};
+use crate::persistence::{RawSaveOptions, SaveOptions};
+use std::ffi::CString;
+use std::ptr;
+
+use super::{Task, TaskType};
+
+impl<TYPE> Task<TYPE> {
+    /// Save the task and its channels to MAX, using the task name unless `save_as` is set.
+    pub fn save(&self, options: &SaveOptions) -> Result<()> {
+        let options = RawSaveOptions::try_from(options)?;
+        daqmx_call!(crate::sys::DAQmxSaveTask(
+            self.raw_handle(),
+            options.save_as.as_ptr(),
+            options.author.as_ptr(),
+            options.options
+        ))
+    }
+
+    /// Save a channel in the task to MAX as a global channel, using the channel name unless `save_as` is set.
+    pub fn save_global_channel(&self, channel: &str, options: &SaveOptions) -> Result<()> {
+        let channel = CString::new(channel)?;
+        let options = RawSaveOptions::try_from(options)?;
+        daqmx_call!(crate::sys::DAQmxSaveGlobalChan(
+            self.raw_handle(),
+            channel.as_ptr(),
+            options.save_as.as_ptr(),
+            options.author.as_ptr(),
+            options.options
+        ))
+    }
+}
+
+impl<TYPE: TaskType> Task<TYPE> {
+    /// Load a task saved in MAX, including its channels and timing.
+    ///
+    /// The saved task must contain channels of the type of this task, e.g. analog inputs for a
+    /// `Task<AnalogInput>`. Otherwise this returns [`DaqmxError::TaskTypeMismatch`].
+    pub fn load(name: &str) -> Result<Self> {
+        let c_name = CString::new(name)?;
+        let mut handle = ptr::null_mut();
+        daqmx_call!(crate::sys::DAQmxLoadTask(c_name.as_ptr(), &mut handle))?;
+        let task = Self::from_raw_handle(handle);
+
+        // DAQmx tasks only contain a single type of channel, so checking the first is enough.
+        if let Some(channel) = task.channel_names()?.first() {
+            let channel = CString::new(channel.as_str())?;
+            let mut channel_type = 0;
+            daqmx_call!(crate::sys::DAQmxGetChanType(
+                task.raw_handle(),
+                channel.as_ptr(),
+                &mut channel_type
+            ))?;
+            if channel_type != TYPE::CHANNEL_TYPE {
+                return Err(DaqmxError::TaskTypeMismatch(name.to_owned()));
+            }
+        }
+
+        Ok(task)
+    }
+}
@@ -0,0 +1,417 @@
+//! A reader for input tasks which manages the read buffer and the layout of the data.
+//!
+//! [`InputTask::read`] leaves it to the caller to size the buffer and work out where each
+//! channel's data lies in it. The [`Reader`] owns a buffer sized for the task and returns
+//! the data as a [`Waveform`] which can be viewed by channel or by sample.
+use crate::sys::{DAQmx_Val_SampClk, uInt64};
+
+use crate::daqmx_call;
+use crate::error::{DaqmxError, Result};
+use crate::types::{DataFillMode, Timeout};
+
+use super::{InputTask, SingleValueInput, Task};
+
+/// Reads multiple channels and samples from a task into a reusable buffer.
+///
+/// The channels are read when the reader is created, so create it once all channels are added to the task.
+///
+/// The reader supports reads with a single value per channel in each sample, which excludes line
+/// reads of digital tasks. See [`SingleValueInput`].
+///
+/// # Example
+/// ```no_run
+/// use daqmx::channels::ChannelBuilder;
+/// use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+/// use daqmx::tasks::{AnalogInput, Reader, Task};
+/// use daqmx::types::Timeout;
+///
+/// let mut task = Task::<AnalogInput>::new("").unwrap();
+/// task.create_channel(VoltageChannelBuilder::new("PXI1Slot2/ai0:1").unwrap()).unwrap();
+///
+/// let mut reader: Reader<_, f64> = Reader::new(task, 100).unwrap();
+/// let waveform = reader.read(Timeout::Seconds(1.0), Some(10)).unwrap();
+/// for (name, data) in waveform.channels() {
+///     println!("{name}: {data:?}");
+/// }
+/// ```
+pub struct Reader<TYPE, T> {
+    task: Task<TYPE>,
+    buffer: Vec<T>,
+    channel_names: Vec<String>,
+    samples_per_channel: usize,
+    error: Option<DaqmxError>,
+}
+
+impl<TYPE, T> Reader<TYPE, T>
+where
+    Task<TYPE>: SingleValueInput<T>,
+    T: Copy + Default,
+{
+    /// Create a reader for the task with a buffer large enough for `samples_per_channel` samples of every channel.
+    pub fn new(task: Task<TYPE>, samples_per_channel: usize) -> Result<Self> {
+        let channel_names = task.channel_names()?;
+        let buffer = vec![T::default(); channel_names.len() * samples_per_channel];
+        Ok(Self {
+            task,
+            buffer,
+            channel_names,
+            samples_per_channel,
+            error: None,
+        })
+    }
+
+    /// The task being read from. Use this to start and stop the task.
+    pub fn task(&mut self) -> &mut Task<TYPE> {
+        &mut self.task
+    }
+
+    /// The names of the channels in the task.
+    pub fn channel_names(&self) -> &[String] {
+        &self.channel_names
+    }
+
+    /// The number of channels in the task.
+    pub fn number_of_channels(&self) -> usize {
+        self.channel_names.len()
+    }
+
+    /// The maximum number of samples per channel that can be read at once.
+    pub fn capacity(&self) -> usize {
+        self.samples_per_channel
+    }
+
+    /// Read from the task into the buffer.
+    ///
+    /// `samples_per_channel` is limited to the [`Reader::capacity`]. If it is [`None`] the reader
+    /// waits to fill its buffer with [`Reader::capacity`] samples per channel, rather than reading
+    /// everything available as [`InputTask::read`] does, which may not fit in the buffer.
+    ///
+    /// As with [`InputTask::read`], an error after some samples were read is returned in [`Waveform::error`].
+    pub fn read(
+        &mut self,
+        timeout: Timeout,
+        samples_per_channel: Option<u32>,
+    ) -> Result<Waveform<'_, T>> {
+        let capacity = self.samples_per_channel.try_into().unwrap_or(u32::MAX);
+        let requested = samples_per_channel.map_or(capacity, |samples| samples.min(capacity));
+
+        let outcome = self.task.read(
+            timeout,
+            DataFillMode::GroupByChannel,
+            Some(requested),
+            &mut self.buffer[..],
+        )?;
+        let read = outcome.samples_per_channel as usize;
+        self.error = outcome.error;
+
+        // The read position is the next sample to be read, so step back over this read.
+        let position = read_position(&self.task)?;
+        let first_sample = position.saturating_sub(read as u64);
+        let dt = sample_period(&self.task)?;
+
+        Ok(Waveform {
+            data: &self.buffer[..read * self.channel_names.len()],
+            channel_names: &self.channel_names,
+            samples_per_channel: read,
+            first_sample,
+            dt,
+            error: self.error.as_ref(),
+        })
+    }
+}
+
+/// Gets the position of the next sample to read in the acquisition.
+fn read_position<TYPE>(task: &Task<TYPE>) -> Result<u64> {
+    let mut value: uInt64 = 0;
+    daqmx_call!(crate::sys::DAQmxGetReadCurrReadPos(
+        task.raw_handle(),
+        &mut value
+    ))?;
+    Ok(value)
+}
+
+/// Gets the time between samples if the task uses a sample clock.
+fn sample_period<TYPE>(task: &Task<TYPE>) -> Result<Option<f64>> {
+    let mut timing_type = 0;
+    daqmx_call!(crate::sys::DAQmxGetSampTimingType(
+        task.raw_handle(),
+        &mut timing_type
+    ))?;
+    if timing_type != DAQmx_Val_SampClk {
+        return Ok(None);
+    }
+
+    let mut rate = 0.0;
+    daqmx_call!(crate::sys::DAQmxGetSampClkRate(
+        task.raw_handle(),
+        &mut rate
+    ))?;
+    Ok(Some(1.0 / rate))
+}
+
+/// The data from a single read of a [`Reader`], borrowed from its buffer.
+///
+/// The data is stored channel by channel. Use [`Waveform::channel`] for a channel's samples
+/// or [`Waveform::samples`] to step through the samples of all channels together.
+#[derive(Debug, Clone, Copy)]
+pub struct Waveform<'a, T> {
+    data: &'a [T],
+    channel_names: &'a [String],
+    samples_per_channel: usize,
+    first_sample: u64,
+    dt: Option<f64>,
+    error: Option<&'a DaqmxError>,
+}
+
+/// A [`Waveform`] from an analog input task.
+pub type AnalogWaveform<'a> = Waveform<'a, f64>;
+
+impl<'a, T: Copy> Waveform<'a, T> {
+    /// The number of channels in the waveform.
+    pub fn number_of_channels(&self) -> usize {
+        self.channel_names.len()
+    }
+
+    /// The number of samples read for each channel.
+    pub fn samples_per_channel(&self) -> usize {
+        self.samples_per_channel
+    }
+
+    /// The names of the channels, in the order they are stored.
+    pub fn channel_names(&self) -> &'a [String] {
+        self.channel_names
+    }
+
+    /// The raw data, grouped by channel.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// The samples for the channel at `index`.
+    pub fn channel(&self, index: usize) -> Option<&'a [T]> {
+        if index >= self.number_of_channels() {
+            return None;
+        }
+        let start = index * self.samples_per_channel;
+        Some(&self.data[start..start + self.samples_per_channel])
+    }
+
+    /// The samples for the channel with the given name.
+    pub fn channel_by_name(&self, name: &str) -> Option<&'a [T]> {
+        let index = self.channel_names.iter().position(|n| n == name)?;
+        self.channel(index)
+    }
+
+    /// Iterates over the channels as pairs of the channel name and its samples.
+    pub fn channels(&self) -> impl Iterator<Item = (&'a str, &'a [T])> + use<'a, T> {
+        let waveform = *self;
+        self.channel_names
+            .iter()
+            .enumerate()
+            .map(move |(index, name)| (name.as_str(), waveform.channel(index).unwrap()))
+    }
+
+    /// The values of every channel at the sample `index`.
+    pub fn sample(&self, index: usize) -> Option<Sample<'a, T>> {
+        if index >= self.samples_per_channel {
+            return None;
+        }
+        Some(Sample {
+            data: self.data,
+            index,
+            stride: self.samples_per_channel,
+        })
+    }
+
+    /// Iterates over the samples, giving the values of every channel at each sample.
+    pub fn samples(&self) -> Samples<'a, T> {
+        Samples {
+            waveform: *self,
+            next: 0,
+        }
+    }
+
+    /// The index of the first sample since the task started.
+    pub fn first_sample(&self) -> u64 {
+        self.first_sample
+    }
+
+    /// The time between samples in seconds.
+    ///
+    /// This is [`None`] if the task is not timed by a sample clock.
+    pub fn dt(&self) -> Option<f64> {
+        self.dt
+    }
+
+    /// The time of the first sample in seconds, relative to the start of the acquisition.
+    ///
+    /// This is [`None`] if the task is not timed by a sample clock.
+    pub fn t0(&self) -> Option<f64> {
+        self.dt.map(|dt| self.first_sample as f64 * dt)
+    }
+
+    /// The error reported after reading these samples, such as a timeout before all samples were ready.
+    pub fn error(&self) -> Option<&'a DaqmxError> {
+        self.error
+    }
+}
+
+/// The values of every channel at a single sample in a [`Waveform`].
+#[derive(Debug, Clone, Copy)]
+pub struct Sample<'a, T> {
+    data: &'a [T],
+    index: usize,
+    stride: usize,
+}
+
+impl<'a, T: Copy> Sample<'a, T> {
+    /// The index of this sample in the waveform.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The number of channels in the sample.
+    pub fn number_of_channels(&self) -> usize {
+        self.data.len() / self.stride
+    }
+
+    /// The value of the channel at `channel`.
+    pub fn get(&self, channel: usize) -> Option<T> {
+        if channel >= self.number_of_channels() {
+            return None;
+        }
+        Some(self.data[channel * self.stride + self.index])
+    }
+
+    /// Iterates over the value of each channel.
+    pub fn iter(&self) -> impl Iterator<Item = T> + use<'a, T> {
+        self.data
+            .iter()
+            .skip(self.index)
+            .step_by(self.stride)
+            .copied()
+    }
+
+    /// Copy the values of each channel into a new vector.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+}
+
+/// Iterator over the samples of a [`Waveform`]. Created by [`Waveform::samples`].
+#[derive(Debug, Clone)]
+pub struct Samples<'a, T> {
+    waveform: Waveform<'a, T>,
+    next: usize,
+}
+
+impl<'a, T: Copy> Iterator for Samples<'a, T> {
+    type Item = Sample<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.waveform.sample(self.next)?;
+        self.next += 1;
+        Some(sample)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.waveform.samples_per_channel - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for Samples<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names() -> Vec<String> {
+        vec!["ai0".to_owned(), "ai1".to_owned()]
+    }
+
+    #[test]
+    fn test_waveform_channels() {
+        let names = names();
+        let waveform = Waveform {
+            data: &[1.0, 2.0, 3.0, 10.0, 20.0, 30.0],
+            channel_names: &names,
+            samples_per_channel: 3,
+            first_sample: 0,
+            dt: None,
+            error: None,
+        };
+
+        assert_eq!(waveform.channel(0), Some(&[1.0, 2.0, 3.0][..]));
+        assert_eq!(waveform.channel(1), Some(&[10.0, 20.0, 30.0][..]));
+        assert_eq!(waveform.channel(2), None);
+        assert_eq!(
+            waveform.channel_by_name("ai1"),
+            Some(&[10.0, 20.0, 30.0][..])
+        );
+        assert_eq!(waveform.channel_by_name("ai2"), None);
+
+        let channels: Vec<_> = waveform.channels().map(|(name, _)| name).collect();
+        assert_eq!(channels, vec!["ai0", "ai1"]);
+    }
+
+    #[test]
+    fn test_waveform_samples() {
+        let names = names();
+        let waveform = Waveform {
+            data: &[1, 2, 3, 10, 20, 30],
+            channel_names: &names,
+            samples_per_channel: 3,
+            first_sample: 0,
+            dt: None,
+            error: None,
+        };
+
+        let samples: Vec<Vec<i32>> = waveform.samples().map(|sample| sample.to_vec()).collect();
+        assert_eq!(samples, vec![vec![1, 10], vec![2, 20], vec![3, 30]]);
+        assert_eq!(waveform.samples().len(), 3);
+
+        let sample = waveform.sample(1).unwrap();
+        assert_eq!(sample.get(1), Some(20));
+        assert_eq!(sample.get(2), None);
+        assert!(waveform.sample(3).is_none());
+    }
+
+    #[test]
+    fn test_waveform_timing() {
+        let names = names();
+        let waveform = Waveform {
+            data: &[0.0; 4],
+            channel_names: &names,
+            samples_per_channel: 2,
+            first_sample: 100,
+            dt: Some(0.001),
+            error: None,
+        };
+
+        assert_eq!(waveform.dt(), Some(0.001));
+        assert!((waveform.t0().unwrap() - 0.1).abs() < 1e-12);
+
+        let untimed = Waveform {
+            dt: None,
+            ..waveform
+        };
+        assert_eq!(untimed.t0(), None);
+    }
+
+    #[test]
+    fn test_empty_waveform() {
+        let names = names();
+        let waveform: Waveform<f64> = Waveform {
+            data: &[],
+            channel_names: &names,
+            samples_per_channel: 0,
+            first_sample: 0,
+            dt: None,
+            error: None,
+        };
+
+        assert_eq!(waveform.channel(0), Some(&[][..]));
+        assert_eq!(waveform.samples().count(), 0);
+    }
+}
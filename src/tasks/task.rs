@@ -1,22 +1,36 @@
+use crate::channels::ai_channels::AnyAnalogInput;
+use crate::channels::ao_channels::AnyAnalogOutput;
+use crate::channels::ci_channels::AnyCounterInput;
+use crate::channels::co_channels::AnyCounterOutput;
+use crate::channels::digital_channels::{DigitalInputChannel, DigitalOutputChannel};
+use crate::channels::{ChannelKind, ChannelList, TaskChannel};
 use crate::daqmx_call;
-use crate::error::{Result, handle_error};
+use crate::error::{DaqmxError, Result, handle_error};
 use crate::types::*;
-use ni_daqmx_sys::DAQmxGetTaskName;
+use super::events::ParkedCallback;
+use crate::sys::DAQmxGetTaskName;
 /// Provides a wrapper and functions for the DAQmx Task
-use std::ffi::CString;
+use std::ffi::{CString, c_char};
 use std::marker::PhantomData;
 use std::ptr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// New type for the raw task handle from the C FFI
-struct TaskHandle(ni_daqmx_sys::TaskHandle);
+struct TaskHandle {
+    raw: crate::sys::TaskHandle,
+    /// Event callbacks which DAQmx refused to unregister, such as while the task was running.
+    ///
+    /// These are only dropped once unregistered or after the task is cleared so DAQmx can never
+    /// call back with a dangling pointer.
+    parked_callbacks: Mutex<Vec<ParkedCallback>>,
+}
 
 unsafe impl Send for TaskHandle {}
 unsafe impl Sync for TaskHandle {}
 
 impl Drop for TaskHandle {
     fn drop(&mut self) {
-        unsafe { ni_daqmx_sys::DAQmxClearTask(self.0) };
+        unsafe { crate::sys::DAQmxClearTask(self.raw) };
     }
 }
 
@@ -24,6 +38,26 @@ impl Drop for TaskHandle {
 ///Marker type for an analog input task.
 pub struct AnalogInput;
 
+#[derive(Clone)]
+///Marker type for an analog output task.
+pub struct AnalogOutput;
+
+#[derive(Clone)]
+///Marker type for a digital input task.
+pub struct DigitalInput;
+
+#[derive(Clone)]
+///Marker type for a digital output task.
+pub struct DigitalOutput;
+
+#[derive(Clone)]
+///Marker type for a counter input task.
+pub struct CounterInput;
+
+#[derive(Clone)]
+///Marker type for a counter output task.
+pub struct CounterOutput;
+
 #[derive(Clone)]
 pub struct Task<TYPE> {
     handle: Arc<TaskHandle>,
@@ -36,8 +70,20 @@ impl<TYPE> Task<TYPE> {
     /// This is designed for immediate use in the FFI.
     /// You should not hold this raw handle yourself as you
     /// lose the memory safety given by the wrapped task.
-    pub(crate) fn raw_handle(&self) -> ni_daqmx_sys::TaskHandle {
-        self.handle.0
+    pub(crate) fn raw_handle(&self) -> crate::sys::TaskHandle {
+        self.handle.raw
+    }
+
+    /// Keep an event callback alive until it is unregistered when the task stops, or the task is cleared.
+    pub(super) fn park_callback(&self, callback: ParkedCallback) {
+        self.parked_callbacks().push(callback);
+    }
+
+    pub(super) fn parked_callbacks(&self) -> MutexGuard<'_, Vec<ParkedCallback>> {
+        self.handle
+            .parked_callbacks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
     }
 
     /// Create a new task handle from a name. For use in specific task types.
@@ -45,18 +91,26 @@ impl<TYPE> Task<TYPE> {
         let c_name = CString::new(name)?;
 
         let handle = {
-            let mut tmp_handle: ni_daqmx_sys::TaskHandle = ptr::null_mut();
-            daqmx_call!(ni_daqmx_sys::DAQmxCreateTask(
+            let mut tmp_handle: crate::sys::TaskHandle = ptr::null_mut();
+            daqmx_call!(crate::sys::DAQmxCreateTask(
                 c_name.as_ptr(),
                 &mut tmp_handle
             ))?;
             tmp_handle
         };
 
-        Ok(Self {
-            handle: Arc::new(TaskHandle(handle)),
+        Ok(Self::from_raw_handle(handle))
+    }
+
+    /// Take ownership of a task handle created by DAQmx. The task is cleared when dropped.
+    pub(super) fn from_raw_handle(raw: crate::sys::TaskHandle) -> Self {
+        Self {
+            handle: Arc::new(TaskHandle {
+                raw,
+                parked_callbacks: Mutex::new(Vec::new()),
+            }),
             channel_type: PhantomData,
-        })
+        }
     }
 
     /// Gets the name assigned to the task in DAQmx.
@@ -67,28 +121,32 @@ impl<TYPE> Task<TYPE> {
     /// ```
     /// use daqmx::tasks::{Task, AnalogInput};
     ///
-    /// let mut task = Task::<AnalogInput>::new("").unwrap();
+    /// let task = Task::<AnalogInput>::new("").unwrap();
     /// let name = task.name().unwrap();
     ///
     /// // Returns Non-Empty Name
     /// assert_ne!(&name, "");
     /// ```
-    pub fn name(&mut self) -> Result<String> {
-        //first call to get size.
-        let return_code = unsafe { DAQmxGetTaskName(self.raw_handle(), std::ptr::null_mut(), 0) };
-        if return_code < 0 {
-            handle_error(return_code)?;
-        }
+    pub fn name(&self) -> Result<String> {
+        self.get_string(DAQmxGetTaskName)
+    }
 
-        let buffer_size = return_code as u32;
-        let mut buffer: Vec<i8> = vec![0i8; buffer_size as usize];
-        daqmx_call!(DAQmxGetTaskName(
+    /// Gets the number of virtual channels in the task.
+    pub fn number_of_channels(&self) -> Result<u32> {
+        let mut value = 0;
+        daqmx_call!(crate::sys::DAQmxGetTaskNumChans(
             self.raw_handle(),
-            buffer.as_mut_ptr(),
-            buffer_size
+            &mut value
         ))?;
+        Ok(value)
+    }
 
-        Ok(buffer_to_string(buffer))
+    /// Gets the names of the virtual channels in the task, in the order they were added.
+    ///
+    /// Any ranges in the list returned by DAQmx are expanded into the individual channels.
+    pub fn channel_names(&self) -> Result<Vec<String>> {
+        let names = ChannelList::parse(&self.get_string(crate::sys::DAQmxGetTaskChannels)?)?;
+        Ok(names.into_iter().collect())
     }
 
     /// Configure a hardware timed task with the provided parameters.
@@ -112,7 +170,7 @@ impl<TYPE> Task<TYPE> {
             None => CString::new("OnboardClock")?,
         };
 
-        daqmx_call!(ni_daqmx_sys::DAQmxCfgSampClkTiming(
+        daqmx_call!(crate::sys::DAQmxCfgSampClkTiming(
             self.raw_handle(),
             source_c.as_ptr(),
             rate,
@@ -122,6 +180,69 @@ impl<TYPE> Task<TYPE> {
         ))
     }
 
+    /// Gets the rate of the sample clock in samples per second per channel.
+    pub fn sample_clock_rate(&self) -> Result<f64> {
+        let mut value = 0.0;
+        daqmx_call!(crate::sys::DAQmxGetSampClkRate(
+            self.raw_handle(),
+            &mut value
+        ))?;
+        Ok(value)
+    }
+
+    /// Gets the terminal of the sample clock.
+    pub fn sample_clock_source(&self) -> Result<String> {
+        self.get_string(crate::sys::DAQmxGetSampClkSrc)
+    }
+
+    /// Gets the edge of the sample clock used to acquire or generate samples.
+    pub fn sample_clock_active_edge(&self) -> Result<ClockEdge> {
+        let mut value = 0;
+        daqmx_call!(crate::sys::DAQmxGetSampClkActiveEdge(
+            self.raw_handle(),
+            &mut value
+        ))?;
+        ClockEdge::try_from(value)
+    }
+
+    /// Gets whether the task acquires or generates a finite number of samples or runs continuously.
+    pub fn sample_mode(&self) -> Result<SampleMode> {
+        let mut value = 0;
+        daqmx_call!(crate::sys::DAQmxGetSampQuantSampMode(
+            self.raw_handle(),
+            &mut value
+        ))?;
+        SampleMode::try_from(value)
+    }
+
+    /// Gets the number of samples per channel for a finite task, or the buffer size for a continuous one.
+    pub fn samples_per_channel(&self) -> Result<u64> {
+        let mut value = 0;
+        daqmx_call!(crate::sys::DAQmxGetSampQuantSampPerChan(
+            self.raw_handle(),
+            &mut value
+        ))?;
+        Ok(value)
+    }
+
+    /// Configure the task to use implicit timing, where the timing is set by the signal being measured or generated.
+    ///
+    /// This is used for counter tasks such as buffered period measurements or pulse train generation.
+    ///
+    /// * For [`SampleMode::FiniteSamples`], `samples_per_channel` is the number of samples to acquire or pulses to generate.
+    /// * For [`SampleMode::ContinuousSamples`], `samples_per_channel` may be used to configure the buffer size.
+    pub fn configure_implicit_timing(
+        &mut self,
+        mode: SampleMode,
+        samples_per_channel: u64,
+    ) -> Result<()> {
+        daqmx_call!(crate::sys::DAQmxCfgImplicitTiming(
+            self.raw_handle(),
+            mode.into(),
+            samples_per_channel
+        ))
+    }
+
     /// Transitions the task from the committed state to the running state, which begins measurement or generation.
     /// Using this function is required for some applications and optional for others.
     ///
@@ -131,20 +252,25 @@ impl<TYPE> Task<TYPE> {
     ///If you do not call [`Task::start`] and [`Task::stop`] when you call NI-DAQmx Read functions or NI-DAQmx Write functions multiple times, such as in a loop, the task starts and stops repeatedly.
     /// Starting and stopping a task repeatedly reduces the performance of the application.
     pub fn start(&mut self) -> Result<()> {
-        daqmx_call!(ni_daqmx_sys::DAQmxStartTask(self.raw_handle()))
+        daqmx_call!(crate::sys::DAQmxStartTask(self.raw_handle()))
     }
 
     /// Stops the task and returns it to the state it was in before it was started.
     ///
     ///If you do not call [`Task::start`] and [`Task::stop`] when you call NI-DAQmx Read functions or NI-DAQmx Write functions multiple times, such as in a loop, the task starts and stops repeatedly.
     /// Starting and stopping a task repeatedly reduces the performance of the application.
+    ///
+    /// Event callbacks whose registrations were dropped while the task was running are unregistered
+    /// and freed now.
     pub fn stop(&mut self) -> Result<()> {
-        daqmx_call!(ni_daqmx_sys::DAQmxStopTask(self.raw_handle()))
+        daqmx_call!(crate::sys::DAQmxStopTask(self.raw_handle()))?;
+        self.release_parked_callbacks();
+        Ok(())
     }
 
     /// Waits for the measurement or generation to complete. Use this function to ensure that the specified operation is complete before you stop the task.
     pub fn wait_until_done(&mut self, timeout: Timeout) -> Result<()> {
-        daqmx_call!(ni_daqmx_sys::DAQmxWaitUntilTaskDone(
+        daqmx_call!(crate::sys::DAQmxWaitUntilTaskDone(
             self.raw_handle(),
             timeout.into()
         ))
@@ -153,7 +279,7 @@ impl<TYPE> Task<TYPE> {
     ///Gets whether DAQmx read automatically starts the task.
     pub fn read_auto_start(&mut self) -> Result<bool> {
         let mut value: bool32 = 0;
-        daqmx_call!(ni_daqmx_sys::DAQmxGetReadAutoStart(
+        daqmx_call!(crate::sys::DAQmxGetReadAutoStart(
             self.raw_handle(),
             &mut value
         ))?;
@@ -166,10 +292,97 @@ impl<TYPE> Task<TYPE> {
             true => 1,
             false => 0,
         };
-        daqmx_call!(ni_daqmx_sys::DAQmxSetReadAutoStart(
+        daqmx_call!(crate::sys::DAQmxSetReadAutoStart(self.raw_handle(), value))?;
+        Ok(())
+    }
+
+    /// Read a string property of the task, given a raw DAQmx function.
+    fn get_string(
+        &self,
+        daqmx_fn: unsafe extern "C" fn(crate::sys::TaskHandle, *mut c_char, u32) -> i32,
+    ) -> Result<String> {
+        //first call to get size.
+        let return_code = unsafe { daqmx_fn(self.raw_handle(), std::ptr::null_mut(), 0) };
+        if return_code < 0 {
+            handle_error(return_code)?;
+        }
+
+        let buffer_size = return_code as u32;
+        let mut buffer: Vec<i8> = vec![0i8; buffer_size as usize];
+        daqmx_call!(daqmx_fn(
             self.raw_handle(),
-            value
+            buffer.as_mut_ptr(),
+            buffer_size
         ))?;
-        Ok(())
+
+        Ok(buffer_to_string(buffer))
     }
 }
+
+impl<TYPE: TaskType> Task<TYPE> {
+    /// Gets the channel with the given name, checking it is in the task.
+    ///
+    /// DAQmx channel names aren't case sensitive, so neither is the check.
+    pub(crate) fn existing_channel<K: ChannelKind>(&self, name: &str) -> Result<TaskChannel<K>> {
+        let name = CString::new(name)?;
+        let exists = self.channel_names()?.iter().any(|existing| {
+            existing
+                .as_bytes()
+                .eq_ignore_ascii_case(name.as_bytes())
+        });
+        if !exists {
+            return Err(DaqmxError::ChannelNotInTask(
+                name.to_string_lossy().into_owned(),
+            ));
+        }
+        Ok(TaskChannel::new(self.raw_handle(), name))
+    }
+
+    /// Gets the virtual channels in the task, in the order they were added.
+    ///
+    /// The channels are the kind common to all channels of the task, e.g. [`AnyAnalogInput`] for an
+    /// analog input task, which provides the properties shared by every channel of that type.
+    pub fn channels(&self) -> Result<Vec<TaskChannel<TYPE::ChannelKind>>> {
+        self.channel_names()?
+            .into_iter()
+            .map(|name| Ok(TaskChannel::new(self.raw_handle(), CString::new(name)?)))
+            .collect()
+    }
+}
+
+/// Implemented by the task type markers to give the kind of channel common to all channels of the task.
+pub trait TaskType {
+    type ChannelKind: ChannelKind;
+    /// The DAQmx channel type (e.g. `DAQmx_Val_AI`) of the channels in the task.
+    const CHANNEL_TYPE: i32;
+}
+
+impl TaskType for AnalogInput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_AI;
+    type ChannelKind = AnyAnalogInput;
+}
+
+impl TaskType for AnalogOutput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_AO;
+    type ChannelKind = AnyAnalogOutput;
+}
+
+impl TaskType for DigitalInput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_DI;
+    type ChannelKind = DigitalInputChannel;
+}
+
+impl TaskType for DigitalOutput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_DO;
+    type ChannelKind = DigitalOutputChannel;
+}
+
+impl TaskType for CounterInput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_CI;
+    type ChannelKind = AnyCounterInput;
+}
+
+impl TaskType for CounterOutput {
+    const CHANNEL_TYPE: i32 = crate::sys::DAQmx_Val_CO;
+    type ChannelKind = AnyCounterOutput;
+}
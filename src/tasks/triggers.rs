@@ -0,0 +1,187 @@
+//! Provides the trigger configuration for all task types.
+//!
+//! * Start triggers begin the acquisition or generation.
+//! * Reference triggers mark the point in a finite acquisition around which pretrigger and posttrigger samples are captured.
+//! * Pause triggers pause the sample clock while the trigger condition is met.
+use crate::daqmx_call;
+use crate::error::Result;
+use crate::types::*;
+use std::ffi::CString;
+
+use super::Task;
+
+impl<TYPE> Task<TYPE> {
+    /// Configure the task to start when a digital edge is detected on `source`.
+    pub fn configure_digital_edge_start_trigger(
+        &mut self,
+        source: &str,
+        edge: ClockEdge,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgDigEdgeStartTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            edge.into()
+        ))
+    }
+
+    /// Configure the task to start when the analog signal on `source` crosses `level` with the given `slope`.
+    pub fn configure_analog_edge_start_trigger(
+        &mut self,
+        source: &str,
+        slope: Slope,
+        level: f64,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgAnlgEdgeStartTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            slope.into(),
+            level
+        ))
+    }
+
+    /// Configure the task to start when the analog signal on `source` enters or leaves the window between `bottom` and `top`.
+    pub fn configure_analog_window_start_trigger(
+        &mut self,
+        source: &str,
+        condition: WindowCondition,
+        top: f64,
+        bottom: f64,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgAnlgWindowStartTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            condition.into(),
+            top,
+            bottom
+        ))
+    }
+
+    /// Removes any start trigger so the task starts immediately.
+    pub fn disable_start_trigger(&mut self) -> Result<()> {
+        daqmx_call!(crate::sys::DAQmxDisableStartTrig(self.raw_handle()))
+    }
+
+    /// Configure a reference trigger on a digital edge on `source`.
+    ///
+    /// `pretrigger_samples` is the number of samples per channel to keep from before the trigger.
+    /// The remaining samples configured in the timing are acquired after the trigger.
+    pub fn configure_digital_edge_reference_trigger(
+        &mut self,
+        source: &str,
+        edge: ClockEdge,
+        pretrigger_samples: u32,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgDigEdgeRefTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            edge.into(),
+            pretrigger_samples
+        ))
+    }
+
+    /// Configure a reference trigger when the analog signal on `source` crosses `level` with the given `slope`.
+    ///
+    /// `pretrigger_samples` is the number of samples per channel to keep from before the trigger.
+    pub fn configure_analog_edge_reference_trigger(
+        &mut self,
+        source: &str,
+        slope: Slope,
+        level: f64,
+        pretrigger_samples: u32,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgAnlgEdgeRefTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            slope.into(),
+            level,
+            pretrigger_samples
+        ))
+    }
+
+    /// Configure a reference trigger when the analog signal on `source` enters or leaves the window between `bottom` and `top`.
+    ///
+    /// `pretrigger_samples` is the number of samples per channel to keep from before the trigger.
+    pub fn configure_analog_window_reference_trigger(
+        &mut self,
+        source: &str,
+        condition: WindowCondition,
+        top: f64,
+        bottom: f64,
+        pretrigger_samples: u32,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxCfgAnlgWindowRefTrig(
+            self.raw_handle(),
+            source_c.as_ptr(),
+            condition.into(),
+            top,
+            bottom,
+            pretrigger_samples
+        ))
+    }
+
+    /// Removes any reference trigger from the task.
+    pub fn disable_reference_trigger(&mut self) -> Result<()> {
+        daqmx_call!(crate::sys::DAQmxDisableRefTrig(self.raw_handle()))
+    }
+
+    /// Configure the task to pause while the digital signal on `source` is at the `pause_when` level.
+    pub fn configure_digital_level_pause_trigger(
+        &mut self,
+        source: &str,
+        pause_when: Level,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxSetPauseTrigType(
+            self.raw_handle(),
+            crate::sys::DAQmx_Val_DigLvl
+        ))?;
+        daqmx_call!(crate::sys::DAQmxSetDigLvlPauseTrigSrc(
+            self.raw_handle(),
+            source_c.as_ptr()
+        ))?;
+        daqmx_call!(crate::sys::DAQmxSetDigLvlPauseTrigWhen(
+            self.raw_handle(),
+            pause_when.into()
+        ))
+    }
+
+    /// Configure the task to pause while the analog signal on `source` is above or below `level`.
+    pub fn configure_analog_level_pause_trigger(
+        &mut self,
+        source: &str,
+        pause_when: LevelCondition,
+        level: f64,
+    ) -> Result<()> {
+        let source_c = CString::new(source)?;
+        daqmx_call!(crate::sys::DAQmxSetPauseTrigType(
+            self.raw_handle(),
+            crate::sys::DAQmx_Val_AnlgLvl
+        ))?;
+        daqmx_call!(crate::sys::DAQmxSetAnlgLvlPauseTrigSrc(
+            self.raw_handle(),
+            source_c.as_ptr()
+        ))?;
+        daqmx_call!(crate::sys::DAQmxSetAnlgLvlPauseTrigWhen(
+            self.raw_handle(),
+            pause_when.into()
+        ))?;
+        daqmx_call!(crate::sys::DAQmxSetAnlgLvlPauseTrigLvl(
+            self.raw_handle(),
+            level
+        ))
+    }
+
+    /// Removes any pause trigger from the task.
+    pub fn disable_pause_trigger(&mut self) -> Result<()> {
+        daqmx_call!(crate::sys::DAQmxSetPauseTrigType(
+            self.raw_handle(),
+            crate::sys::DAQmx_Val_None
+        ))
+    }
+}
@@ -29,11 +29,11 @@ pub enum DataFillMode {
     GroupByScanNumber,
 }
 
-impl From<DataFillMode> for ni_daqmx_sys::bool32 {
+impl From<DataFillMode> for crate::sys::bool32 {
     fn from(fill_mode: DataFillMode) -> Self {
         match fill_mode {
-            DataFillMode::GroupByChannel => ni_daqmx_sys::DAQmx_Val_GroupByChannel as u32,
-            DataFillMode::GroupByScanNumber => ni_daqmx_sys::DAQmx_Val_GroupByScanNumber as u32,
+            DataFillMode::GroupByChannel => crate::sys::DAQmx_Val_GroupByChannel as u32,
+            DataFillMode::GroupByScanNumber => crate::sys::DAQmx_Val_GroupByScanNumber as u32,
         }
     }
 }
@@ -52,7 +52,7 @@ pub enum Timeout {
 impl From<Timeout> for f64 {
     fn from(timeout: Timeout) -> Self {
         match timeout {
-            Timeout::WaitForever => ni_daqmx_sys::DAQmx_Val_WaitInfinitely,
+            Timeout::WaitForever => crate::sys::DAQmx_Val_WaitInfinitely,
             Timeout::NoWait => 0.0,
             Timeout::Seconds(seconds) => seconds,
         }
@@ -62,6 +62,8 @@ impl From<Timeout> for f64 {
 ///Represents the active edge of clock.
 ///
 /// Default is rising.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClockEdge {
     Rising,
     Falling,
@@ -76,13 +78,94 @@ impl Default for ClockEdge {
 impl From<ClockEdge> for i32 {
     fn from(edge: ClockEdge) -> Self {
         match edge {
-            ClockEdge::Rising => ni_daqmx_sys::DAQmx_Val_Rising as i32,
-            ClockEdge::Falling => ni_daqmx_sys::DAQmx_Val_Falling as i32,
+            ClockEdge::Rising => crate::sys::DAQmx_Val_Rising as i32,
+            ClockEdge::Falling => crate::sys::DAQmx_Val_Falling as i32,
+        }
+    }
+}
+
+impl TryFrom<i32> for ClockEdge {
+    type Error = crate::error::DaqmxError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            crate::sys::DAQmx_Val_Rising => Ok(ClockEdge::Rising),
+            crate::sys::DAQmx_Val_Falling => Ok(ClockEdge::Falling),
+            _ => Err(crate::error::DaqmxError::UnexpectedValue("Clock Edge", value)),
+        }
+    }
+}
+
+/// Represents the slope of an analog signal used for an analog edge trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Slope {
+    #[default]
+    Rising,
+    Falling,
+}
+
+impl From<Slope> for i32 {
+    fn from(slope: Slope) -> Self {
+        match slope {
+            Slope::Rising => crate::sys::DAQmx_Val_RisingSlope,
+            Slope::Falling => crate::sys::DAQmx_Val_FallingSlope,
+        }
+    }
+}
+
+/// Represents when an analog window trigger fires relative to the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowCondition {
+    /// Trigger when the signal enters the window.
+    EnteringWindow,
+    /// Trigger when the signal leaves the window.
+    LeavingWindow,
+}
+
+impl From<WindowCondition> for i32 {
+    fn from(condition: WindowCondition) -> Self {
+        match condition {
+            WindowCondition::EnteringWindow => crate::sys::DAQmx_Val_EnteringWin,
+            WindowCondition::LeavingWindow => crate::sys::DAQmx_Val_LeavingWin,
+        }
+    }
+}
+
+/// Represents when an analog level trigger is active relative to the level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelCondition {
+    AboveLevel,
+    BelowLevel,
+}
+
+impl From<LevelCondition> for i32 {
+    fn from(condition: LevelCondition) -> Self {
+        match condition {
+            LevelCondition::AboveLevel => crate::sys::DAQmx_Val_AboveLvl,
+            LevelCondition::BelowLevel => crate::sys::DAQmx_Val_BelowLvl,
+        }
+    }
+}
+
+/// Represents a digital logic level, e.g. the idle state of a counter output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    High,
+    Low,
+}
+
+impl From<Level> for i32 {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::High => crate::sys::DAQmx_Val_High,
+            Level::Low => crate::sys::DAQmx_Val_Low,
         }
     }
 }
 
 /// Represents the different timing modes of a task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SampleMode {
     /// Acquire or generate a finite number of samples.
     FiniteSamples,
@@ -96,17 +179,71 @@ pub enum SampleMode {
 impl From<SampleMode> for i32 {
     fn from(mode: SampleMode) -> Self {
         match mode {
-            SampleMode::FiniteSamples => ni_daqmx_sys::DAQmx_Val_FiniteSamps as i32,
-            SampleMode::ContinuousSamples => ni_daqmx_sys::DAQmx_Val_ContSamps as i32,
-            SampleMode::HardwareTimedSinglePoint => {
-                ni_daqmx_sys::DAQmx_Val_HWTimedSinglePoint as i32
+            SampleMode::FiniteSamples => crate::sys::DAQmx_Val_FiniteSamps as i32,
+            SampleMode::ContinuousSamples => crate::sys::DAQmx_Val_ContSamps as i32,
+            SampleMode::HardwareTimedSinglePoint => crate::sys::DAQmx_Val_HWTimedSinglePoint as i32,
+        }
+    }
+}
+
+impl TryFrom<i32> for SampleMode {
+    type Error = crate::error::DaqmxError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            crate::sys::DAQmx_Val_FiniteSamps => Ok(SampleMode::FiniteSamples),
+            crate::sys::DAQmx_Val_ContSamps => Ok(SampleMode::ContinuousSamples),
+            crate::sys::DAQmx_Val_HWTimedSinglePoint => Ok(SampleMode::HardwareTimedSinglePoint),
+            _ => Err(crate::error::DaqmxError::UnexpectedValue("Sample Mode", value)),
+        }
+    }
+}
+
+/// Represents when an every N samples event fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EveryNSamplesEventType {
+    /// Fire when N samples are acquired into the buffer. For input tasks.
+    AcquiredIntoBuffer,
+    /// Fire when N samples are transferred from the buffer to the device. For output tasks.
+    TransferredFromBuffer,
+}
+
+impl From<EveryNSamplesEventType> for i32 {
+    fn from(event_type: EveryNSamplesEventType) -> Self {
+        match event_type {
+            EveryNSamplesEventType::AcquiredIntoBuffer => {
+                crate::sys::DAQmx_Val_Acquired_Into_Buffer
             }
+            EveryNSamplesEventType::TransferredFromBuffer => {
+                crate::sys::DAQmx_Val_Transferred_From_Buffer
+            }
+        }
+    }
+}
+
+/// Represents the hardware signals which can fire a signal event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    SampleClock,
+    SampleCompleteEvent,
+    ChangeDetectionEvent,
+    CounterOutputEvent,
+}
+
+impl From<Signal> for i32 {
+    fn from(signal: Signal) -> Self {
+        match signal {
+            Signal::SampleClock => crate::sys::DAQmx_Val_SampleClock,
+            Signal::SampleCompleteEvent => crate::sys::DAQmx_Val_SampleCompleteEvent,
+            Signal::ChangeDetectionEvent => crate::sys::DAQmx_Val_ChangeDetectionEvent,
+            Signal::CounterOutputEvent => crate::sys::DAQmx_Val_CounterOutputEvent,
         }
     }
 }
 
 //Used quite a bit so lets re-export here with conversion.
-pub use ni_daqmx_sys::bool32;
+#[allow(non_camel_case_types)]
+pub type bool32 = crate::sys::bool32;
 
 #[cfg(test)]
 mod tests {
@@ -130,7 +267,7 @@ mod tests {
     fn timeout_conversion_tests() {
         assert_eq!(
             f64::from(Timeout::WaitForever),
-            ni_daqmx_sys::DAQmx_Val_WaitInfinitely
+            crate::sys::DAQmx_Val_WaitInfinitely
         );
 
         assert_eq!(f64::from(Timeout::NoWait), 0.0);
@@ -142,27 +279,102 @@ mod tests {
     fn edge_conversion_tests() {
         assert_eq!(
             i32::from(ClockEdge::Rising),
-            ni_daqmx_sys::DAQmx_Val_Rising as i32
+            crate::sys::DAQmx_Val_Rising as i32
         );
         assert_eq!(
             i32::from(ClockEdge::Falling),
-            ni_daqmx_sys::DAQmx_Val_Falling as i32
+            crate::sys::DAQmx_Val_Falling as i32
+        );
+        assert_eq!(
+            ClockEdge::try_from(crate::sys::DAQmx_Val_Falling),
+            Ok(ClockEdge::Falling)
+        );
+        assert!(ClockEdge::try_from(0).is_err());
+    }
+
+    #[test]
+    fn slope_conversion_tests() {
+        assert_eq!(i32::from(Slope::Rising), crate::sys::DAQmx_Val_RisingSlope);
+        assert_eq!(
+            i32::from(Slope::Falling),
+            crate::sys::DAQmx_Val_FallingSlope
+        );
+    }
+
+    #[test]
+    fn window_condition_conversion_tests() {
+        assert_eq!(
+            i32::from(WindowCondition::EnteringWindow),
+            crate::sys::DAQmx_Val_EnteringWin
+        );
+        assert_eq!(
+            i32::from(WindowCondition::LeavingWindow),
+            crate::sys::DAQmx_Val_LeavingWin
+        );
+    }
+
+    #[test]
+    fn level_condition_conversion_tests() {
+        assert_eq!(
+            i32::from(LevelCondition::AboveLevel),
+            crate::sys::DAQmx_Val_AboveLvl
+        );
+        assert_eq!(
+            i32::from(LevelCondition::BelowLevel),
+            crate::sys::DAQmx_Val_BelowLvl
+        );
+    }
+
+    #[test]
+    fn every_n_samples_event_type_conversion_tests() {
+        assert_eq!(
+            i32::from(EveryNSamplesEventType::AcquiredIntoBuffer),
+            crate::sys::DAQmx_Val_Acquired_Into_Buffer
+        );
+        assert_eq!(
+            i32::from(EveryNSamplesEventType::TransferredFromBuffer),
+            crate::sys::DAQmx_Val_Transferred_From_Buffer
         );
     }
 
+    #[test]
+    fn signal_conversion_tests() {
+        assert_eq!(
+            i32::from(Signal::SampleClock),
+            crate::sys::DAQmx_Val_SampleClock
+        );
+        assert_eq!(
+            i32::from(Signal::CounterOutputEvent),
+            crate::sys::DAQmx_Val_CounterOutputEvent
+        );
+    }
+
+    #[test]
+    fn level_conversion_tests() {
+        assert_eq!(i32::from(Level::High), crate::sys::DAQmx_Val_High);
+        assert_eq!(i32::from(Level::Low), crate::sys::DAQmx_Val_Low);
+    }
+
     #[test]
     fn sample_mode_conversion_tests() {
         assert_eq!(
             i32::from(SampleMode::FiniteSamples),
-            ni_daqmx_sys::DAQmx_Val_FiniteSamps as i32
+            crate::sys::DAQmx_Val_FiniteSamps as i32
         );
         assert_eq!(
             i32::from(SampleMode::ContinuousSamples),
-            ni_daqmx_sys::DAQmx_Val_ContSamps as i32
+            crate::sys::DAQmx_Val_ContSamps as i32
         );
         assert_eq!(
             i32::from(SampleMode::HardwareTimedSinglePoint),
-            ni_daqmx_sys::DAQmx_Val_HWTimedSinglePoint as i32
+            crate::sys::DAQmx_Val_HWTimedSinglePoint as i32
         );
+        for mode in [
+            SampleMode::FiniteSamples,
+            SampleMode::ContinuousSamples,
+            SampleMode::HardwareTimedSinglePoint,
+        ] {
+            assert_eq!(SampleMode::try_from(i32::from(mode)), Ok(mode));
+        }
     }
 }
@@ -9,11 +9,13 @@ use daqmx::tasks::*;
 use daqmx::types::*;
 use std::ffi::CString;
 use std::sync::Arc;
-use daqmx::channels::ai_channels::AnalogTerminalConfig;
+use daqmx::channels::ai_channels::temperature::Rtd;
+use daqmx::channels::ai_channels::{AnalogTerminalConfig, AnyAnalogInputChannel, MeasurementType};
+use daqmx::error::DaqmxError;
 
 #[test]
 fn test_scalar_read() {
-    let mut task = Task::new("scalar").unwrap();
+    let mut task: Task<AnalogInput> = Task::new("scalar").unwrap();
     let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
     task.create_channel(ch1).unwrap();
     let _ = task.read_scalar(Timeout::Seconds(1.0)).unwrap();
@@ -22,7 +24,7 @@ fn test_scalar_read() {
 
 #[test]
 fn test_buffered_read() {
-    let mut task = Task::new("scalar").unwrap();
+    let mut task: Task<AnalogInput> = Task::new("scalar").unwrap();
     let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
     task.create_channel(ch1).unwrap();
     task.configure_sample_clock_timing(
@@ -46,9 +48,44 @@ fn test_buffered_read() {
     .unwrap();
 }
 
+#[test]
+fn test_partial_read_on_timeout() {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::ContinuousSamples,
+        10000,
+    )
+    .unwrap();
+
+    // Ask for more samples than can be acquired before the timeout.
+    let mut buffer = [0.0; 5000];
+    task.start().unwrap();
+    let outcome = task
+        .read(
+            Timeout::Seconds(0.5),
+            DataFillMode::GroupByChannel,
+            Some(5000),
+            &mut buffer[..],
+        )
+        .unwrap();
+
+    assert!(outcome.samples_per_channel > 0);
+    assert!(outcome.samples_per_channel < 5000);
+    assert!(matches!(
+        outcome.error,
+        Some(daqmx::error::DaqmxError::DaqmxError(-200284, _))
+    ));
+    task.stop().unwrap();
+}
+
 #[test]
 fn test_stop() {
-    let mut task = Task::new("scalar").unwrap();
+    let mut task: Task<AnalogInput> = Task::new("scalar").unwrap();
     let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
     task.create_channel(ch1).unwrap();
     task.configure_sample_clock_timing(
@@ -98,7 +135,7 @@ fn test_voltage_input_builder() {
         .min(-10.0)
         .terminal_config(AnalogTerminalConfig::RSE);
 
-    let mut task = Task::new("").unwrap();
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
     task.create_channel(ch1).unwrap();
 
     let configured: TaskChannel<Voltage> = task.get_channel("my name").unwrap();
@@ -115,6 +152,52 @@ fn test_voltage_input_builder() {
     assert_eq!(configured.scale().unwrap(), VoltageScale::Volts);
 }
 
+#[test]
+fn test_channels_expands_range() {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let physical_channels: ChannelList = "PXI1Slot2/ai0:2".parse().unwrap();
+    let ch1 = VoltageChannelBuilder::new(physical_channels.to_string())
+        .unwrap()
+        .max(2.0)
+        .min(-2.0);
+    task.create_channel(ch1).unwrap();
+
+    let channels = task.channels().unwrap();
+    assert_eq!(channels.len(), 3);
+    for (channel, physical) in channels.iter().zip(&physical_channels) {
+        assert_eq!(&channel.physical_channel().unwrap(), physical);
+        assert_eq!(channel.ai_max().unwrap(), 2.0);
+    }
+}
+
+#[test]
+fn test_get_channel_checks_channel() {
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai1")
+        .unwrap()
+        .name("voltage")
+        .unwrap();
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    assert!(task.get_channel::<Voltage>("VOLTAGE").is_ok());
+    assert!(matches!(
+        task.get_channel::<Voltage>("missing"),
+        Err(DaqmxError::ChannelNotInTask(_))
+    ));
+    assert!(matches!(
+        task.get_channel::<Rtd>("voltage"),
+        Err(DaqmxError::ChannelKindMismatch(
+            _,
+            MeasurementType::Rtd,
+            MeasurementType::Voltage
+        ))
+    ));
+    assert!(matches!(
+        task.get_any_channel("voltage").unwrap(),
+        AnyAnalogInputChannel::Voltage(_)
+    ));
+}
+
 #[test]
 fn test_voltage_input_builder_custom_scale() {
     //create custom scale first.
@@ -128,7 +211,7 @@ fn test_voltage_input_builder_custom_scale() {
         .min(-10.0)
         .terminal_config(AnalogTerminalConfig::RSE);
 
-    let mut task = Task::new("").unwrap();
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
     task.create_channel(ch1).unwrap();
 
     let configured: TaskChannel<Voltage> = task.get_channel("my name").unwrap();
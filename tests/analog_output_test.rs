@@ -0,0 +1,90 @@
+//! Integration tests for covering the analog output tasks and channels.
+//!
+use daqmx::channels::ai_channels::AnalogChannelBuilder;
+use daqmx::channels::ai_channels::voltage::VoltageScale;
+use daqmx::channels::ao_channels::voltage::{VoltageOutput, VoltageOutputChannelBuilder};
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+#[test]
+fn test_scalar_write() {
+    let mut task: Task<AnalogOutput> = Task::new("scalar_write").unwrap();
+    let ch1 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.write_scalar(true, Timeout::Seconds(1.0), 1.5).unwrap();
+}
+
+#[test]
+fn test_buffered_write() {
+    let mut task: Task<AnalogOutput> = Task::new("buffered_write").unwrap();
+    let ch1 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao0").unwrap();
+    let ch2 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao1").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.create_channel(ch2).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::FiniteSamples,
+        100,
+    )
+    .unwrap();
+
+    let buffer = [0.5; 200];
+
+    let written = task
+        .write(
+            false,
+            Timeout::Seconds(1.0),
+            DataFillMode::GroupByChannel,
+            &buffer[..],
+        )
+        .unwrap();
+    assert_eq!(written, 100);
+
+    task.start().unwrap();
+    task.wait_until_done(Timeout::Seconds(1.0)).unwrap();
+}
+
+#[test]
+fn test_write_buffer_must_match_channels() {
+    let mut task: Task<AnalogOutput> = Task::new("").unwrap();
+    let ch1 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao0").unwrap();
+    let ch2 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao1").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.create_channel(ch2).unwrap();
+
+    let buffer = [0.5; 3];
+
+    let result = task.write(
+        false,
+        Timeout::Seconds(1.0),
+        DataFillMode::GroupByChannel,
+        &buffer[..],
+    );
+    assert_eq!(result, Err(daqmx::error::DaqmxError::WriteBufferSize(3, 2)));
+}
+
+#[test]
+fn test_voltage_output_builder() {
+    let ch1 = VoltageOutputChannelBuilder::new("PXI1Slot2/ao1")
+        .unwrap()
+        .name("my output")
+        .unwrap()
+        .scale(VoltageScale::Volts)
+        .max(5.0)
+        .min(-5.0);
+
+    let mut task: Task<AnalogOutput> = Task::new("").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    let configured: TaskChannel<VoltageOutput> = task.get_channel("my output").unwrap();
+    assert_eq!(
+        configured.physical_channel().unwrap(),
+        "PXI1Slot2/ao1".to_owned()
+    );
+    assert_eq!(configured.ao_max().unwrap(), 5.0);
+    assert_eq!(configured.ao_min().unwrap(), -5.0);
+    assert_eq!(configured.scale().unwrap(), VoltageScale::Volts);
+}
@@ -0,0 +1,45 @@
+//! Integration tests for the async reader. These require the `async` feature.
+//!
+#![cfg(feature = "async")]
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+use futures::StreamExt;
+use futures::executor::block_on;
+
+fn voltage_task(mode: SampleMode, samples_per_channel: u64) -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    let ch2 = VoltageChannelBuilder::new("PXI1Slot2/ai1").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.create_channel(ch2).unwrap();
+    task.configure_sample_clock_timing(None, 1000.0, ClockEdge::Rising, mode, samples_per_channel)
+        .unwrap();
+    task
+}
+
+#[test]
+fn test_async_read() {
+    let task = voltage_task(SampleMode::ContinuousSamples, 1000);
+    let mut reader = AsyncReader::new(task, 100).unwrap();
+    reader.task().start().unwrap();
+
+    let mut buffer = [0.0; 200];
+    let outcome = block_on(reader.read(DataFillMode::GroupByChannel, &mut buffer)).unwrap();
+    assert_eq!(outcome.samples_per_channel, 100);
+    assert!(outcome.is_complete());
+
+    reader.task().stop().unwrap();
+}
+
+#[test]
+fn test_finite_stream_ends() {
+    let task = voltage_task(SampleMode::FiniteSamples, 250);
+    let mut reader = AsyncReader::new(task, 100).unwrap();
+    reader.task().start().unwrap();
+
+    let blocks: Vec<Vec<f64>> = block_on(reader.by_ref().map(|block| block.unwrap()).collect());
+    let lengths: Vec<usize> = blocks.iter().map(|block| block.len()).collect();
+    assert_eq!(lengths, vec![200, 200, 100]);
+}
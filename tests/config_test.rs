@@ -0,0 +1,111 @@
+//! Integration tests for creating tasks from configuration. These require the `serde` feature.
+//!
+#![cfg(feature = "serde")]
+use daqmx::channels::ai_channels::temperature::{Thermocouple, ThermocoupleType};
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::config::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+const CONFIG: &str = r#"
+    name = "daqmx-rs config task"
+
+    [timing]
+    rate = 500.0
+    sample_mode = "FiniteSamples"
+    samples_per_channel = 50
+
+    [[scales]]
+    type = "Linear"
+    name = "daqmx-rs config scale"
+    slope = 2.0
+    pre_scaled_units = "Volts"
+    scaled_units = "bar"
+
+    [[channels]]
+    kind = "Voltage"
+    physical_channel = "PXI1Slot2/ai0"
+    name = "pressure"
+    min = -2.0
+    max = 2.0
+    custom_scale = "daqmx-rs config scale"
+
+    [[channels]]
+    kind = "Thermocouple"
+    physical_channel = "PXI1Slot2/ai1"
+    thermocouple_type = "K"
+    cjc_source = "BuiltIn"
+"#;
+
+#[test]
+fn test_create_task_from_config() {
+    let config: TaskConfig = toml::from_str(CONFIG).unwrap();
+    let mut task = config.create_task().unwrap();
+
+    assert_eq!(task.name().unwrap(), "daqmx-rs config task");
+    assert_eq!(
+        task.channel_names().unwrap(),
+        vec!["pressure", "PXI1Slot2/ai1"]
+    );
+    let thermocouple: TaskChannel<Thermocouple> = task.get_channel("PXI1Slot2/ai1").unwrap();
+    assert!(matches!(
+        thermocouple.thermocouple_type().unwrap(),
+        ThermocoupleType::K
+    ));
+    assert_eq!(task.sample_clock_rate().unwrap(), 500.0);
+
+    let mut buffer = [0.0; 100];
+    let read = task
+        .read(
+            Timeout::Seconds(1.0),
+            DataFillMode::GroupByChannel,
+            Some(50),
+            &mut buffer[..],
+        )
+        .unwrap();
+    assert_eq!(read.into_result().unwrap(), 50);
+}
+
+#[test]
+fn test_config_round_trip_through_task() {
+    let config: TaskConfig = toml::from_str(CONFIG).unwrap();
+    let task = config.create_task().unwrap();
+
+    let exported = TaskConfig::from_task(&task).unwrap();
+    assert_eq!(exported.name, config.name);
+    assert_eq!(exported.timing, config.timing);
+    assert_eq!(exported.scales, config.scales);
+
+    let AnalogInputChannelConfig::Voltage(voltage) = &exported.channels[0] else {
+        panic!("expected a voltage channel");
+    };
+    assert_eq!(voltage.name.as_deref(), Some("pressure"));
+    assert_eq!(voltage.min, Some(-2.0));
+    assert_eq!(
+        voltage.custom_scale.as_deref(),
+        Some("daqmx-rs config scale")
+    );
+    let AnalogInputChannelConfig::Thermocouple(thermocouple) = &exported.channels[1] else {
+        panic!("expected a thermocouple channel");
+    };
+    assert_eq!(thermocouple.name, None);
+    assert_eq!(thermocouple.cjc_source, Some(CjcSourceConfig::BuiltIn));
+
+    // Recreating the exported configuration gives the same configuration again.
+    drop(task);
+    let recreated = exported.create_task().unwrap();
+    assert_eq!(TaskConfig::from_task(&recreated).unwrap(), exported);
+}
+
+#[test]
+fn test_export_software_timed_task() {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap())
+        .unwrap();
+
+    let exported = TaskConfig::from_task(&task).unwrap();
+    assert_eq!(exported.timing, None);
+    let text = serde_json::to_string(&exported).unwrap();
+    assert!(text.contains(r#""kind":"Voltage""#));
+}
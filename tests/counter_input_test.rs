@@ -0,0 +1,87 @@
+//! Integration tests for covering the counter input tasks and channels.
+//!
+use daqmx::channels::ci_channels::*;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+#[test]
+fn test_count_edges_scalar_read() {
+    let mut task: Task<CounterInput> = Task::new("").unwrap();
+    let ch1 = CountEdgesChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .initial_count(10)
+        .count_direction(CountDirection::CountUp);
+    task.create_channel(ch1).unwrap();
+    task.start().unwrap();
+    let count: u32 = task.read_scalar(Timeout::Seconds(1.0)).unwrap();
+    assert!(count >= 10);
+}
+
+#[test]
+fn test_count_edges_builder() {
+    let ch1 = CountEdgesChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .name("edges")
+        .unwrap()
+        .edge(ClockEdge::Falling)
+        .initial_count(5)
+        .count_direction(CountDirection::CountDown);
+
+    let mut task: Task<CounterInput> = Task::new("").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    let configured: TaskChannel<CountEdges> = task.get_channel("edges").unwrap();
+    assert_eq!(configured.active_edge().unwrap(), ClockEdge::Falling);
+    assert_eq!(configured.initial_count().unwrap(), 5);
+    assert_eq!(
+        configured.count_direction().unwrap(),
+        CountDirection::CountDown
+    );
+}
+
+#[test]
+fn test_frequency_builder() {
+    let ch1 = FrequencyChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .name("speed")
+        .unwrap()
+        .min(10.0)
+        .max(1000.0)
+        .measurement_method(CounterMeasurementMethod::HighFrequencyTwoCounters)
+        .measurement_time(0.01);
+
+    let mut task: Task<CounterInput> = Task::new("").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    let configured: TaskChannel<Frequency> = task.get_channel("speed").unwrap();
+    assert_eq!(configured.ci_min().unwrap(), 10.0);
+    assert_eq!(configured.ci_max().unwrap(), 1000.0);
+    assert_eq!(configured.units().unwrap(), FrequencyUnits::Hertz);
+    assert_eq!(
+        configured.measurement_method().unwrap(),
+        CounterMeasurementMethod::HighFrequencyTwoCounters
+    );
+    assert_eq!(configured.measurement_time().unwrap(), 0.01);
+}
+
+#[test]
+fn test_period_scalar_read() {
+    let mut task: Task<CounterInput> = Task::new("").unwrap();
+    let ch1 = PeriodChannelBuilder::new("PXI1Slot2/ctr0").unwrap();
+    task.create_channel(ch1).unwrap();
+    let _: f64 = task.read_scalar(Timeout::Seconds(1.0)).unwrap();
+}
+
+#[test]
+fn test_pulse_width_builder() {
+    let ch1 = PulseWidthChannelBuilder::new("PXI1Slot2/ctr1")
+        .unwrap()
+        .units(TimeUnits::Ticks)
+        .starting_edge(ClockEdge::Falling);
+
+    let mut task: Task<CounterInput> = Task::new("").unwrap();
+    let configured = task.create_channel(ch1).unwrap();
+    assert_eq!(configured.units().unwrap(), TimeUnits::Ticks);
+    assert_eq!(configured.starting_edge().unwrap(), ClockEdge::Falling);
+}
@@ -0,0 +1,89 @@
+//! Integration tests for covering the counter output tasks and channels.
+//!
+use daqmx::channels::co_channels::*;
+use daqmx::channels::*;
+use daqmx::error::DaqmxError;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+#[test]
+fn test_finite_pulse_train() {
+    let mut task: Task<CounterOutput> = Task::new("").unwrap();
+    let ch1 = PulseFrequencyChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .frequency(1000.0)
+        .duty_cycle(0.25);
+    task.create_channel(ch1).unwrap();
+    task.configure_implicit_timing(SampleMode::FiniteSamples, 10)
+        .unwrap();
+    task.start().unwrap();
+    task.wait_until_done(Timeout::Seconds(1.0)).unwrap();
+}
+
+#[test]
+fn test_continuous_pulse_train() {
+    let mut task: Task<CounterOutput> = Task::new("").unwrap();
+    let ch1 = PulseTimeChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .high_time(0.001)
+        .low_time(0.002);
+    task.create_channel(ch1).unwrap();
+    task.configure_implicit_timing(SampleMode::ContinuousSamples, 1000)
+        .unwrap();
+    task.start().unwrap();
+    task.stop().unwrap();
+}
+
+#[test]
+fn test_pulse_frequency_builder() {
+    let ch1 = PulseFrequencyChannelBuilder::new("PXI1Slot2/ctr0")
+        .unwrap()
+        .name("clock")
+        .unwrap()
+        .idle_state(Level::High)
+        .initial_delay(0.5)
+        .frequency(100.0)
+        .duty_cycle(0.1);
+
+    let mut task: Task<CounterOutput> = Task::new("").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    let configured: TaskChannel<PulseFrequency> = task.get_channel("clock").unwrap();
+    assert_eq!(configured.idle_state().unwrap(), Level::High);
+    assert_eq!(configured.initial_delay().unwrap(), 0.5);
+    assert_eq!(configured.frequency().unwrap(), 100.0);
+    assert_eq!(configured.duty_cycle().unwrap(), 0.1);
+}
+
+#[test]
+fn test_pulse_ticks_builder() {
+    let ch1 = PulseTicksChannelBuilder::new("PXI1Slot2/ctr1")
+        .unwrap()
+        .high_ticks(20)
+        .unwrap()
+        .low_ticks(30)
+        .unwrap()
+        .initial_delay(5)
+        .unwrap();
+
+    let mut task: Task<CounterOutput> = Task::new("").unwrap();
+    let configured = task.create_channel(ch1).unwrap();
+    assert_eq!(configured.high_ticks().unwrap(), 20);
+    assert_eq!(configured.low_ticks().unwrap(), 30);
+    assert_eq!(configured.initial_delay().unwrap(), 5);
+    assert_eq!(
+        configured.timebase_source().unwrap(),
+        "/PXI1Slot2/20MHzTimebase"
+    );
+}
+
+#[test]
+fn test_pulse_ticks_builder_rejects_large_counts() {
+    let result = PulseTicksChannelBuilder::new("PXI1Slot2/ctr1")
+        .unwrap()
+        .high_ticks(u32::MAX);
+    assert!(matches!(
+        result,
+        Err(DaqmxError::ValueOutOfRange("high ticks", value)) if value == u64::from(u32::MAX)
+    ));
+}
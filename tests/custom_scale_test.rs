@@ -1 +1,292 @@
+//! Integration tests for creating custom scales and using them on channels.
+use daqmx::channels::ai_channels::voltage::{Voltage, VoltageChannelBuilder, VoltageScale};
+use daqmx::channels::*;
+use daqmx::error::DaqmxError;
+use daqmx::scales::*;
+use daqmx::tasks::*;
 
+fn scaled_channel(scale: &str) -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let channel = VoltageChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .scale(VoltageScale::new_custom(scale).unwrap());
+    task.create_channel(channel).unwrap();
+    task
+}
+
+#[test]
+fn test_map_scale() {
+    MapScale::new(
+        "daqmx-rs map scale",
+        0.0,
+        10.0,
+        0.0,
+        100.0,
+        PreScaledUnits::Volts,
+        "%",
+    )
+    .unwrap();
+    let task = scaled_channel("daqmx-rs map scale");
+    let channel: TaskChannel<Voltage> = task.get_channel("PXI1Slot2/ai0").unwrap();
+    assert_eq!(channel.custom_scale_name().unwrap(), "daqmx-rs map scale");
+}
+
+#[test]
+fn test_map_scale_rejects_empty_range() {
+    let result = MapScale::new(
+        "daqmx-rs empty map scale",
+        1.0,
+        1.0,
+        0.0,
+        100.0,
+        PreScaledUnits::Volts,
+        "%",
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_polynomial_scale() {
+    PolynomialScale::new(
+        "daqmx-rs polynomial scale",
+        &[1.0, 2.0],
+        &[-0.5, 0.5],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    scaled_channel("daqmx-rs polynomial scale");
+}
+
+#[test]
+fn test_polynomial_reverse_coefficients() {
+    // y = 1 + 2x reverses to x = -0.5 + 0.5y.
+    let reverse =
+        PolynomialScale::calculate_reverse_coefficients(&[1.0, 2.0], -10.0, 10.0, 100, None)
+            .unwrap();
+    assert_eq!(reverse.len(), 2);
+    assert!((reverse[0] + 0.5).abs() < 1e-6);
+    assert!((reverse[1] - 0.5).abs() < 1e-6);
+
+    let reverse =
+        PolynomialScale::calculate_reverse_coefficients(&[0.0, 1.0, 0.1], 0.0, 5.0, 100, Some(3))
+            .unwrap();
+    assert_eq!(reverse.len(), 4);
+}
+
+#[test]
+fn test_polynomial_reverse_coefficients_rejects_large_order() {
+    let result = PolynomialScale::calculate_reverse_coefficients(
+        &[1.0, 2.0],
+        -10.0,
+        10.0,
+        100,
+        Some(u32::MAX),
+    );
+    assert!(matches!(
+        result,
+        Err(DaqmxError::ValueOutOfRange("reverse order", _))
+    ));
+}
+
+#[test]
+fn test_polynomial_scale_from_forward_coefficients() {
+    PolynomialScale::from_forward_coefficients(
+        "daqmx-rs forward polynomial scale",
+        &[0.0, 1.0, 0.01],
+        0.0,
+        10.0,
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    scaled_channel("daqmx-rs forward polynomial scale");
+}
+
+#[test]
+fn test_table_scale() {
+    TableScale::new(
+        "daqmx-rs table scale",
+        &[0.0, 1.0, 5.0],
+        &[0.0, 0.5, 10.0],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    scaled_channel("daqmx-rs table scale");
+}
+
+#[test]
+fn test_table_scale_rejects_mismatched_lengths() {
+    let result = TableScale::new(
+        "daqmx-rs mismatched table scale",
+        &[0.0, 1.0, 5.0],
+        &[0.0, 0.5],
+        PreScaledUnits::Volts,
+        "bar",
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_open_linear_scale_properties() {
+    let created = LinearScale::new(
+        "daqmx-rs open linear scale",
+        2.5,
+        -1.0,
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    created.set_description("Inlet pressure").unwrap();
+
+    let scale = LinearScale::open("daqmx-rs open linear scale").unwrap();
+    assert_eq!(scale.scale_type().unwrap(), ScaleType::Linear);
+    assert_eq!(scale.slope().unwrap(), 2.5);
+    assert_eq!(scale.y_intercept().unwrap(), -1.0);
+    assert_eq!(scale.pre_scaled_units().unwrap(), PreScaledUnits::Volts);
+    assert_eq!(scale.scaled_units().unwrap(), "bar");
+    assert_eq!(scale.description().unwrap(), "Inlet pressure");
+
+    scale.set_slope(5.0).unwrap();
+    scale.set_scaled_units("kPa").unwrap();
+    assert_eq!(created.slope().unwrap(), 5.0);
+    assert_eq!(created.scaled_units().unwrap(), "kPa");
+}
+
+#[test]
+fn test_open_scale_used_by_channel() {
+    MapScale::new(
+        "daqmx-rs channel map scale",
+        0.0,
+        10.0,
+        0.0,
+        100.0,
+        PreScaledUnits::Volts,
+        "%",
+    )
+    .unwrap();
+    let task = scaled_channel("daqmx-rs channel map scale");
+    let channel: TaskChannel<Voltage> = task.get_channel("PXI1Slot2/ai0").unwrap();
+
+    let VoltageScale::CustomScale(Some(name)) = channel.scale().unwrap() else {
+        panic!("expected a custom scale");
+    };
+    let scale = CustomScale::open(name.as_ref().clone()).unwrap();
+    let map = scale.into_map().unwrap();
+    assert_eq!(map.pre_scaled_max().unwrap(), 10.0);
+    assert_eq!(map.scaled_max().unwrap(), 100.0);
+}
+
+#[test]
+fn test_open_array_scale_properties() {
+    PolynomialScale::new(
+        "daqmx-rs open polynomial scale",
+        &[1.0, 2.0],
+        &[-0.5, 0.5],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    let polynomial = PolynomialScale::open("daqmx-rs open polynomial scale").unwrap();
+    assert_eq!(polynomial.forward_coefficients().unwrap(), vec![1.0, 2.0]);
+    assert_eq!(polynomial.reverse_coefficients().unwrap(), vec![-0.5, 0.5]);
+
+    TableScale::new(
+        "daqmx-rs open table scale",
+        &[0.0, 1.0, 5.0],
+        &[0.0, 0.5, 10.0],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    let table = TableScale::open("daqmx-rs open table scale").unwrap();
+    assert_eq!(table.pre_scaled_values().unwrap(), vec![0.0, 1.0, 5.0]);
+    assert_eq!(table.scaled_values().unwrap(), vec![0.0, 0.5, 10.0]);
+}
+
+#[test]
+fn test_open_scale_checks_type() {
+    TableScale::new(
+        "daqmx-rs wrong type scale",
+        &[0.0, 1.0],
+        &[0.0, 2.0],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    let result = LinearScale::open("daqmx-rs wrong type scale");
+    assert!(matches!(
+        result,
+        Err(DaqmxError::ScaleTypeMismatch(
+            _,
+            ScaleType::Linear,
+            ScaleType::Table
+        ))
+    ));
+}
+
+#[test]
+fn test_open_missing_scale() {
+    assert!(CustomScale::open("daqmx-rs missing scale").is_err());
+}
+
+#[test]
+fn test_scaling_from_stored_parameters() {
+    let linear = LinearScale::new(
+        "daqmx-rs linear scaling",
+        2.5,
+        -1.0,
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    let scaling = LinearScale::open("daqmx-rs linear scaling")
+        .unwrap()
+        .scaling()
+        .unwrap();
+    assert_eq!(scaling, linear.scaling().unwrap());
+    assert_eq!(scaling.apply(2.0), 4.0);
+    assert_eq!(scaling.reverse(4.0), 2.0);
+
+    let map = MapScale::new(
+        "daqmx-rs map scaling",
+        1.0,
+        5.0,
+        0.0,
+        100.0,
+        PreScaledUnits::Volts,
+        "%",
+    )
+    .unwrap();
+    let scaling = map.scaling().unwrap();
+    assert_eq!(scaling.apply(3.0), 50.0);
+    assert_eq!(scaling.reverse(50.0), 3.0);
+
+    let polynomial = PolynomialScale::new(
+        "daqmx-rs polynomial scaling",
+        &[1.0, 2.0],
+        &[-0.5, 0.5],
+        PreScaledUnits::Volts,
+        "bar",
+    )
+    .unwrap();
+    let scaling = polynomial.scaling().unwrap();
+    let mut values = [0.0, 1.0, 2.0];
+    scaling.apply_in_place(&mut values);
+    assert_eq!(values, [1.0, 3.0, 5.0]);
+    scaling.reverse_in_place(&mut values);
+    assert_eq!(values, [0.0, 1.0, 2.0]);
+
+    let table = TableScale::new(
+        "daqmx-rs table scaling",
+        &[0.0, 1.0, 5.0],
+        &[0.0, 0.5, 10.0],
+        PreScaledUnits::Volts,
+        "l/min",
+    )
+    .unwrap();
+    let scaling = table.scaling().unwrap();
+    assert_eq!(scaling.apply(3.0), 5.25);
+    assert_eq!(scaling.reverse(5.25), 3.0);
+}
@@ -0,0 +1,81 @@
+//! Integration tests for covering the digital input and output tasks and channels.
+//!
+use daqmx::channels::digital_channels::*;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+#[test]
+fn test_port_scalar_read() {
+    let mut task: Task<DigitalInput> = Task::new("").unwrap();
+    let ch1 = DigitalInputChannelBuilder::new("PXI1Slot2/port1")
+        .unwrap()
+        .line_grouping(LineGrouping::ChannelForAllLines);
+    task.create_channel(ch1).unwrap();
+    let _: u8 = task.read_scalar(Timeout::Seconds(1.0)).unwrap();
+    let _: u32 = task.read_scalar(Timeout::Seconds(1.0)).unwrap();
+}
+
+#[test]
+fn test_line_read() {
+    let mut task: Task<DigitalInput> = Task::new("").unwrap();
+    let ch1 = DigitalInputChannelBuilder::new("PXI1Slot2/port1/line0:3").unwrap();
+    task.create_channel(ch1).unwrap();
+
+    let mut buffer = [false; 4];
+    let read = task
+        .read(
+            Timeout::Seconds(1.0),
+            DataFillMode::GroupByChannel,
+            Some(1),
+            &mut buffer[..],
+        )
+        .unwrap();
+    assert_eq!(read.samples_per_channel, 1);
+    assert!(read.is_complete());
+}
+
+#[test]
+fn test_line_scalar_write() {
+    let mut task: Task<DigitalOutput> = Task::new("").unwrap();
+    let ch1 = DigitalOutputChannelBuilder::new("PXI1Slot2/port1/line0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.write_scalar(true, Timeout::Seconds(1.0), true).unwrap();
+}
+
+#[test]
+fn test_line_write_checks_buffer_size() {
+    let mut task: Task<DigitalOutput> = Task::new("").unwrap();
+    let ch1 = DigitalOutputChannelBuilder::new("PXI1Slot2/port1/line0:1")
+        .unwrap()
+        .line_grouping(LineGrouping::ChannelForAllLines);
+    task.create_channel(ch1).unwrap();
+
+    // One channel but two lines so a single value can't be written.
+    let result = task.write_scalar(true, Timeout::Seconds(1.0), true);
+    assert_eq!(result, Err(daqmx::error::DaqmxError::WriteBufferSize(1, 2)));
+
+    task.write(
+        true,
+        Timeout::Seconds(1.0),
+        DataFillMode::GroupByChannel,
+        &[true, false],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_port_write() {
+    let mut task: Task<DigitalOutput> = Task::new("").unwrap();
+    let ch1 = DigitalOutputChannelBuilder::new("PXI1Slot2/port1")
+        .unwrap()
+        .name("relays")
+        .unwrap()
+        .line_grouping(LineGrouping::ChannelForAllLines);
+    task.create_channel(ch1).unwrap();
+    task.write_scalar(true, Timeout::Seconds(1.0), 0xA5u8).unwrap();
+
+    let channel: TaskChannel<DigitalOutputChannel> = task.get_channel("relays").unwrap();
+    assert_eq!(channel.do_number_of_lines().unwrap(), 8);
+    assert!(!channel.do_invert_lines().unwrap());
+}
@@ -0,0 +1,136 @@
+//! Integration tests for the task event callbacks.
+//!
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc;
+
+fn finite_task(samples: u64) -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::FiniteSamples,
+        samples,
+    )
+    .unwrap();
+    task
+}
+
+#[test]
+fn test_every_n_samples_and_done_events() {
+    let mut task = finite_task(100);
+
+    let events = Arc::new(AtomicU32::new(0));
+    let callback_events = events.clone();
+    let samples_event = task
+        .register_every_n_samples_event(
+            EveryNSamplesEventType::AcquiredIntoBuffer,
+            10,
+            move |samples| {
+                assert_eq!(samples, 10);
+                callback_events.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let done_event = task
+        .register_done_event(move |status| {
+            sender.send(status).unwrap();
+        })
+        .unwrap();
+
+    task.start().unwrap();
+    let status = receiver
+        .recv_timeout(std::time::Duration::from_secs(2))
+        .unwrap();
+    assert_eq!(status, Ok(()));
+    task.stop().unwrap();
+
+    assert!(events.load(Ordering::SeqCst) > 0);
+    samples_event.unregister().unwrap();
+    done_event.unregister().unwrap();
+}
+
+#[test]
+fn test_reregister_after_drop() {
+    let mut task = finite_task(100);
+
+    let registration = task.register_done_event(|_| {}).unwrap();
+    drop(registration);
+
+    // The first registration is released so we can register again.
+    let _registration = task.register_done_event(|_| {}).unwrap();
+}
+
+#[test]
+fn test_signal_event() {
+    let mut task = finite_task(100);
+
+    let fired = Arc::new(AtomicBool::new(false));
+    let callback_fired = fired.clone();
+    let registration = task
+        .register_signal_event(Signal::SampleClock, move || {
+            callback_fired.store(true, Ordering::SeqCst);
+        })
+        .unwrap();
+
+    task.start().unwrap();
+    task.wait_until_done(Timeout::Seconds(1.0)).unwrap();
+    assert!(fired.load(Ordering::SeqCst));
+    task.stop().unwrap();
+    registration.unregister().unwrap();
+}
+
+/// Sets the flag when dropped, to check when a closure is freed.
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_failed_unregister_still_clears_task() {
+    let mut task: Task<AnalogInput> = Task::new("daqmx-rs parked callback").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::ContinuousSamples,
+        1000,
+    )
+    .unwrap();
+
+    // The closure holds a clone of the task, as is common for reading in the callback.
+    let freed = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(freed.clone());
+    let callback_task = task.clone();
+    let registration = task
+        .register_done_event(move |_| {
+            let _ = (&flag, &callback_task);
+        })
+        .unwrap();
+
+    task.start().unwrap();
+    // DAQmx refuses to unregister while the task is running.
+    assert!(registration.unregister().is_err());
+    assert!(!freed.load(Ordering::SeqCst));
+
+    task.stop().unwrap();
+    assert!(freed.load(Ordering::SeqCst));
+
+    // Dropping the last clone clears the task, which frees its name.
+    drop(task);
+    let _task: Task<AnalogInput> = Task::new("daqmx-rs parked callback").unwrap();
+}
@@ -0,0 +1,140 @@
+//! Integration tests for saving to and loading from the MAX configuration.
+//!
+//! These clean up after themselves but will leave items in MAX if they fail.
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::error::DaqmxError;
+use daqmx::persistence::{self, SaveOptions};
+use daqmx::scales::{LinearScale, PreScaledUnits};
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+fn voltage_task(name: &str) -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new(name).unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .name("saved voltage")
+        .unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::FiniteSamples,
+        100,
+    )
+    .unwrap();
+    task
+}
+
+#[test]
+fn test_save_and_load_task() {
+    let task = voltage_task("daqmx-rs saved task");
+    task.save(&SaveOptions {
+        overwrite: true,
+        ..Default::default()
+    })
+    .unwrap();
+    drop(task);
+
+    assert!(
+        persistence::saved_tasks()
+            .unwrap()
+            .contains(&"daqmx-rs saved task".to_owned())
+    );
+
+    let mut loaded: Task<AnalogInput> = Task::load("daqmx-rs saved task").unwrap();
+    assert_eq!(loaded.name().unwrap(), "daqmx-rs saved task");
+    assert_eq!(loaded.channel_names().unwrap(), vec!["saved voltage"]);
+    let mut buffer = [0.0; 100];
+    let read = loaded
+        .read(
+            Timeout::Seconds(1.0),
+            DataFillMode::GroupByChannel,
+            Some(100),
+            &mut buffer[..],
+        )
+        .unwrap();
+    assert_eq!(read.samples_per_channel, 100);
+    drop(loaded);
+
+    persistence::delete_saved_task("daqmx-rs saved task").unwrap();
+    assert!(
+        !persistence::saved_tasks()
+            .unwrap()
+            .contains(&"daqmx-rs saved task".to_owned())
+    );
+}
+
+#[test]
+fn test_save_without_overwrite_fails() {
+    let task = voltage_task("");
+    let options = SaveOptions {
+        save_as: Some("daqmx-rs no overwrite".to_owned()),
+        ..Default::default()
+    };
+    task.save(&options).unwrap();
+    assert!(task.save(&options).is_err());
+    persistence::delete_saved_task("daqmx-rs no overwrite").unwrap();
+}
+
+#[test]
+fn test_load_wrong_task_type() {
+    let task = voltage_task("");
+    task.save(&SaveOptions {
+        save_as: Some("daqmx-rs wrong type".to_owned()),
+        overwrite: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let loaded = Task::<DigitalInput>::load("daqmx-rs wrong type");
+    assert!(matches!(loaded, Err(DaqmxError::TaskTypeMismatch(_))));
+    persistence::delete_saved_task("daqmx-rs wrong type").unwrap();
+}
+
+#[test]
+fn test_save_global_channel() {
+    let task = voltage_task("");
+    task.save_global_channel(
+        "saved voltage",
+        &SaveOptions {
+            save_as: Some("daqmx-rs global channel".to_owned()),
+            overwrite: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(
+        persistence::saved_global_channels()
+            .unwrap()
+            .contains(&"daqmx-rs global channel".to_owned())
+    );
+    persistence::delete_saved_global_channel("daqmx-rs global channel").unwrap();
+}
+
+#[test]
+fn test_save_scale() {
+    let scale = LinearScale::new(
+        "daqmx-rs saved scale",
+        2.0,
+        0.0,
+        PreScaledUnits::Volts,
+        "test",
+    )
+    .unwrap();
+    scale
+        .save(&SaveOptions {
+            overwrite: true,
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert!(
+        persistence::saved_scales()
+            .unwrap()
+            .contains(&"daqmx-rs saved scale".to_owned())
+    );
+    persistence::delete_saved_scale("daqmx-rs saved scale").unwrap();
+}
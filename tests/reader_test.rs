@@ -0,0 +1,103 @@
+//! Integration tests for the reader and waveform types.
+//!
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+fn two_channel_task() -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .name("first")
+        .unwrap();
+    let ch2 = VoltageChannelBuilder::new("PXI1Slot2/ai1")
+        .unwrap()
+        .name("second")
+        .unwrap();
+    task.create_channel(ch1).unwrap();
+    task.create_channel(ch2).unwrap();
+    task
+}
+
+#[test]
+fn test_channel_names() {
+    let task = two_channel_task();
+    assert_eq!(task.channel_names().unwrap(), vec!["first", "second"]);
+}
+
+#[test]
+fn test_finite_read() {
+    let mut task = two_channel_task();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::FiniteSamples,
+        100,
+    )
+    .unwrap();
+
+    let mut reader: Reader<_, f64> = Reader::new(task, 50).unwrap();
+    assert_eq!(reader.number_of_channels(), 2);
+
+    let waveform = reader.read(Timeout::Seconds(1.0), Some(50)).unwrap();
+    assert_eq!(waveform.samples_per_channel(), 50);
+    assert_eq!(waveform.channel_by_name("second").unwrap().len(), 50);
+    assert_eq!(waveform.samples().count(), 50);
+    assert_eq!(waveform.t0(), Some(0.0));
+    assert_eq!(waveform.dt(), Some(0.001));
+
+    let waveform = reader.read(Timeout::Seconds(1.0), Some(50)).unwrap();
+    assert_eq!(waveform.first_sample(), 50);
+}
+
+#[test]
+fn test_read_limited_to_capacity() {
+    let mut task = two_channel_task();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::ContinuousSamples,
+        1000,
+    )
+    .unwrap();
+
+    let mut reader: Reader<_, f64> = Reader::new(task, 10).unwrap();
+    reader.task().start().unwrap();
+    let waveform = reader.read(Timeout::Seconds(1.0), Some(100)).unwrap();
+    assert_eq!(waveform.samples_per_channel(), 10);
+    reader.task().stop().unwrap();
+}
+
+#[test]
+fn test_read_all_fills_buffer() {
+    let mut task = two_channel_task();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::ContinuousSamples,
+        1000,
+    )
+    .unwrap();
+
+    let mut reader: Reader<_, f64> = Reader::new(task, 10).unwrap();
+    reader.task().start().unwrap();
+    // Let more samples build up than the reader can hold.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    let waveform = reader.read(Timeout::Seconds(1.0), None).unwrap();
+    assert_eq!(waveform.samples_per_channel(), 10);
+    assert!(waveform.error().is_none());
+    reader.task().stop().unwrap();
+}
+
+#[test]
+fn test_on_demand_read() {
+    let task = two_channel_task();
+    let mut reader: Reader<_, f64> = Reader::new(task, 1).unwrap();
+    let waveform: AnalogWaveform = reader.read(Timeout::Seconds(1.0), Some(1)).unwrap();
+    assert_eq!(waveform.sample(0).unwrap().to_vec().len(), 2);
+    assert_eq!(waveform.t0(), None);
+}
@@ -0,0 +1,715 @@
+//! Integration tests for the sensor channel kinds, such as strain gages, bridges, load cells,
+//! IEPE sensors, thermistors and resistances, and the RMS and frequency measurements.
+//!
+//! The X Series test device can't make these measurements, so these only run against the
+//! simulation.
+#![cfg(feature = "simulation")]
+use daqmx::channels::ai_channels::bridge::{
+    Bridge, BridgeChannelBuilder, BridgeConfiguration, BridgeScale,
+};
+use daqmx::channels::ai_channels::bridge_sensor::{
+    BridgeCalibration, BridgeSensorScale, ForceBridge, ForceBridgeChannelBuilder, PressureBridge,
+    PressureBridgeChannelBuilder, TorqueBridge, TorqueBridgeChannelBuilder,
+};
+use daqmx::channels::ai_channels::current::current_dc::{CurrentScale, ShuntResistorLocation};
+use daqmx::channels::ai_channels::current::current_rms::{CurrentRms, CurrentRmsChannelBuilder};
+use daqmx::channels::ai_channels::frequency::{
+    FrequencyScale, FrequencyVoltage, FrequencyVoltageChannelBuilder,
+};
+use daqmx::channels::ai_channels::iepe::{
+    AccelerationScale, Accelerometer, AccelerometerChannelBuilder, AccelerometerSensitivityUnits,
+    Coupling, ForceIepe, ForceIepeChannelBuilder, ForceIepeSensitivityUnits, ForceScale,
+    Microphone, MicrophoneChannelBuilder,
+};
+use daqmx::channels::ai_channels::resistance::{
+    ExcitationSource, Resistance, ResistanceChannelBuilder, ResistanceScale, WireConfiguration,
+};
+use daqmx::channels::ai_channels::strain::{
+    StrainGage, StrainGageChannelBuilder, StrainGageConfiguration, StrainScale,
+};
+use daqmx::channels::ai_channels::temperature::{
+    BuiltInTemperatureSensor, BuiltInTemperatureSensorBuilder, SteinhartHartCoefficients,
+    TemperatureUnits, Thermistor, ThermistorBuilder, ThermistorExcitation,
+};
+use daqmx::channels::ai_channels::voltage::{VoltageRms, VoltageRmsChannelBuilder, VoltageScale};
+use daqmx::channels::ai_channels::{
+    AnalogChannelBuilder, AnalogTerminalConfig, AnyAnalogInputChannel,
+};
+use daqmx::channels::*;
+use daqmx::scales::{LinearScale, PreScaledUnits};
+use daqmx::tasks::*;
+
+#[test]
+fn test_strain_gage_builder() {
+    let channel = StrainGageChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .name("strain")
+        .unwrap()
+        .min(-0.002)
+        .max(0.002)
+        .strain_gage_configuration(StrainGageConfiguration::QuarterBridgeI)
+        .excitation_source(ExcitationSource::External)
+        .excitation_voltage(5.0)
+        .gage_factor(2.1)
+        .nominal_gage_resistance(120.0)
+        .poisson_ratio(0.285)
+        .lead_wire_resistance(0.5);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<StrainGage> = task.get_channel("strain").unwrap();
+    assert_eq!(channel.ai_max().unwrap(), 0.002);
+    assert_eq!(channel.scale().unwrap(), StrainScale::Strain);
+    assert_eq!(
+        channel.strain_gage_configuration().unwrap(),
+        StrainGageConfiguration::QuarterBridgeI
+    );
+    assert_eq!(
+        channel.bridge_configuration().unwrap(),
+        BridgeConfiguration::QuarterBridge
+    );
+    assert_eq!(
+        channel.excitation_source().unwrap(),
+        ExcitationSource::External
+    );
+    assert_eq!(channel.excitation_voltage().unwrap(), 5.0);
+    assert_eq!(channel.gage_factor().unwrap(), 2.1);
+    assert_eq!(channel.nominal_bridge_resistance().unwrap(), 120.0);
+    assert_eq!(channel.poisson_ratio().unwrap(), 0.285);
+    assert_eq!(channel.lead_wire_resistance().unwrap(), 0.5);
+
+    channel.set_gage_factor(2.05).unwrap();
+    assert_eq!(channel.gage_factor().unwrap(), 2.05);
+    assert!(matches!(
+        task.get_any_channel("strain").unwrap(),
+        AnyAnalogInputChannel::StrainGage(_)
+    ));
+}
+
+#[test]
+fn test_bridge_builder() {
+    let channel = BridgeChannelBuilder::new("PXI1Slot2/ai1")
+        .unwrap()
+        .scale(BridgeScale::MilliVoltsPerVolt)
+        .min(-2.0)
+        .max(2.0)
+        .bridge_configuration(BridgeConfiguration::HalfBridge)
+        .excitation_voltage(10.0)
+        .nominal_bridge_resistance(1000.0);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<Bridge> = task.get_channel("PXI1Slot2/ai1").unwrap();
+    assert_eq!(channel.scale().unwrap(), BridgeScale::MilliVoltsPerVolt);
+    assert_eq!(
+        channel.bridge_configuration().unwrap(),
+        BridgeConfiguration::HalfBridge
+    );
+    assert_eq!(
+        channel.excitation_source().unwrap(),
+        ExcitationSource::Internal
+    );
+    assert_eq!(channel.excitation_voltage().unwrap(), 10.0);
+    assert_eq!(channel.nominal_bridge_resistance().unwrap(), 1000.0);
+    assert!(matches!(
+        task.get_any_channel("PXI1Slot2/ai1").unwrap(),
+        AnyAnalogInputChannel::Bridge(_)
+    ));
+}
+
+#[test]
+fn test_bridge_custom_scale() {
+    LinearScale::new(
+        "daqmx-rs bridge scale",
+        1000.0,
+        0.0,
+        PreScaledUnits::VoltsPerVolt,
+        "kg",
+    )
+    .unwrap();
+    let channel = BridgeChannelBuilder::new("PXI1Slot2/ai2")
+        .unwrap()
+        .scale(BridgeScale::new_custom("daqmx-rs bridge scale").unwrap());
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let channel = task.create_channel(channel).unwrap();
+
+    assert_eq!(
+        channel.scale().unwrap(),
+        BridgeScale::new_custom("daqmx-rs bridge scale").unwrap()
+    );
+}
+
+#[test]
+fn test_accelerometer_builder() {
+    let channel = AccelerometerChannelBuilder::new("PXI1Slot2/ai3")
+        .unwrap()
+        .name("accel")
+        .unwrap()
+        .min(-50.0)
+        .max(50.0)
+        .scale(AccelerationScale::MetersPerSecondSquared)
+        .sensitivity(10.2)
+        .sensitivity_units(AccelerometerSensitivityUnits::MilliVoltsPerG)
+        .excitation_current(0.002)
+        .coupling(Coupling::DC);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<Accelerometer> = task.get_channel("accel").unwrap();
+    assert_eq!(channel.ai_max().unwrap(), 50.0);
+    assert_eq!(
+        channel.scale().unwrap(),
+        AccelerationScale::MetersPerSecondSquared
+    );
+    assert_eq!(channel.sensitivity().unwrap(), 10.2);
+    assert_eq!(
+        channel.sensitivity_units().unwrap(),
+        AccelerometerSensitivityUnits::MilliVoltsPerG
+    );
+    assert_eq!(
+        channel.excitation_source().unwrap(),
+        ExcitationSource::Internal
+    );
+    assert_eq!(channel.excitation_current().unwrap(), 0.002);
+    assert_eq!(channel.coupling().unwrap(), Coupling::DC);
+
+    channel.set_coupling(Coupling::AC).unwrap();
+    assert_eq!(channel.coupling().unwrap(), Coupling::AC);
+    assert!(matches!(
+        task.get_any_channel("accel").unwrap(),
+        AnyAnalogInputChannel::Accelerometer(_)
+    ));
+}
+
+#[test]
+fn test_microphone_builder() {
+    let channel = MicrophoneChannelBuilder::new("PXI1Slot2/ai4")
+        .unwrap()
+        .sensitivity(50.0)
+        .max_sound_pressure_level(120.0);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<Microphone> = task.get_channel("PXI1Slot2/ai4").unwrap();
+    assert_eq!(channel.sensitivity().unwrap(), 50.0);
+    assert_eq!(channel.max_sound_pressure_level().unwrap(), 120.0);
+    assert_eq!(channel.coupling().unwrap(), Coupling::AC);
+    assert!(matches!(
+        task.get_any_channel("PXI1Slot2/ai4").unwrap(),
+        AnyAnalogInputChannel::Microphone(_)
+    ));
+}
+
+#[test]
+fn test_force_iepe_builder() {
+    let channel = ForceIepeChannelBuilder::new("PXI1Slot2/ai5")
+        .unwrap()
+        .scale(ForceScale::Pounds)
+        .min(-100.0)
+        .max(100.0)
+        .sensitivity(10.0)
+        .sensitivity_units(ForceIepeSensitivityUnits::MilliVoltsPerPound);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<ForceIepe> = task.get_channel("PXI1Slot2/ai5").unwrap();
+    assert_eq!(channel.scale().unwrap(), ForceScale::Pounds);
+    assert_eq!(channel.sensitivity().unwrap(), 10.0);
+    assert_eq!(
+        channel.sensitivity_units().unwrap(),
+        ForceIepeSensitivityUnits::MilliVoltsPerPound
+    );
+    assert!(matches!(
+        task.get_any_channel("PXI1Slot2/ai5").unwrap(),
+        AnyAnalogInputChannel::ForceIepe(_)
+    ));
+}
+
+#[test]
+fn test_force_bridge_two_point_linear() {
+    let calibration = BridgeCalibration::TwoPointLinear {
+        first_electrical_value: 0.0,
+        first_physical_value: 0.0,
+        second_electrical_value: 3.0,
+        second_physical_value: 500.0,
+    };
+    let channel = ForceBridgeChannelBuilder::new("PXI1Slot2/ai6")
+        .unwrap()
+        .name("load")
+        .unwrap()
+        .min(-500.0)
+        .max(500.0)
+        .scale(BridgeSensorScale::Units(PreScaledUnits::Newtons))
+        .calibration(calibration.clone())
+        .physical_units(PreScaledUnits::Pounds);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<ForceBridge> = task.get_channel("load").unwrap();
+    assert_eq!(
+        channel.scale().unwrap(),
+        BridgeSensorScale::Units(PreScaledUnits::Newtons)
+    );
+    assert_eq!(channel.calibration().unwrap(), calibration);
+    assert_eq!(
+        channel.electrical_units().unwrap(),
+        PreScaledUnits::MilliVoltsPerVolt
+    );
+    assert_eq!(channel.physical_units().unwrap(), PreScaledUnits::Pounds);
+    assert_eq!(channel.nominal_bridge_resistance().unwrap(), 350.0);
+    assert!(matches!(
+        task.get_any_channel("load").unwrap(),
+        AnyAnalogInputChannel::ForceBridge(_)
+    ));
+}
+
+#[test]
+fn test_pressure_bridge_table() {
+    let calibration = BridgeCalibration::Table {
+        electrical_values: vec![0.0, 1.0, 2.05],
+        physical_values: vec![0.0, 50.0, 100.0],
+    };
+    let channel = PressureBridgeChannelBuilder::new("PXI1Slot2/ai7")
+        .unwrap()
+        .scale(BridgeSensorScale::Units(PreScaledUnits::Bar))
+        .calibration(calibration.clone());
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<PressureBridge> = task.get_channel("PXI1Slot2/ai7").unwrap();
+    assert_eq!(
+        channel.scale().unwrap(),
+        BridgeSensorScale::Units(PreScaledUnits::Bar)
+    );
+    assert_eq!(channel.physical_units().unwrap(), PreScaledUnits::PSI);
+    assert_eq!(channel.calibration().unwrap(), calibration);
+}
+
+#[test]
+fn test_torque_bridge_polynomial() {
+    let calibration = BridgeCalibration::Polynomial {
+        forward_coefficients: vec![0.0, 10.0, 0.1],
+        reverse_coefficients: vec![0.0, 0.1],
+    };
+    let channel = TorqueBridgeChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .calibration(calibration.clone())
+        .electrical_units(PreScaledUnits::VoltsPerVolt);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<TorqueBridge> = task.get_channel("PXI1Slot2/ai0").unwrap();
+    assert_eq!(
+        channel.scale().unwrap(),
+        BridgeSensorScale::Units(PreScaledUnits::NewtonMeters)
+    );
+    assert_eq!(channel.calibration().unwrap(), calibration);
+    assert_eq!(
+        channel.electrical_units().unwrap(),
+        PreScaledUnits::VoltsPerVolt
+    );
+}
+
+#[test]
+fn test_bridge_table_lengths_must_match() {
+    let channel = ForceBridgeChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .calibration(BridgeCalibration::Table {
+            electrical_values: vec![0.0, 1.0],
+            physical_values: vec![0.0],
+        });
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    assert!(task.create_channel(channel).is_err());
+}
+
+#[test]
+fn test_thermistor_current_excitation() {
+    let coefficients = SteinhartHartCoefficients {
+        a: 0.001125,
+        b: 0.000234,
+        c: 0.0000000876,
+    };
+    let channel = ThermistorBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .name("thermistor")
+        .unwrap()
+        .min(-20.0)
+        .max(80.0)
+        .units(TemperatureUnits::Kelvin)
+        .wire_configuration(WireConfiguration::TwoWire)
+        .excitation(ThermistorExcitation::Current(0.0001))
+        .coefficients(coefficients);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<Thermistor> = task.get_channel("thermistor").unwrap();
+    assert_eq!(channel.ai_max().unwrap(), 80.0);
+    assert_eq!(
+        channel.temperature_units().unwrap(),
+        TemperatureUnits::Kelvin
+    );
+    assert_eq!(
+        channel.wire_configuration().unwrap(),
+        WireConfiguration::TwoWire
+    );
+    assert_eq!(
+        channel.excitation().unwrap(),
+        ThermistorExcitation::Current(0.0001)
+    );
+    assert_eq!(channel.coefficients().unwrap(), coefficients);
+
+    channel
+        .set_coefficients(SteinhartHartCoefficients::default())
+        .unwrap();
+    assert_eq!(
+        channel.coefficients().unwrap(),
+        SteinhartHartCoefficients::default()
+    );
+    assert!(matches!(
+        task.get_any_channel("thermistor").unwrap(),
+        AnyAnalogInputChannel::Thermistor(_)
+    ));
+}
+
+#[test]
+fn test_thermistor_voltage_excitation() {
+    let channel = ThermistorBuilder::new("PXI1Slot2/ai1").unwrap().excitation(
+        ThermistorExcitation::Voltage {
+            voltage: 5.0,
+            r1: 10000.0,
+        },
+    );
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let channel = task.create_channel(channel).unwrap();
+
+    assert_eq!(
+        channel.excitation().unwrap(),
+        ThermistorExcitation::Voltage {
+            voltage: 5.0,
+            r1: 10000.0
+        }
+    );
+    assert_eq!(
+        channel.excitation_source().unwrap(),
+        ExcitationSource::Internal
+    );
+}
+
+#[test]
+fn test_built_in_temperature_sensor() {
+    let channel = BuiltInTemperatureSensorBuilder::new("PXI1Slot2/_boardTempSensor_vs_aignd")
+        .unwrap()
+        .name("board")
+        .unwrap()
+        .units(TemperatureUnits::Fahrenheit);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<BuiltInTemperatureSensor> = task.get_channel("board").unwrap();
+    assert_eq!(
+        channel.temperature_units().unwrap(),
+        TemperatureUnits::Fahrenheit
+    );
+    assert!(matches!(
+        task.get_any_channel("board").unwrap(),
+        AnyAnalogInputChannel::BuiltInTemperatureSensor(_)
+    ));
+}
+
+#[test]
+fn test_resistance_builder() {
+    let channel = ResistanceChannelBuilder::new("PXI1Slot2/ai2")
+        .unwrap()
+        .name("heater")
+        .unwrap()
+        .min(0.0)
+        .max(50.0)
+        .wire_configuration(WireConfiguration::TwoWire)
+        .excitation_current(0.01)
+        .lead_wire_resistance(0.2);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<Resistance> = task.get_channel("heater").unwrap();
+    assert_eq!(channel.ai_max().unwrap(), 50.0);
+    assert_eq!(channel.scale().unwrap(), ResistanceScale::Ohms);
+    assert_eq!(
+        channel.wire_configuration().unwrap(),
+        WireConfiguration::TwoWire
+    );
+    assert_eq!(
+        channel.excitation_source().unwrap(),
+        ExcitationSource::Internal
+    );
+    assert_eq!(channel.excitation_current().unwrap(), 0.01);
+    assert_eq!(channel.lead_wire_resistance().unwrap(), 0.2);
+
+    channel.reset_lead_wire_resistance().unwrap();
+    assert_eq!(channel.lead_wire_resistance().unwrap(), 0.0);
+    channel
+        .set_wire_configuration(WireConfiguration::FourWire)
+        .unwrap();
+    assert_eq!(
+        channel.wire_configuration().unwrap(),
+        WireConfiguration::FourWire
+    );
+    assert!(matches!(
+        task.get_any_channel("heater").unwrap(),
+        AnyAnalogInputChannel::Resistance(_)
+    ));
+}
+
+#[test]
+fn test_resistance_custom_scale() {
+    LinearScale::new(
+        "daqmx-rs contact scale",
+        1000.0,
+        0.0,
+        PreScaledUnits::Ohms,
+        "mOhm",
+    )
+    .unwrap();
+    let channel = ResistanceChannelBuilder::new("PXI1Slot2/ai3")
+        .unwrap()
+        .scale(ResistanceScale::new_custom("daqmx-rs contact scale").unwrap());
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let channel = task.create_channel(channel).unwrap();
+
+    assert_eq!(
+        channel.scale().unwrap(),
+        ResistanceScale::new_custom("daqmx-rs contact scale").unwrap()
+    );
+}
+
+#[test]
+fn test_voltage_rms_builder() {
+    let channel = VoltageRmsChannelBuilder::new("PXI1Slot2/ai0")
+        .unwrap()
+        .name("mains")
+        .unwrap()
+        .min(0.0)
+        .max(10.0)
+        .terminal_config(AnalogTerminalConfig::Differential);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<VoltageRms> = task.get_channel("mains").unwrap();
+    assert_eq!(channel.ai_min().unwrap(), 0.0);
+    assert_eq!(channel.ai_max().unwrap(), 10.0);
+    assert_eq!(
+        channel.terminal_config().unwrap(),
+        AnalogTerminalConfig::Differential
+    );
+    assert_eq!(channel.scale().unwrap(), VoltageScale::Volts);
+    assert!(matches!(
+        task.get_any_channel("mains").unwrap(),
+        AnyAnalogInputChannel::VoltageRms(_)
+    ));
+}
+
+#[test]
+fn test_current_rms_builder() {
+    let channel = CurrentRmsChannelBuilder::new("PXI1Slot2/ai1")
+        .unwrap()
+        .name("supply")
+        .unwrap()
+        .min(0.0)
+        .max(0.02)
+        .shunt_resistor_location(ShuntResistorLocation::External(100.0));
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<CurrentRms> = task.get_channel("supply").unwrap();
+    assert_eq!(channel.ai_max().unwrap(), 0.02);
+    assert_eq!(channel.scale().unwrap(), CurrentScale::Amps);
+    assert_eq!(
+        channel.shunt_resistor_location().unwrap(),
+        ShuntResistorLocation::External(100.0)
+    );
+    assert!(matches!(
+        task.get_any_channel("supply").unwrap(),
+        AnyAnalogInputChannel::CurrentRms(_)
+    ));
+}
+
+#[test]
+fn test_frequency_voltage_builder() {
+    let channel = FrequencyVoltageChannelBuilder::new("PXI1Slot2/ai2")
+        .unwrap()
+        .name("tacho")
+        .unwrap()
+        .min(10.0)
+        .max(1000.0)
+        .threshold_level(2.5)
+        .hysteresis(0.5);
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(channel).unwrap();
+
+    let channel: TaskChannel<FrequencyVoltage> = task.get_channel("tacho").unwrap();
+    assert_eq!(channel.ai_min().unwrap(), 10.0);
+    assert_eq!(channel.ai_max().unwrap(), 1000.0);
+    assert_eq!(channel.scale().unwrap(), FrequencyScale::Hertz);
+    assert_eq!(channel.threshold_level().unwrap(), 2.5);
+    assert_eq!(channel.hysteresis().unwrap(), 0.5);
+
+    channel.set_threshold_level(1.0).unwrap();
+    assert_eq!(channel.threshold_level().unwrap(), 1.0);
+    channel.reset_threshold_level().unwrap();
+    assert_eq!(channel.threshold_level().unwrap(), 2.5);
+    assert!(matches!(
+        task.get_any_channel("tacho").unwrap(),
+        AnyAnalogInputChannel::FrequencyVoltage(_)
+    ));
+}
+
+#[test]
+fn test_frequency_voltage_custom_scale() {
+    LinearScale::new(
+        "daqmx-rs rpm scale",
+        60.0,
+        0.0,
+        PreScaledUnits::Hertz,
+        "rpm",
+    )
+    .unwrap();
+    let channel = FrequencyVoltageChannelBuilder::new("PXI1Slot2/ai3")
+        .unwrap()
+        .scale(FrequencyScale::new_custom("daqmx-rs rpm scale").unwrap());
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let channel = task.create_channel(channel).unwrap();
+
+    assert_eq!(
+        channel.scale().unwrap(),
+        FrequencyScale::new_custom("daqmx-rs rpm scale").unwrap()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_sensor_channel_config_round_trip() {
+    use daqmx::config::{AnalogInputChannelConfig, TaskConfig};
+
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    task.create_channel(
+        StrainGageChannelBuilder::new("PXI1Slot2/ai0")
+            .unwrap()
+            .strain_gage_configuration(StrainGageConfiguration::HalfBridgeII)
+            .gage_factor(2.1),
+    )
+    .unwrap();
+    task.create_channel(
+        BridgeChannelBuilder::new("PXI1Slot2/ai1")
+            .unwrap()
+            .scale(BridgeScale::MilliVoltsPerVolt),
+    )
+    .unwrap();
+    task.create_channel(
+        AccelerometerChannelBuilder::new("PXI1Slot2/ai2")
+            .unwrap()
+            .sensitivity(100.0)
+            .coupling(Coupling::DC),
+    )
+    .unwrap();
+    task.create_channel(MicrophoneChannelBuilder::new("PXI1Slot2/ai3").unwrap())
+        .unwrap();
+    task.create_channel(
+        ForceBridgeChannelBuilder::new("PXI1Slot2/ai4")
+            .unwrap()
+            .calibration(BridgeCalibration::Table {
+                electrical_values: vec![0.0, 2.0],
+                physical_values: vec![0.0, 1000.0],
+            }),
+    )
+    .unwrap();
+    task.create_channel(
+        ThermistorBuilder::new("PXI1Slot2/ai5")
+            .unwrap()
+            .excitation(ThermistorExcitation::Current(0.0002)),
+    )
+    .unwrap();
+    task.create_channel(
+        ResistanceChannelBuilder::new("PXI1Slot2/ai6")
+            .unwrap()
+            .lead_wire_resistance(0.1),
+    )
+    .unwrap();
+    task.create_channel(VoltageRmsChannelBuilder::new("PXI1Slot2/ai7").unwrap())
+        .unwrap();
+    task.create_channel(
+        CurrentRmsChannelBuilder::new("PXI1Slot2/ai8")
+            .unwrap()
+            .shunt_resistor_location(ShuntResistorLocation::Internal),
+    )
+    .unwrap();
+    task.create_channel(
+        FrequencyVoltageChannelBuilder::new("PXI1Slot2/ai9")
+            .unwrap()
+            .threshold_level(1.5)
+            .hysteresis(0.2),
+    )
+    .unwrap();
+
+    let exported = TaskConfig::from_task(&task).unwrap();
+    let AnalogInputChannelConfig::StrainGage(strain) = &exported.channels[0] else {
+        panic!("expected a strain gage channel");
+    };
+    assert_eq!(
+        strain.strain_gage_configuration,
+        Some(StrainGageConfiguration::HalfBridgeII)
+    );
+    assert_eq!(strain.gage_factor, Some(2.1));
+    assert!(matches!(
+        exported.channels[1],
+        AnalogInputChannelConfig::Bridge(_)
+    ));
+    let AnalogInputChannelConfig::Accelerometer(accel) = &exported.channels[2] else {
+        panic!("expected an accelerometer channel");
+    };
+    assert_eq!(accel.sensitivity, Some(100.0));
+    assert_eq!(accel.coupling, Some(Coupling::DC));
+    assert!(matches!(
+        exported.channels[3],
+        AnalogInputChannelConfig::Microphone(_)
+    ));
+    let AnalogInputChannelConfig::ForceBridge(load) = &exported.channels[4] else {
+        panic!("expected a force bridge channel");
+    };
+    assert_eq!(load.units, Some(PreScaledUnits::Newtons));
+    assert!(matches!(
+        load.calibration,
+        Some(BridgeCalibration::Table { .. })
+    ));
+    let AnalogInputChannelConfig::Thermistor(thermistor) = &exported.channels[5] else {
+        panic!("expected a thermistor channel");
+    };
+    assert_eq!(
+        thermistor.excitation,
+        Some(ThermistorExcitation::Current(0.0002))
+    );
+    let AnalogInputChannelConfig::Resistance(resistance) = &exported.channels[6] else {
+        panic!("expected a resistance channel");
+    };
+    assert_eq!(resistance.lead_wire_resistance, Some(0.1));
+    assert!(matches!(
+        exported.channels[7],
+        AnalogInputChannelConfig::VoltageRms(_)
+    ));
+    let AnalogInputChannelConfig::CurrentRms(current) = &exported.channels[8] else {
+        panic!("expected an RMS current channel");
+    };
+    assert_eq!(
+        current.shunt_resistor_location,
+        Some(ShuntResistorLocation::Internal)
+    );
+    let AnalogInputChannelConfig::FrequencyVoltage(frequency) = &exported.channels[9] else {
+        panic!("expected a frequency channel");
+    };
+    assert_eq!(frequency.threshold_level, Some(1.5));
+    assert_eq!(frequency.hysteresis, Some(0.2));
+
+    let text = toml::to_string(&exported).unwrap();
+    let loaded: TaskConfig = toml::from_str(&text).unwrap();
+    drop(task);
+    let recreated = loaded.create_task().unwrap();
+    assert_eq!(TaskConfig::from_task(&recreated).unwrap(), exported);
+}
@@ -0,0 +1,95 @@
+//! Integration tests for discovering the devices in the system.
+//!
+use daqmx::channels::ai_channels::{AnalogTerminalConfig, MeasurementType};
+use daqmx::error::DaqmxError;
+use daqmx::system::{self, Device, InputRange, PhysicalChannel};
+
+#[test]
+fn test_device_names_include_test_device() {
+    let names = system::device_names().unwrap();
+    assert!(names.iter().any(|name| name == "PXI1Slot2"));
+}
+
+#[test]
+fn test_device_properties() {
+    let device = system::devices()
+        .unwrap()
+        .into_iter()
+        .find(|device| device.name() == "PXI1Slot2")
+        .unwrap();
+
+    assert!(device.is_simulated().unwrap());
+    assert!(device.product_type().unwrap().starts_with("PXIe-63"));
+    assert_eq!(device.pxi_chassis_number().unwrap(), 1);
+    assert_eq!(device.pxi_slot_number().unwrap(), 2);
+    assert!(device.ai_max_multi_channel_rate().unwrap() > 0.0);
+}
+
+#[test]
+fn test_device_physical_channels() {
+    let device = Device::new("PXI1Slot2").unwrap();
+
+    let ai = device.ai_physical_channels().unwrap();
+    assert_eq!(ai[0], "PXI1Slot2/ai0");
+    assert!(ai.len() >= 16);
+    assert!(device.ao_physical_channels().unwrap().len() >= 2);
+    assert!(
+        device
+            .di_lines()
+            .unwrap()
+            .contains(&"PXI1Slot2/port1/line0".to_string())
+    );
+    assert!(
+        device
+            .do_ports()
+            .unwrap()
+            .contains(&"PXI1Slot2/port1".to_string())
+    );
+    assert!(
+        device
+            .ci_physical_channels()
+            .unwrap()
+            .contains(&"PXI1Slot2/ctr1".to_string())
+    );
+    assert!(
+        device
+            .ai_supported_measurement_types()
+            .unwrap()
+            .contains(&MeasurementType::Voltage)
+    );
+}
+
+#[test]
+fn test_unknown_device_errors() {
+    let device = Device::new("NotADevice").unwrap();
+    assert!(matches!(
+        device.product_type(),
+        Err(DaqmxError::DaqmxError(_, _))
+    ));
+}
+
+#[test]
+fn test_physical_channel_capabilities() {
+    let channel = PhysicalChannel::new("PXI1Slot2/ai0").unwrap();
+
+    let configs = channel.ai_terminal_configs().unwrap();
+    assert!(configs.contains(&AnalogTerminalConfig::RSE));
+    assert!(configs.contains(&AnalogTerminalConfig::Differential));
+    assert!(
+        channel
+            .ai_supported_measurement_types()
+            .unwrap()
+            .contains(&MeasurementType::Voltage)
+    );
+    assert!(channel.ai_voltage_ranges().unwrap().contains(&InputRange {
+        min: -10.0,
+        max: 10.0
+    }));
+    assert!(!channel.has_teds().unwrap());
+}
+
+#[test]
+fn test_physical_channel_not_analog_input() {
+    let channel = PhysicalChannel::new("PXI1Slot2/ctr0").unwrap();
+    assert!(channel.ai_terminal_configs().is_err());
+}
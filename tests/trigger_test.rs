@@ -0,0 +1,103 @@
+//! Integration tests for the trigger configuration of tasks.
+//!
+//! Simulated devices fire triggers immediately so these confirm the configuration is accepted.
+use daqmx::channels::ai_channels::voltage::VoltageChannelBuilder;
+use daqmx::channels::*;
+use daqmx::tasks::*;
+use daqmx::types::*;
+
+fn finite_task() -> Task<AnalogInput> {
+    let mut task: Task<AnalogInput> = Task::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::FiniteSamples,
+        100,
+    )
+    .unwrap();
+    task
+}
+
+#[test]
+fn test_digital_edge_start_trigger() {
+    let mut task = finite_task();
+    task.configure_digital_edge_start_trigger("/PXI1Slot2/PFI0", ClockEdge::Falling)
+        .unwrap();
+
+    let mut buffer = [0.0; 100];
+    task.read(
+        Timeout::Seconds(1.0),
+        DataFillMode::GroupByChannel,
+        Some(100),
+        &mut buffer[..],
+    )
+    .unwrap();
+
+    task.disable_start_trigger().unwrap();
+}
+
+#[test]
+fn test_analog_start_triggers() {
+    let mut task = finite_task();
+    task.configure_analog_edge_start_trigger("APFI0", Slope::Rising, 1.0)
+        .unwrap();
+    task.configure_analog_window_start_trigger("APFI0", WindowCondition::EnteringWindow, 2.0, -2.0)
+        .unwrap();
+    task.start().unwrap();
+    task.stop().unwrap();
+}
+
+#[test]
+fn test_reference_trigger() {
+    let mut task = finite_task();
+    task.configure_digital_edge_reference_trigger("/PXI1Slot2/PFI0", ClockEdge::Rising, 20)
+        .unwrap();
+
+    let mut buffer = [0.0; 100];
+    task.read(
+        Timeout::Seconds(1.0),
+        DataFillMode::GroupByChannel,
+        Some(100),
+        &mut buffer[..],
+    )
+    .unwrap();
+
+    task.configure_analog_edge_reference_trigger("APFI0", Slope::Falling, 0.5, 10)
+        .unwrap();
+    task.configure_analog_window_reference_trigger(
+        "APFI0",
+        WindowCondition::LeavingWindow,
+        1.0,
+        -1.0,
+        10,
+    )
+    .unwrap();
+    task.disable_reference_trigger().unwrap();
+}
+
+#[test]
+fn test_pause_triggers() {
+    let mut task = Task::<AnalogInput>::new("").unwrap();
+    let ch1 = VoltageChannelBuilder::new("PXI1Slot2/ai0").unwrap();
+    task.create_channel(ch1).unwrap();
+    task.configure_sample_clock_timing(
+        None,
+        1000.0,
+        ClockEdge::Rising,
+        SampleMode::ContinuousSamples,
+        1000,
+    )
+    .unwrap();
+
+    task.configure_digital_level_pause_trigger("/PXI1Slot2/PFI1", Level::High)
+        .unwrap();
+    task.start().unwrap();
+    task.stop().unwrap();
+
+    task.configure_analog_level_pause_trigger("APFI0", LevelCondition::BelowLevel, 0.0)
+        .unwrap();
+    task.disable_pause_trigger().unwrap();
+}